@@ -52,6 +52,8 @@ pub struct Confirm {
     yes_label: String,
     no_label: String,
     selected_color: Color,
+    unselected_color: Color,
+    help_color: Color,
 }
 
 impl Default for Confirm {
@@ -65,7 +67,9 @@ impl Default for Confirm {
             cancelled: false,
             yes_label: "Yes".to_string(),
             no_label: "No".to_string(),
-            selected_color: Color::Cyan,
+            selected_color: Color::SELECTION,
+            unselected_color: Color::MUTED,
+            help_color: Color::MUTED,
         }
     }
 }
@@ -101,6 +105,18 @@ impl Confirm {
         self
     }
 
+    /// Set the unselected label color.
+    pub fn unselected_color(mut self, color: Color) -> Self {
+        self.unselected_color = color;
+        self
+    }
+
+    /// Set the help text color.
+    pub fn help_color(mut self, color: Color) -> Self {
+        self.help_color = color;
+        self
+    }
+
     /// Get the current value.
     pub fn value(&self) -> bool {
         self.value
@@ -116,6 +132,11 @@ impl Confirm {
         self.submitted
     }
 
+    /// Clear the submitted state so the value can be edited again.
+    pub fn reopen(&mut self) {
+        self.submitted = false;
+    }
+
     /// Check if cancelled.
     pub fn is_cancelled(&self) -> bool {
         self.cancelled
@@ -125,6 +146,11 @@ impl Confirm {
     pub fn set_focused(&mut self, focused: bool) {
         self.focused = focused;
     }
+
+    /// Check if the prompt is focused.
+    pub fn is_focused(&self) -> bool {
+        self.focused
+    }
 }
 
 impl Model for Confirm {
@@ -151,16 +177,16 @@ impl Model for Confirm {
         let yes_style = if self.value {
             format!("{}\x1b[1m{}\x1b[0m", self.selected_color.to_ansi_fg(), self.yes_label)
         } else {
-            format!("{}{}{}", Color::BrightBlack.to_ansi_fg(), self.yes_label, "\x1b[0m")
+            format!("{}{}{}", self.unselected_color.to_ansi_fg(), self.yes_label, "\x1b[0m")
         };
 
         let no_style = if !self.value {
             format!("{}\x1b[1m{}\x1b[0m", self.selected_color.to_ansi_fg(), self.no_label)
         } else {
-            format!("{}{}{}", Color::BrightBlack.to_ansi_fg(), self.no_label, "\x1b[0m")
+            format!("{}{}{}", self.unselected_color.to_ansi_fg(), self.no_label, "\x1b[0m")
         };
 
-        let hint = format!("{}(y/n){}", Color::BrightBlack.to_ansi_fg(), "\x1b[0m");
+        let hint = format!("{}(y/n){}", self.help_color.to_ansi_fg(), "\x1b[0m");
 
         format!("? {} {} / {} {}", self.title, yes_style, no_style, hint)
     }
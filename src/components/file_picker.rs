@@ -16,9 +16,10 @@
 use std::path::{Path, PathBuf};
 
 use crate::{
+    layout::{HitArea, Rect},
     runtime::{Cmd, Model, accessible::Accessible},
-    style::Color,
-    terminal::{Event, KeyCode},
+    style::{Color, file_url, hyperlink},
+    terminal::{Event, KeyCode, MouseButton, MouseEvent, MouseEventKind},
 };
 
 /// Message type for file picker.
@@ -34,6 +35,8 @@ pub enum FilePickerMsg {
     Back,
     /// Toggle showing hidden files.
     ToggleHidden,
+    /// Entry clicked, by index. Clicking the current entry enters it.
+    Click(usize),
     /// Submit selection.
     Submit,
     /// Cancel selection.
@@ -86,6 +89,13 @@ pub struct FilePicker {
     focused: bool,
     height: usize,
     scroll_offset: usize,
+    title_color: Color,
+    cursor_color: Color,
+    directory_color: Color,
+    help_color: Color,
+    hyperlinks: bool,
+    /// Where the picker was last drawn.
+    area: HitArea,
 }
 
 impl Default for FilePicker {
@@ -114,6 +124,12 @@ impl FilePicker {
             focused: true,
             height: 10,
             scroll_offset: 0,
+            title_color: Color::PRIMARY,
+            cursor_color: Color::ACCENT,
+            directory_color: Color::PRIMARY,
+            help_color: Color::MUTED,
+            hyperlinks: true,
+            area: HitArea::new(),
         };
 
         picker.refresh_entries();
@@ -173,11 +189,60 @@ impl FilePicker {
         self
     }
 
+    /// Set the title color.
+    pub fn title_color(mut self, color: Color) -> Self {
+        self.title_color = color;
+        self
+    }
+
+    /// Set the cursor indicator color.
+    pub fn cursor_color(mut self, color: Color) -> Self {
+        self.cursor_color = color;
+        self
+    }
+
+    /// Set the directory icon color.
+    pub fn directory_color(mut self, color: Color) -> Self {
+        self.directory_color = color;
+        self
+    }
+
+    /// Set the color of the path, sizes and help text.
+    pub fn help_color(mut self, color: Color) -> Self {
+        self.help_color = color;
+        self
+    }
+
+    /// Link entry names to their `file://` paths (default: true).
+    ///
+    /// Links are only emitted when hyperlinks are enabled for the terminal.
+    pub fn hyperlinks(mut self, enabled: bool) -> Self {
+        self.hyperlinks = enabled;
+        self
+    }
+
     /// Set focus state.
     pub fn set_focused(&mut self, focused: bool) {
         self.focused = focused;
     }
 
+    /// Check if the picker is focused.
+    pub fn is_focused(&self) -> bool {
+        self.focused
+    }
+
+    /// Record where the picker is drawn, for mouse hit-testing.
+    ///
+    /// [`Model::view_in`] records this automatically.
+    pub fn set_area(&mut self, area: Rect) {
+        self.area.set(area);
+    }
+
+    /// Where the picker was last drawn.
+    pub fn area(&self) -> Option<Rect> {
+        self.area.get()
+    }
+
     /// Get the selected path.
     pub fn selected(&self) -> Option<&PathBuf> {
         self.selected.as_ref()
@@ -188,6 +253,11 @@ impl FilePicker {
         self.submitted
     }
 
+    /// Clear the submitted state so the value can be edited again.
+    pub fn reopen(&mut self) {
+        self.submitted = false;
+    }
+
     /// Check if cancelled.
     pub fn is_cancelled(&self) -> bool {
         self.cancelled
@@ -198,6 +268,38 @@ impl FilePicker {
         &self.current_dir
     }
 
+    /// Select and submit a path directly, without browsing.
+    ///
+    /// Relative paths are resolved against the current directory. The path
+    /// must exist and satisfy the picker's filters; otherwise the picker is
+    /// left unchanged and a description of the problem is returned.
+    pub fn select_path(&mut self, path: impl AsRef<Path>) -> Result<(), String> {
+        let path = self.current_dir.join(path.as_ref());
+        let metadata =
+            std::fs::metadata(&path).map_err(|_| format!("'{}' does not exist", path.display()))?;
+
+        if self.dirs_only && !metadata.is_dir() {
+            return Err(format!("'{}' is not a directory", path.display()));
+        }
+        if self.files_only && metadata.is_dir() {
+            return Err(format!("'{}' is not a file", path.display()));
+        }
+        if !metadata.is_dir() && !self.extensions.is_empty() {
+            let ext = path.extension().and_then(|e| e.to_str()).unwrap_or("").to_lowercase();
+            if !self.extensions.iter().any(|e| e.to_lowercase() == ext) {
+                return Err(format!(
+                    "'{}' must have one of the extensions: {}",
+                    path.display(),
+                    self.extensions.join(", ")
+                ));
+            }
+        }
+
+        self.selected = Some(path);
+        self.submitted = true;
+        Ok(())
+    }
+
     /// Refresh the list of entries from the filesystem.
     fn refresh_entries(&mut self) {
         self.entries.clear();
@@ -308,6 +410,23 @@ impl FilePicker {
         }
     }
 
+    fn handle_mouse(&self, mouse: MouseEvent) -> Option<FilePickerMsg> {
+        let (_, row) = self.area.hit(mouse.column, mouse.row)?;
+        match mouse.kind {
+            MouseEventKind::Down(MouseButton::Left) => {
+                // Entries start below the title and the current directory.
+                let top = usize::from(!self.title.is_empty()) + 1;
+                let row = (row as usize).checked_sub(top)?;
+                let index = self.scroll_offset + row;
+                (row < self.height && index < self.entries.len())
+                    .then_some(FilePickerMsg::Click(index))
+            },
+            MouseEventKind::ScrollUp => Some(FilePickerMsg::Up),
+            MouseEventKind::ScrollDown => Some(FilePickerMsg::Down),
+            _ => None,
+        }
+    }
+
     /// Format file size for display.
     fn format_size(size: u64) -> String {
         const KB: u64 = 1024;
@@ -343,6 +462,14 @@ impl Model for FilePicker {
                 self.show_hidden = !self.show_hidden;
                 self.refresh_entries();
             },
+            FilePickerMsg::Click(index) => {
+                if index == self.cursor {
+                    self.enter();
+                } else if index < self.entries.len() {
+                    self.cursor = index;
+                    self.adjust_scroll();
+                }
+            },
             FilePickerMsg::Submit => {
                 if let Some(entry) = self.entries.get(self.cursor)
                     && (self.dirs_only || !entry.is_dir)
@@ -363,14 +490,19 @@ impl Model for FilePicker {
 
         // Title
         if !self.title.is_empty() {
-            output.push_str(&format!("{}{}{}", Color::Cyan.to_ansi_fg(), self.title, "\x1b[0m"));
+            output.push_str(&format!(
+                "{}{}{}",
+                self.title_color.to_ansi_fg(),
+                self.title,
+                "\x1b[0m"
+            ));
             output.push('\n');
         }
 
         // Current directory
         output.push_str(&format!(
             "{}{}{}",
-            Color::BrightBlack.to_ansi_fg(),
+            self.help_color.to_ansi_fg(),
             self.current_dir.display(),
             "\x1b[0m"
         ));
@@ -378,7 +510,7 @@ impl Model for FilePicker {
 
         // Entries
         if self.entries.is_empty() {
-            output.push_str(&format!("{}(empty){}", Color::BrightBlack.to_ansi_fg(), "\x1b[0m"));
+            output.push_str(&format!("{}(empty){}", self.help_color.to_ansi_fg(), "\x1b[0m"));
         } else {
             let visible_end = (self.scroll_offset + self.height).min(self.entries.len());
 
@@ -388,38 +520,33 @@ impl Model for FilePicker {
 
                 // Cursor indicator
                 if is_selected {
-                    output.push_str(&format!("{}❯ ", Color::Magenta.to_ansi_fg()));
+                    output.push_str(&format!("{}❯ ", self.cursor_color.to_ansi_fg()));
                 } else {
                     output.push_str("  ");
                 }
 
                 // Directory indicator
                 if entry.is_dir {
-                    output.push_str(&format!("{}📁 ", Color::Blue.to_ansi_fg()));
+                    output.push_str(&format!("{}📁 ", self.directory_color.to_ansi_fg()));
                 } else {
                     output.push_str("   ");
                 }
 
                 // Name
+                let name = format!("{}{}", entry.name, if entry.is_dir { "/" } else { "" });
+                let name =
+                    if self.hyperlinks { hyperlink(&name, &file_url(&entry.path)) } else { name };
                 if is_selected {
-                    output.push_str(&format!(
-                        "\x1b[1m{}{}\x1b[0m",
-                        entry.name,
-                        if entry.is_dir { "/" } else { "" }
-                    ));
+                    output.push_str(&format!("\x1b[1m{}\x1b[0m", name));
                 } else {
-                    output.push_str(&format!(
-                        "{}{}",
-                        entry.name,
-                        if entry.is_dir { "/" } else { "" }
-                    ));
+                    output.push_str(&name);
                 }
 
                 // Size (for files)
                 if let Some(size) = entry.size {
                     output.push_str(&format!(
                         "  {}{}{}",
-                        Color::BrightBlack.to_ansi_fg(),
+                        self.help_color.to_ansi_fg(),
                         Self::format_size(size),
                         "\x1b[0m"
                     ));
@@ -437,7 +564,7 @@ impl Model for FilePicker {
                 };
                 output.push_str(&format!(
                     "\n{}({}/{} - {}%){}",
-                    Color::BrightBlack.to_ansi_fg(),
+                    self.help_color.to_ansi_fg(),
                     self.cursor + 1,
                     self.entries.len(),
                     position,
@@ -449,20 +576,25 @@ impl Model for FilePicker {
         // Help
         output.push_str(&format!(
             "\n\n{}↑/↓: navigate  Enter: select  Backspace: parent  .: toggle hidden  Esc: cancel{}",
-            Color::BrightBlack.to_ansi_fg(),
+            self.help_color.to_ansi_fg(),
             "\x1b[0m"
         ));
 
         output
     }
 
+    fn view_in(&self, area: Rect) -> String {
+        self.area.set(area);
+        self.view()
+    }
+
     fn handle_event(&self, event: Event) -> Option<Self::Message> {
         if !self.focused {
             return None;
         }
 
-        if let Event::Key(key) = event {
-            match key.code {
+        match event {
+            Event::Key(key) => match key.code {
                 KeyCode::Up | KeyCode::Char('k') => Some(FilePickerMsg::Up),
                 KeyCode::Down | KeyCode::Char('j') => Some(FilePickerMsg::Down),
                 KeyCode::Enter => Some(FilePickerMsg::Enter),
@@ -470,9 +602,9 @@ impl Model for FilePicker {
                 KeyCode::Char('.') => Some(FilePickerMsg::ToggleHidden),
                 KeyCode::Esc => Some(FilePickerMsg::Cancel),
                 _ => None,
-            }
-        } else {
-            None
+            },
+            Event::Mouse(mouse) => self.handle_mouse(mouse),
+            _ => None,
         }
     }
 }
@@ -69,7 +69,7 @@ impl Default for FooterHints {
             hints: Vec::new(),
             width: 80,
             key_color: Color::Default,
-            desc_color: Color::BrightBlack,
+            desc_color: Color::MUTED,
             show_separator: false,
             separator_char: '─',
             show_scroll_left: false,
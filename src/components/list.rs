@@ -1,6 +1,8 @@
 //! List component with filtering and pagination.
 //!
-//! A filterable, scrollable list with keyboard navigation.
+//! A filterable, scrollable list with keyboard navigation. Typing filters the
+//! items fzf-style: characters match in order but need not be adjacent, the
+//! best matches are listed first and every matched character is highlighted.
 //!
 //! # Example
 //!
@@ -12,11 +14,33 @@
 //!     .height(10)
 //!     .filterable(true);
 //! ```
+//!
+//! For very long lists, [`List::source`] reads labels from a [`ListSource`]
+//! on demand instead.
+//!
+//! Items take one line each unless a [`ListDelegate`] draws them: the built-in
+//! [`DefaultDelegate`] adds a description line under each title, and custom
+//! delegates can render icons, badges or anything else over several lines.
+//!
+//! ```rust
+//! use teapot::components::{DefaultDelegate, List};
+//!
+//! let list = List::new("Packages")
+//!     .items(vec!["serde", "tokio"])
+//!     .item_description(0, "Serialization framework")
+//!     .item_description(1, "Async runtime")
+//!     .delegate(DefaultDelegate::new());
+//! ```
+
+use std::{collections::HashMap, sync::Arc};
 
+use super::source::{ListSource, Shared, prefetch_range};
 use crate::{
+    layout::{HitArea, Rect},
     runtime::{Cmd, Model},
-    style::Color,
-    terminal::{Event, KeyCode, KeyModifiers},
+    style::{Color, hyperlink},
+    terminal::{Event, KeyCode, KeyModifiers, MouseButton, MouseEvent, MouseEventKind},
+    util::{CaseMatching, FuzzyMatcher, ScrollState},
 };
 
 /// Message type for list.
@@ -42,6 +66,11 @@ pub enum ListMsg {
     ClearFilter,
     /// Submit selection.
     Submit,
+    /// Item clicked, by position in the filtered list. Clicking the
+    /// current item submits it.
+    Click(usize),
+    /// Items requested from the source have arrived.
+    Loaded,
     /// Cancel selection.
     Cancel,
     /// Focus the list.
@@ -50,17 +79,165 @@ pub enum ListMsg {
     Blur,
 }
 
+/// An item being drawn by a [`ListDelegate`].
+#[derive(Debug)]
+pub struct ListItem<'a, T> {
+    /// Index into the list's items.
+    pub index: usize,
+    /// The item's value; `None` for a list backed by a [`ListSource`].
+    pub value: Option<&'a T>,
+    /// The plain label, or `…` while it is loading from a source.
+    pub label: &'a str,
+    /// The label with filter matches highlighted and its link applied.
+    pub title: &'a str,
+    /// The description set with [`List::item_description`].
+    pub description: Option<&'a str>,
+    /// Positions of the characters in `label` that matched the filter.
+    pub matches: &'a [usize],
+    /// Whether the item is under the cursor.
+    pub selected: bool,
+    /// The cursor character if selected, otherwise a space.
+    pub cursor: &'a str,
+    /// The list's selected or unselected color, whichever applies.
+    pub color: &'a Color,
+}
+
+/// Renders the items of a [`List`].
+///
+/// Every item is drawn in exactly [`height`](ListDelegate::height) lines:
+/// extra lines are dropped and missing ones left blank, so the list can page
+/// through items without measuring them.
+///
+/// # Example
+///
+/// ```rust
+/// use teapot::components::{List, ListDelegate, ListItem};
+///
+/// struct Status;
+///
+/// impl ListDelegate<(String, bool)> for Status {
+///     fn render(&self, item: &ListItem<'_, (String, bool)>) -> String {
+///         let ok = item.value.is_some_and(|(_, ok)| *ok);
+///         format!("{} {} {}", item.cursor, if ok { "✓" } else { "✗" }, item.title)
+///     }
+/// }
+///
+/// let list = List::new("Checks")
+///     .items_with_labels(vec![(("build".into(), true), "build".into())])
+///     .delegate(Status);
+/// ```
+pub trait ListDelegate<T>: Send + Sync {
+    /// Lines each item takes up.
+    fn height(&self) -> usize {
+        1
+    }
+
+    /// Blank lines between items.
+    fn spacing(&self) -> usize {
+        0
+    }
+
+    /// Draw an item as up to [`height`](ListDelegate::height) lines.
+    fn render(&self, item: &ListItem<'_, T>) -> String;
+}
+
+/// The built-in delegate: the cursor and title, with the item's description
+/// on the line below.
+#[derive(Debug, Clone)]
+#[must_use = "delegates do nothing unless passed to List::delegate"]
+pub struct DefaultDelegate {
+    show_description: bool,
+    spacing: usize,
+    description_color: Color,
+}
+
+impl Default for DefaultDelegate {
+    fn default() -> Self {
+        Self { show_description: true, spacing: 1, description_color: Color::MUTED }
+    }
+}
+
+impl DefaultDelegate {
+    /// Create a delegate that shows descriptions, with a blank line between
+    /// items.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set whether descriptions are shown.
+    pub fn show_description(mut self, show: bool) -> Self {
+        self.show_description = show;
+        self
+    }
+
+    /// Set the number of blank lines between items.
+    pub fn spacing(mut self, spacing: usize) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    /// Set the description color.
+    pub fn description_color(mut self, color: Color) -> Self {
+        self.description_color = color;
+        self
+    }
+
+    /// One line per item, as a list without a delegate draws them.
+    fn plain() -> Self {
+        Self { show_description: false, spacing: 0, ..Self::default() }
+    }
+}
+
+impl<T> ListDelegate<T> for DefaultDelegate {
+    fn height(&self) -> usize {
+        1 + usize::from(self.show_description)
+    }
+
+    fn spacing(&self) -> usize {
+        self.spacing
+    }
+
+    fn render(&self, item: &ListItem<'_, T>) -> String {
+        let mut out = format!("{}{} {}\x1b[0m", item.color.to_ansi_fg(), item.cursor, item.title);
+        if self.show_description {
+            out.push_str(&format!(
+                "\n{}  {}\x1b[0m",
+                self.description_color.to_ansi_fg(),
+                item.description.unwrap_or_default()
+            ));
+        }
+        out
+    }
+}
+
+impl<T> std::fmt::Debug for Shared<dyn ListDelegate<T>> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ListDelegate")
+            .field("height", &self.0.height())
+            .field("spacing", &self.0.spacing())
+            .finish()
+    }
+}
+
 /// A filterable, paginated list component.
 #[derive(Debug, Clone)]
 #[must_use = "components do nothing unless used in a view or run with Program"]
 pub struct List<T> {
     title: String,
     items: Vec<(T, String)>,
+    /// Labels produced on demand, used instead of `items` when set.
+    source: Option<Shared<dyn ListSource>>,
     filtered_indices: Vec<usize>,
+    /// Matched character positions in each filtered item's label.
+    highlights: Vec<Vec<usize>>,
     filter: String,
-    cursor: usize,
-    offset: usize,
+    matcher: FuzzyMatcher,
+    /// Extra text each item is matched against but that isn't shown.
+    keywords: HashMap<usize, String>,
+    scroll: ScrollState,
     height: usize,
+    /// Whether `height` was set, capping the area drawn into.
+    fixed_height: bool,
     focused: bool,
     submitted: bool,
     cancelled: bool,
@@ -72,6 +249,14 @@ pub struct List<T> {
     filter_color: Color,
     match_highlight_color: Color,
     no_match_text: String,
+    /// Hyperlinks by item index.
+    links: HashMap<usize, String>,
+    /// Descriptions by item index.
+    descriptions: HashMap<usize, String>,
+    /// Draws the items; one plain line each when unset.
+    delegate: Option<Shared<dyn ListDelegate<T>>>,
+    /// Where the list was last drawn.
+    area: HitArea,
 }
 
 impl<T: Clone> Default for List<T> {
@@ -79,22 +264,30 @@ impl<T: Clone> Default for List<T> {
         Self {
             title: String::new(),
             items: Vec::new(),
+            source: None,
             filtered_indices: Vec::new(),
+            highlights: Vec::new(),
             filter: String::new(),
-            cursor: 0,
-            offset: 0,
+            matcher: FuzzyMatcher::new(),
+            keywords: HashMap::new(),
+            scroll: ScrollState::new(),
             height: 10,
+            fixed_height: false,
             focused: true,
             submitted: false,
             cancelled: false,
             filterable: true,
             filter_placeholder: "Type to filter...".to_string(),
             cursor_char: "❯",
-            selected_color: Color::Cyan,
+            selected_color: Color::SELECTION,
             unselected_color: Color::Default,
-            filter_color: Color::Yellow,
-            match_highlight_color: Color::Green,
+            filter_color: Color::WARNING,
+            match_highlight_color: Color::SUCCESS,
             no_match_text: "No matching items".to_string(),
+            links: HashMap::new(),
+            descriptions: HashMap::new(),
+            delegate: None,
+            area: HitArea::new(),
         }
     }
 }
@@ -105,16 +298,32 @@ impl<T: Clone> List<T> {
         Self { title: title.into(), ..Default::default() }
     }
 
-    /// Set items with display labels.
+    /// Set items with display labels, replacing any source.
     pub fn items_with_labels(mut self, items: Vec<(T, String)>) -> Self {
         self.items = items;
+        self.source = None;
         self.rebuild_filtered();
         self
     }
 
-    /// Set the visible height.
+    /// Read item labels from a source instead of owning them.
+    ///
+    /// Only the items being drawn are requested, so the source can be
+    /// arbitrarily large. A source-backed list has no filter and no values:
+    /// use [`List::current_index`] to find out what was picked.
+    pub fn source(mut self, source: impl ListSource + 'static) -> Self {
+        self.set_source(source);
+        self
+    }
+
+    /// Set the visible height in lines.
+    ///
+    /// With a multi-line [`ListDelegate`] fewer items fit, but always at
+    /// least one. In [`Model::view_in`] this is the most the list takes; left
+    /// unset, the list fills the area.
     pub fn height(mut self, height: usize) -> Self {
         self.height = height;
+        self.fixed_height = true;
         self
     }
 
@@ -130,6 +339,27 @@ impl<T: Clone> List<T> {
         self
     }
 
+    /// Set how letter case is compared when filtering (smart case by default).
+    pub fn case_matching(mut self, case: CaseMatching) -> Self {
+        self.matcher = self.matcher.case(case);
+        self.rebuild_filtered();
+        self
+    }
+
+    /// Require each filter term to appear as a substring instead of matching
+    /// fuzzily.
+    pub fn exact(mut self, exact: bool) -> Self {
+        self.matcher = self.matcher.exact(exact);
+        self.rebuild_filtered();
+        self
+    }
+
+    /// Give the item at `index` hidden keywords that the filter also matches.
+    pub fn item_keywords(mut self, index: usize, keywords: impl Into<String>) -> Self {
+        self.set_item_keywords(index, keywords);
+        self
+    }
+
     /// Set the cursor character.
     pub fn cursor_char(mut self, c: &'static str) -> Self {
         self.cursor_char = c;
@@ -160,9 +390,35 @@ impl<T: Clone> List<T> {
         self
     }
 
+    /// Link the item at `index` to a URL.
+    ///
+    /// Linked labels are rendered as OSC 8 hyperlinks, or as plain text when
+    /// hyperlinks aren't enabled.
+    pub fn item_link(mut self, index: usize, url: impl Into<String>) -> Self {
+        self.set_item_link(index, url);
+        self
+    }
+
+    /// Give the item at `index` a description, shown by [`DefaultDelegate`].
+    pub fn item_description(mut self, index: usize, description: impl Into<String>) -> Self {
+        self.set_item_description(index, description);
+        self
+    }
+
+    /// Draw items with a delegate instead of one label per line.
+    pub fn delegate(mut self, delegate: impl ListDelegate<T> + 'static) -> Self {
+        self.set_delegate(delegate);
+        self
+    }
+
     /// Get the current cursor position in the filtered list.
     pub fn cursor(&self) -> usize {
-        self.cursor
+        self.scroll.selected()
+    }
+
+    /// Get the index into the items of the item under the cursor.
+    pub fn current_index(&self) -> Option<usize> {
+        self.item_index(self.scroll.selected())
     }
 
     /// Get the current filter string.
@@ -172,42 +428,37 @@ impl<T: Clone> List<T> {
 
     /// Get the number of filtered items.
     pub fn filtered_count(&self) -> usize {
-        self.filtered_indices.len()
+        self.display_len()
     }
 
     /// Get the total number of items.
     pub fn total_count(&self) -> usize {
-        self.items.len()
+        self.source.as_ref().map_or(self.items.len(), |source| source.0.len())
     }
 
     /// Get the selected value if submitted.
     pub fn selected(&self) -> Option<&T> {
-        if self.submitted && !self.filtered_indices.is_empty() {
-            let idx = self.filtered_indices.get(self.cursor)?;
-            self.items.get(*idx).map(|(v, _)| v)
-        } else {
-            None
-        }
+        if self.submitted { self.current() } else { None }
     }
 
     /// Get the current value regardless of submit state.
+    ///
+    /// Always `None` for a list backed by a [`ListSource`].
     pub fn current(&self) -> Option<&T> {
-        if self.filtered_indices.is_empty() {
-            None
-        } else {
-            let idx = self.filtered_indices.get(self.cursor)?;
-            self.items.get(*idx).map(|(v, _)| v)
+        if self.source.is_some() {
+            return None;
         }
+        self.items.get(self.current_index()?).map(|(v, _)| v)
     }
 
     /// Get the current item's label.
+    ///
+    /// Always `None` for a list backed by a [`ListSource`].
     pub fn current_label(&self) -> Option<&str> {
-        if self.filtered_indices.is_empty() {
-            None
-        } else {
-            let idx = self.filtered_indices.get(self.cursor)?;
-            self.items.get(*idx).map(|(_, l)| l.as_str())
+        if self.source.is_some() {
+            return None;
         }
+        self.items.get(self.current_index()?).map(|(_, l)| l.as_str())
     }
 
     /// Check if submitted.
@@ -225,120 +476,249 @@ impl<T: Clone> List<T> {
         self.focused = focused;
     }
 
-    /// Set items dynamically.
+    /// Set items dynamically, replacing any source.
     pub fn set_items(&mut self, items: Vec<(T, String)>) {
         self.items = items;
+        self.source = None;
+        self.rebuild_filtered();
+    }
+
+    /// Read item labels from a source in place, replacing any owned items.
+    pub fn set_source(&mut self, source: impl ListSource + 'static) {
+        self.items.clear();
+        self.source = Some(Shared(Arc::new(source)));
         self.rebuild_filtered();
     }
 
+    /// Link the item at `index` to a URL in place.
+    pub fn set_item_link(&mut self, index: usize, url: impl Into<String>) {
+        self.links.insert(index, url.into());
+    }
+
+    /// Give the item at `index` hidden keywords in place.
+    pub fn set_item_keywords(&mut self, index: usize, keywords: impl Into<String>) {
+        self.keywords.insert(index, keywords.into());
+        self.rebuild_filtered();
+    }
+
+    /// Give the item at `index` a description in place.
+    pub fn set_item_description(&mut self, index: usize, description: impl Into<String>) {
+        self.descriptions.insert(index, description.into());
+    }
+
+    /// Draw items with a delegate in place.
+    pub fn set_delegate(&mut self, delegate: impl ListDelegate<T> + 'static) {
+        self.delegate = Some(Shared(Arc::new(delegate)));
+        self.scroll.clamp(self.display_len(), self.page_size(self.height));
+    }
+
+    /// Get an item's link.
+    pub fn item_link_at(&self, index: usize) -> Option<&str> {
+        self.links.get(&index).map(String::as_str)
+    }
+
+    /// Record where the list is drawn, for mouse hit-testing.
+    ///
+    /// [`Model::view_in`] records this automatically.
+    pub fn set_area(&mut self, area: Rect) {
+        self.area.set(area);
+    }
+
+    /// Where the list was last drawn.
+    pub fn area(&self) -> Option<Rect> {
+        self.area.get()
+    }
+
     /// Set filter and rebuild filtered list.
     pub fn set_filter(&mut self, filter: String) {
         self.filter = filter;
         self.rebuild_filtered();
     }
 
-    /// Rebuild filtered indices based on current filter.
+    /// Rebuild filtered indices based on current filter, best matches first.
     fn rebuild_filtered(&mut self) {
-        if self.filter.is_empty() {
+        self.highlights.clear();
+        if self.filter.trim().is_empty() {
             self.filtered_indices = (0..self.items.len()).collect();
         } else {
-            let filter_lower = self.filter.to_lowercase();
-            self.filtered_indices = self
+            let mut ranked: Vec<(usize, i32, Vec<usize>)> = self
                 .items
                 .iter()
                 .enumerate()
-                .filter(|(_, (_, label))| label.to_lowercase().contains(&filter_lower))
-                .map(|(i, _)| i)
+                .filter_map(|(i, (_, label))| {
+                    // Keywords are matched after the label; positions past
+                    // it aren't drawn.
+                    let hit = match self.keywords.get(&i) {
+                        Some(keywords) => {
+                            let mut hit = self
+                                .matcher
+                                .matches(&self.filter, &format!("{label} {keywords}"))?;
+                            let len = label.chars().count();
+                            hit.positions.retain(|&p| p < len);
+                            hit
+                        },
+                        None => self.matcher.matches(&self.filter, label)?,
+                    };
+                    Some((i, hit.score, hit.positions))
+                })
                 .collect();
+            // Shorter labels win ties, then the original order.
+            ranked.sort_by_key(|(i, score, _)| {
+                (std::cmp::Reverse(*score), self.items[*i].1.chars().count(), *i)
+            });
+            (self.filtered_indices, self.highlights) =
+                ranked.into_iter().map(|(i, _, positions)| (i, positions)).unzip();
         }
         // Reset cursor and offset when filter changes
-        self.cursor = 0;
-        self.offset = 0;
+        self.scroll.select_first();
     }
 
-    fn move_up(&mut self) {
-        if self.cursor > 0 {
-            self.cursor -= 1;
-            self.ensure_visible();
+    /// Number of items that pass the filter.
+    fn display_len(&self) -> usize {
+        self.source.as_ref().map_or(self.filtered_indices.len(), |source| source.0.len())
+    }
+
+    /// Index into the items of the item at a position in the filtered list.
+    fn item_index(&self, filtered: usize) -> Option<usize> {
+        match &self.source {
+            Some(source) => (filtered < source.0.len()).then_some(filtered),
+            None => self.filtered_indices.get(filtered).copied(),
         }
     }
 
-    fn move_down(&mut self) {
-        if self.cursor < self.filtered_indices.len().saturating_sub(1) {
-            self.cursor += 1;
-            self.ensure_visible();
+    /// Whether the filter is available; source-backed lists can't filter.
+    fn has_filter(&self) -> bool {
+        self.filterable && self.source.is_none()
+    }
+
+    /// Label of the item at `index`, or `None` while it is still loading.
+    fn label(&self, index: usize) -> Option<String> {
+        match &self.source {
+            Some(source) => source.0.label(index),
+            None => self.items.get(index).map(|(_, label)| label.clone()),
         }
     }
 
+    /// Ask the source for the items about to be drawn.
+    fn fetch(&self) -> Option<Cmd<ListMsg>> {
+        let source = self.source.as_ref()?;
+        let height = self.area.get().map_or(self.height, |area| self.fitted_height(area));
+        let len = source.0.len();
+        let range = prefetch_range(self.visible_range(height), len);
+        source.0.fetch(range).map(|cmd| cmd.map(|()| ListMsg::Loaded))
+    }
+
+    fn move_up(&mut self) {
+        self.scroll.select_prev();
+    }
+
+    fn move_down(&mut self) {
+        self.scroll.select_next(self.display_len(), self.page_size(self.height));
+    }
+
     fn move_first(&mut self) {
-        self.cursor = 0;
-        self.offset = 0;
+        self.scroll.select_first();
     }
 
     fn move_last(&mut self) {
-        self.cursor = self.filtered_indices.len().saturating_sub(1);
-        self.ensure_visible();
+        self.scroll.select_last(self.display_len(), self.page_size(self.height));
     }
 
     fn page_up(&mut self) {
-        let page_size = self.height.saturating_sub(1);
-        self.cursor = self.cursor.saturating_sub(page_size);
-        self.ensure_visible();
+        self.scroll.page_up(self.page_size(self.height));
     }
 
     fn page_down(&mut self) {
-        let page_size = self.height.saturating_sub(1);
-        self.cursor = (self.cursor + page_size).min(self.filtered_indices.len().saturating_sub(1));
-        self.ensure_visible();
+        self.scroll.page_down(self.display_len(), self.page_size(self.height));
+    }
+
+    /// Lines per item and blank lines between items.
+    fn item_rows(&self) -> (usize, usize) {
+        self.delegate
+            .as_ref()
+            .map_or((1, 0), |delegate| (delegate.0.height().max(1), delegate.0.spacing()))
+    }
+
+    /// Number of items that fit in `height` lines.
+    fn page_size(&self, height: usize) -> usize {
+        let (rows, spacing) = self.item_rows();
+        ((height + spacing) / (rows + spacing)).max(1)
+    }
+
+    /// Visible items for a given height in lines, keeping the cursor in view.
+    fn visible_range(&self, height: usize) -> (usize, usize) {
+        self.scroll.visible_range(self.display_len(), self.page_size(height))
     }
 
-    fn ensure_visible(&mut self) {
-        // Scroll up if cursor is above visible area
-        if self.cursor < self.offset {
-            self.offset = self.cursor;
+    /// Lines available for items when drawn into `area`.
+    fn fitted_height(&self, area: Rect) -> usize {
+        let chrome = usize::from(!self.title.is_empty()) + usize::from(self.has_filter());
+
+        // Leave room for the scroll indicators when the items don't all fit.
+        let mut height = (area.height as usize).saturating_sub(chrome);
+        if self.display_len() > self.page_size(height) {
+            height = height.saturating_sub(2);
         }
-        // Scroll down if cursor is below visible area
-        if self.cursor >= self.offset + self.height {
-            self.offset = self.cursor.saturating_sub(self.height - 1);
+        if self.fixed_height {
+            height = height.min(self.height);
         }
+        height.max(1)
     }
 
-    fn visible_range(&self) -> (usize, usize) {
-        let start = self.offset;
-        let end = (self.offset + self.height).min(self.filtered_indices.len());
-        (start, end)
+    /// Position in the filtered list of the item drawn on `row`.
+    fn item_at(&self, row: u16) -> Option<usize> {
+        let height = self.area.get().map_or(self.height, |area| self.fitted_height(area));
+        let (start, end) = self.visible_range(height);
+        let top = usize::from(!self.title.is_empty())
+            + usize::from(self.has_filter())
+            + usize::from(start > 0);
+        // Clicks on the blank lines between items miss.
+        let (rows, spacing) = self.item_rows();
+        let line = (row as usize).checked_sub(top)?;
+        if line % (rows + spacing) >= rows {
+            return None;
+        }
+        let index = start + line / (rows + spacing);
+        (index < end).then_some(index)
     }
 
-    /// Highlight matching text in label.
-    fn highlight_match(&self, label: &str) -> String {
-        if self.filter.is_empty() {
-            return label.to_string();
+    fn handle_mouse(&self, mouse: MouseEvent) -> Option<ListMsg> {
+        let (_, row) = self.area.hit(mouse.column, mouse.row)?;
+        match mouse.kind {
+            MouseEventKind::Down(MouseButton::Left) => self.item_at(row).map(ListMsg::Click),
+            MouseEventKind::ScrollUp => Some(ListMsg::Up),
+            MouseEventKind::ScrollDown => Some(ListMsg::Down),
+            _ => None,
         }
+    }
 
-        let filter_lower = self.filter.to_lowercase();
-        let label_lower = label.to_lowercase();
-
-        if let Some(pos) = label_lower.find(&filter_lower) {
-            let before = &label[..pos];
-            let matched = &label[pos..pos + self.filter.len()];
-            let after = &label[pos + self.filter.len()..];
-
-            format!(
-                "{}{}{}{}{}",
-                before,
-                self.match_highlight_color.to_ansi_fg(),
-                matched,
-                "\x1b[0m",
-                after
-            )
-        } else {
-            label.to_string()
+    /// Highlight the matched characters of a label drawn in `base`.
+    fn highlight_match(&self, label: &str, positions: &[usize], base: &Color) -> String {
+        let mut out = String::with_capacity(label.len());
+        let mut in_match = false;
+        for (i, c) in label.chars().enumerate() {
+            let matched = positions.binary_search(&i).is_ok();
+            if matched != in_match {
+                if matched {
+                    out.push_str(&self.match_highlight_color.to_ansi_fg());
+                } else {
+                    out.push_str("\x1b[0m");
+                    out.push_str(&base.to_ansi_fg());
+                }
+                in_match = matched;
+            }
+            out.push(c);
         }
+        if in_match {
+            out.push_str("\x1b[0m");
+            out.push_str(&base.to_ansi_fg());
+        }
+        out
     }
 }
 
 impl List<String> {
-    /// Set items from strings.
+    /// Set items from strings, replacing any source.
     pub fn items<I, S>(mut self, items: I) -> Self
     where
         I: IntoIterator<Item = S>,
@@ -351,57 +731,14 @@ impl List<String> {
                 (s.clone(), s)
             })
             .collect();
+        self.source = None;
         self.rebuild_filtered();
         self
     }
 }
 
-impl<T: Clone + Send + 'static> Model for List<T> {
-    type Message = ListMsg;
-
-    fn init(&self) -> Option<Cmd<Self::Message>> {
-        None
-    }
-
-    fn update(&mut self, msg: Self::Message) -> Option<Cmd<Self::Message>> {
-        match msg {
-            ListMsg::Up => self.move_up(),
-            ListMsg::Down => self.move_down(),
-            ListMsg::First => self.move_first(),
-            ListMsg::Last => self.move_last(),
-            ListMsg::PageUp => self.page_up(),
-            ListMsg::PageDown => self.page_down(),
-            ListMsg::InsertFilterChar(c) => {
-                if self.filterable {
-                    self.filter.push(c);
-                    self.rebuild_filtered();
-                }
-            },
-            ListMsg::DeleteFilterChar => {
-                if self.filterable && !self.filter.is_empty() {
-                    self.filter.pop();
-                    self.rebuild_filtered();
-                }
-            },
-            ListMsg::ClearFilter => {
-                if self.filterable {
-                    self.filter.clear();
-                    self.rebuild_filtered();
-                }
-            },
-            ListMsg::Submit => {
-                if !self.filtered_indices.is_empty() {
-                    self.submitted = true;
-                }
-            },
-            ListMsg::Cancel => self.cancelled = true,
-            ListMsg::Focus => self.focused = true,
-            ListMsg::Blur => self.focused = false,
-        }
-        None
-    }
-
-    fn view(&self) -> String {
+impl<T: Clone> List<T> {
+    fn render_sized(&self, height: usize) -> String {
         let mut output = String::new();
 
         // Title
@@ -410,12 +747,12 @@ impl<T: Clone + Send + 'static> Model for List<T> {
         }
 
         // Filter input (if filterable)
-        if self.filterable {
+        if self.has_filter() {
             output.push_str(&format!(
                 "{}/ {}{}",
                 self.filter_color.to_ansi_fg(),
                 if self.filter.is_empty() {
-                    format!("{}{}", Color::BrightBlack.to_ansi_fg(), self.filter_placeholder)
+                    format!("{}{}", Color::MUTED.to_ansi_fg(), self.filter_placeholder)
                 } else {
                     self.filter.clone()
                 },
@@ -424,17 +761,13 @@ impl<T: Clone + Send + 'static> Model for List<T> {
         }
 
         // Check if we have items
-        if self.filtered_indices.is_empty() {
-            if self.items.is_empty() {
-                output.push_str(&format!(
-                    "{}(no items){}",
-                    Color::BrightBlack.to_ansi_fg(),
-                    "\x1b[0m"
-                ));
+        if self.display_len() == 0 {
+            if self.total_count() == 0 {
+                output.push_str(&format!("{}(no items){}", Color::MUTED.to_ansi_fg(), "\x1b[0m"));
             } else {
                 output.push_str(&format!(
                     "{}{}{}",
-                    Color::BrightBlack.to_ansi_fg(),
+                    Color::MUTED.to_ansi_fg(),
                     self.no_match_text,
                     "\x1b[0m"
                 ));
@@ -442,60 +775,78 @@ impl<T: Clone + Send + 'static> Model for List<T> {
             return output;
         }
 
+        let (start, end) = self.visible_range(height);
+
         // Scroll indicator (top)
-        if self.offset > 0 {
+        if start > 0 {
             output.push_str(&format!(
                 "{}  ↑ {} more{}",
-                Color::BrightBlack.to_ansi_fg(),
-                self.offset,
+                Color::MUTED.to_ansi_fg(),
+                start,
                 "\x1b[0m\n"
             ));
         }
 
         // Visible items
-        let (start, end) = self.visible_range();
-        for (view_idx, filtered_idx) in (start..end).enumerate() {
-            let actual_idx = self.filtered_indices[filtered_idx];
-            let (_, label) = &self.items[actual_idx];
-
-            let is_selected = filtered_idx == self.cursor;
-            let cursor = if is_selected { self.cursor_char } else { " " };
-
-            let display_label = if self.filterable && !self.filter.is_empty() {
-                self.highlight_match(label)
-            } else {
+        let plain = DefaultDelegate::plain();
+        let delegate: &dyn ListDelegate<T> = match &self.delegate {
+            Some(delegate) => &*delegate.0,
+            None => &plain,
+        };
+        let (rows, spacing) = self.item_rows();
+        for filtered_idx in start..end {
+            let Some(actual_idx) = self.item_index(filtered_idx) else { break };
+            // Items still loading from a source show a placeholder.
+            let label = self.label(actual_idx).unwrap_or_else(|| "…".to_string());
+
+            let selected = filtered_idx == self.scroll.selected();
+            let color = if selected { &self.selected_color } else { &self.unselected_color };
+            let matches = match self.highlights.get(filtered_idx) {
+                Some(positions) if self.has_filter() => positions.as_slice(),
+                _ => &[],
+            };
+            let title = if matches.is_empty() {
                 label.clone()
+            } else {
+                self.highlight_match(&label, matches, color)
+            };
+            let title = match self.links.get(&actual_idx) {
+                Some(url) => hyperlink(&title, url),
+                None => title,
             };
 
-            if is_selected {
-                output.push_str(&format!(
-                    "{}{} {}{}",
-                    self.selected_color.to_ansi_fg(),
-                    cursor,
-                    display_label,
-                    "\x1b[0m"
-                ));
-            } else {
-                output.push_str(&format!(
-                    "{}{} {}{}",
-                    self.unselected_color.to_ansi_fg(),
-                    cursor,
-                    display_label,
-                    "\x1b[0m"
-                ));
-            }
+            let item = ListItem {
+                index: actual_idx,
+                value: self.source.is_none().then(|| &self.items[actual_idx].0),
+                label: &label,
+                title: &title,
+                description: self.descriptions.get(&actual_idx).map(String::as_str),
+                matches,
+                selected,
+                cursor: if selected { self.cursor_char } else { " " },
+                color,
+            };
 
-            if view_idx < (end - start - 1) {
-                output.push('\n');
+            // Every item takes exactly `rows` lines.
+            if filtered_idx > start {
+                output.push_str(&"\n".repeat(spacing + 1));
+            }
+            let rendered = delegate.render(&item);
+            let mut lines = rendered.lines();
+            for line in 0..rows {
+                if line > 0 {
+                    output.push('\n');
+                }
+                output.push_str(lines.next().unwrap_or_default());
             }
         }
 
         // Scroll indicator (bottom)
-        let remaining = self.filtered_indices.len().saturating_sub(end);
+        let remaining = self.display_len().saturating_sub(end);
         if remaining > 0 {
             output.push_str(&format!(
                 "\n{}  ↓ {} more{}",
-                Color::BrightBlack.to_ansi_fg(),
+                Color::MUTED.to_ansi_fg(),
                 remaining,
                 "\x1b[0m"
             ));
@@ -503,6 +854,75 @@ impl<T: Clone + Send + 'static> Model for List<T> {
 
         output
     }
+}
+
+impl<T: Clone + Send + 'static> Model for List<T> {
+    type Message = ListMsg;
+
+    fn init(&self) -> Option<Cmd<Self::Message>> {
+        self.fetch()
+    }
+
+    fn update(&mut self, msg: Self::Message) -> Option<Cmd<Self::Message>> {
+        // A source may have shrunk since the last update.
+        if self.source.is_some() {
+            self.scroll.clamp(self.display_len(), self.page_size(self.height));
+        }
+
+        match msg {
+            ListMsg::Loaded => return None,
+            ListMsg::Up => self.move_up(),
+            ListMsg::Down => self.move_down(),
+            ListMsg::First => self.move_first(),
+            ListMsg::Last => self.move_last(),
+            ListMsg::PageUp => self.page_up(),
+            ListMsg::PageDown => self.page_down(),
+            ListMsg::InsertFilterChar(c) => {
+                if self.has_filter() {
+                    self.filter.push(c);
+                    self.rebuild_filtered();
+                }
+            },
+            ListMsg::DeleteFilterChar => {
+                if self.has_filter() && !self.filter.is_empty() {
+                    self.filter.pop();
+                    self.rebuild_filtered();
+                }
+            },
+            ListMsg::ClearFilter => {
+                if self.has_filter() {
+                    self.filter.clear();
+                    self.rebuild_filtered();
+                }
+            },
+            ListMsg::Submit => {
+                if self.display_len() > 0 {
+                    self.submitted = true;
+                }
+            },
+            ListMsg::Click(index) => {
+                if index == self.scroll.selected() {
+                    return self.update(ListMsg::Submit);
+                }
+                if index < self.display_len() {
+                    self.scroll.select(index, self.display_len(), self.page_size(self.height));
+                }
+            },
+            ListMsg::Cancel => self.cancelled = true,
+            ListMsg::Focus => self.focused = true,
+            ListMsg::Blur => self.focused = false,
+        }
+        self.fetch()
+    }
+
+    fn view(&self) -> String {
+        self.render_sized(self.height)
+    }
+
+    fn view_in(&self, area: Rect) -> String {
+        self.area.set(area);
+        crate::layout::clip(&self.render_sized(self.fitted_height(area)), area)
+    }
 
     fn handle_event(&self, event: Event) -> Option<Self::Message> {
         if !self.focused {
@@ -530,7 +950,7 @@ impl<T: Clone + Send + 'static> Model for List<T> {
                     KeyCode::PageDown => Some(ListMsg::PageDown),
                     KeyCode::Enter => Some(ListMsg::Submit),
                     KeyCode::Esc => {
-                        if self.filterable && !self.filter.is_empty() {
+                        if self.has_filter() && !self.filter.is_empty() {
                             Some(ListMsg::ClearFilter)
                         } else {
                             Some(ListMsg::Cancel)
@@ -538,7 +958,7 @@ impl<T: Clone + Send + 'static> Model for List<T> {
                     },
                     KeyCode::Backspace => Some(ListMsg::DeleteFilterChar),
                     KeyCode::Char(c) => {
-                        if self.filterable {
+                        if self.has_filter() {
                             Some(ListMsg::InsertFilterChar(c))
                         } else if c == 'q' {
                             Some(ListMsg::Cancel)
@@ -549,6 +969,7 @@ impl<T: Clone + Send + 'static> Model for List<T> {
                     _ => None,
                 }
             },
+            Event::Mouse(mouse) => self.handle_mouse(mouse),
             _ => None,
         }
     }
@@ -599,6 +1020,60 @@ mod tests {
         assert_eq!(list.filtered_count(), 4);
     }
 
+    #[test]
+    fn test_fuzzy_filter() {
+        let mut list: List<String> = List::new("Files").items(vec![
+            "cargo.toml",
+            "src/commands/config.rs",
+            "Config.toml",
+            "café.rs",
+        ]);
+
+        list.set_filter("cfg".to_string());
+        let order: Vec<usize> =
+            (0..list.filtered_count()).map(|i| list.item_index(i).unwrap()).collect();
+        assert_eq!(order, vec![2, 1]);
+        assert_eq!(list.highlights[0], vec![0, 3, 5]);
+
+        // Every matched character is highlighted, including non-ASCII ones.
+        list.set_filter("éf".to_string());
+        assert_eq!(list.filtered_count(), 0);
+        list.set_filter("cé".to_string());
+        let view = list.view();
+        assert_eq!(crate::style::strip_ansi(&view).lines().nth(2), Some("❯ café.rs"));
+        assert_eq!(view.matches(&list.match_highlight_color.to_ansi_fg()).count(), 2);
+    }
+
+    #[test]
+    fn test_match_options() {
+        let items = vec!["Readme", "readme.rs", "src/ready.rs"];
+        let mut list: List<String> = List::new("Files").items(items.clone());
+        list.set_filter("Rea".to_string());
+        assert_eq!(list.filtered_count(), 1);
+
+        let mut list: List<String> =
+            List::new("Files").items(items.clone()).case_matching(CaseMatching::Insensitive);
+        list.set_filter("Rea".to_string());
+        assert_eq!(list.filtered_count(), 3);
+
+        let mut list: List<String> = List::new("Files").items(items).exact(true);
+        list.set_filter("rdme".to_string());
+        assert_eq!(list.filtered_count(), 0);
+        list.set_filter("dy".to_string());
+        assert_eq!(list.current_index(), Some(2));
+    }
+
+    #[test]
+    fn test_item_keywords() {
+        let mut list: List<String> =
+            List::new("Commands").items(vec!["Open file", "Quit"]).item_keywords(1, "exit close");
+        list.set_filter("exit".to_string());
+        assert_eq!(list.current_index(), Some(1));
+        // Keyword matches aren't drawn on the label.
+        assert!(list.highlights[0].is_empty());
+        assert!(!list.view().contains(&list.match_highlight_color.to_ansi_fg()));
+    }
+
     #[test]
     fn test_list_submit() {
         let mut list: List<String> = List::new("Choose").items(vec!["A", "B", "C"]);
@@ -614,16 +1089,16 @@ mod tests {
             .items(vec!["1", "2", "3", "4", "5", "6", "7", "8", "9", "10"])
             .height(3);
 
-        assert_eq!(list.visible_range(), (0, 3));
+        assert_eq!(list.visible_range(list.height), (0, 3));
         list.move_down();
         list.move_down();
         list.move_down();
         assert_eq!(list.cursor(), 3);
-        assert_eq!(list.visible_range(), (1, 4)); // Scrolled down
+        assert_eq!(list.visible_range(list.height), (1, 4)); // Scrolled down
 
         list.move_last();
         assert_eq!(list.cursor(), 9);
-        assert_eq!(list.visible_range(), (7, 10));
+        assert_eq!(list.visible_range(list.height), (7, 10));
     }
 
     #[test]
@@ -633,4 +1108,141 @@ mod tests {
         assert_eq!(list.current(), Some(&1));
         assert_eq!(list.current_label(), Some("One"));
     }
+
+    #[test]
+    fn test_view_in_area() {
+        let mut list = List::new("Pick").items((0..30).map(|i| format!("item {}", i))).height(5);
+        for _ in 0..8 {
+            list.update(ListMsg::Down);
+        }
+
+        // The height caps the area: title, filter, indicators and 5 items.
+        let view = list.view_in(Rect::from_size(20, 12));
+        assert_eq!(view.lines().count(), 9);
+        assert!(crate::style::strip_ansi(&view).contains("item 8"));
+
+        // Without one the list fills the area, less the unused ↑ indicator,
+        // but short lists aren't padded.
+        let list = List::new("Pick").items((0..30).map(|i| format!("item {}", i)));
+        assert_eq!(list.view_in(Rect::from_size(20, 12)).lines().count(), 11);
+        let list = List::new("Pick").items(["a", "b", "c"]).height(5);
+        assert_eq!(list.view_in(Rect::from_size(80, 24)).lines().count(), 5);
+    }
+
+    #[test]
+    fn test_mouse_click() {
+        let mut list = List::new("Pick").items(vec!["a", "b", "c"]);
+        list.set_area(Rect::new(0, 5, 20, 10));
+        let click =
+            |row| Event::Mouse(MouseEvent::new(MouseEventKind::Down(MouseButton::Left), 3, row));
+
+        // Title and filter take the first two rows.
+        assert!(list.handle_event(click(6)).is_none());
+        let msg = list.handle_event(click(8)).unwrap();
+        list.update(msg);
+        assert_eq!(list.cursor(), 1);
+        assert!(!list.is_submitted());
+
+        let msg = list.handle_event(click(8)).unwrap();
+        list.update(msg);
+        assert_eq!(list.selected().map(String::as_str), Some("b"));
+        assert!(list.handle_event(click(2)).is_none());
+    }
+
+    #[test]
+    fn test_delegate() {
+        let mut list = List::new("Packages")
+            .items(vec!["serde", "tokio", "rand", "clap"])
+            .item_description(0, "Serialization")
+            .item_description(1, "Async runtime")
+            .delegate(DefaultDelegate::new())
+            .filterable(false)
+            .height(7);
+
+        // Two lines per item plus a blank line between them: two items fit.
+        let view = crate::style::strip_ansi(&list.view());
+        let lines: Vec<&str> = view.lines().collect();
+        assert_eq!(
+            lines,
+            [
+                "? Packages",
+                "❯ serde",
+                "  Serialization",
+                "",
+                "  tokio",
+                "  Async runtime",
+                "  ↓ 2 more"
+            ]
+        );
+
+        list.update(ListMsg::Down);
+        list.update(ListMsg::Down);
+        assert_eq!(list.cursor(), 2);
+        let view = crate::style::strip_ansi(&list.view());
+        assert!(view.contains("↑ 1 more") && view.contains("❯ rand") && !view.contains("serde"));
+
+        list.update(ListMsg::First);
+        list.set_area(Rect::new(0, 0, 20, 8));
+        let click =
+            |row| Event::Mouse(MouseEvent::new(MouseEventKind::Down(MouseButton::Left), 3, row));
+        assert!(matches!(list.handle_event(click(2)), Some(ListMsg::Click(0))));
+        assert!(list.handle_event(click(3)).is_none());
+        assert!(matches!(list.handle_event(click(5)), Some(ListMsg::Click(1))));
+    }
+
+    #[test]
+    fn test_custom_delegate() {
+        struct Badge;
+
+        impl ListDelegate<u8> for Badge {
+            fn height(&self) -> usize {
+                2
+            }
+
+            fn render(&self, item: &ListItem<'_, u8>) -> String {
+                // Lines past the height are dropped.
+                format!("{} {} [{}]\nextra\nignored", item.cursor, item.label, item.value.unwrap())
+            }
+        }
+
+        let list = List::new("")
+            .items_with_labels(vec![(1, "one".into()), (2, "two".into())])
+            .filterable(false)
+            .delegate(Badge);
+        assert_eq!(list.view(), "❯ one [1]\nextra\n  two [2]\nextra");
+    }
+
+    #[test]
+    fn test_source() {
+        struct Lines;
+
+        impl ListSource for Lines {
+            fn len(&self) -> usize {
+                100_000
+            }
+
+            fn label(&self, index: usize) -> Option<String> {
+                Some(format!("line {index}"))
+            }
+        }
+
+        let mut list: List<String> = List::new("Log").source(Lines).height(3);
+        assert_eq!(list.total_count(), 100_000);
+        let view = crate::style::strip_ansi(&list.view());
+        assert!(!view.contains("/ "));
+        assert!(view.contains("line 2") && !view.contains("line 3"));
+
+        list.update(ListMsg::PageDown);
+        list.update(ListMsg::InsertFilterChar('x'));
+        assert_eq!(list.filter_text(), "");
+        assert_eq!(list.current_index(), Some(2));
+        list.update(ListMsg::Submit);
+        assert!(list.is_submitted());
+        assert!(list.selected().is_none());
+
+        // Owned items replace the source.
+        list.set_items(vec![("a".to_string(), "a".to_string())]);
+        assert_eq!(list.total_count(), 1);
+        assert!(crate::style::strip_ansi(&list.view()).contains('a'));
+    }
 }
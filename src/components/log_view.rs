@@ -0,0 +1,757 @@
+//! Log viewer component for tailing streamed output.
+//!
+//! A [`Viewport`] over a bounded buffer of log lines. Levels are recognized in
+//! `tracing`, `log`/`env_logger` and logfmt output and colored; lines can be
+//! filtered by level and text, following can be paused, and long lines can
+//! wrap. Lines arrive through [`LogView::append`], [`LogViewMsg::Append`] or a
+//! channel passed to [`LogView::receiver`], which [`Model::subscriptions`]
+//! polls.
+//!
+//! Keys: `1`-`5` show errors up to trace and `0` every level, `&` filters by
+//! text, `p` or space pauses, `w` toggles wrapping, and the [`Viewport`] keys
+//! scroll and search.
+//!
+//! # Example
+//!
+//! ```rust
+//! use std::sync::mpsc;
+//!
+//! use teapot::components::{LogLevel, LogView};
+//!
+//! let (tx, rx) = mpsc::channel();
+//! let mut logs = LogView::new(80, 20).capacity(5_000).min_level(LogLevel::Info).receiver(rx);
+//!
+//! logs.append("2024-05-01T12:00:00Z  INFO server: listening on :8080");
+//! logs.append("2024-05-01T12:00:01Z DEBUG server: accepted connection");
+//! assert_eq!((logs.len(), logs.visible_len()), (2, 1));
+//!
+//! // Delivered by the subscription once the program runs.
+//! tx.send("[2024-05-01T12:00:02Z WARN  server] slow request".to_string()).unwrap();
+//! ```
+
+use std::{
+    collections::VecDeque,
+    ops::Range,
+    sync::{Arc, Mutex, mpsc::Receiver},
+    time::Duration,
+};
+
+use super::{Viewport, ViewportMsg};
+use crate::{
+    layout::Rect,
+    runtime::{Cmd, Model, Sub},
+    style::{Color, crop, strip_ansi, wrap},
+    terminal::{Event, KeyCode, KeyModifiers},
+};
+
+/// How often the channel passed to [`LogView::receiver`] is checked.
+const POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+/// Severity of a log line, from least to most severe.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum LogLevel {
+    /// Very detailed tracing.
+    Trace,
+    /// Debugging information.
+    Debug,
+    /// Normal operation.
+    Info,
+    /// Something unexpected.
+    Warn,
+    /// A failure.
+    Error,
+}
+
+impl LogLevel {
+    /// Find the level of a log line.
+    ///
+    /// Looks for an uppercase level (`INFO`, `[WARN]`, `ERROR:`) among the
+    /// first few words, as `tracing` and `env_logger` print it, or a logfmt
+    /// `level=info` field.
+    pub fn detect(line: &str) -> Option<Self> {
+        find_level(&strip_ansi(line)).map(|(level, _)| level)
+    }
+
+    /// The level's name in uppercase.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Trace => "TRACE",
+            Self::Debug => "DEBUG",
+            Self::Info => "INFO",
+            Self::Warn => "WARN",
+            Self::Error => "ERROR",
+        }
+    }
+
+    fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "TRACE" => Some(Self::Trace),
+            "DEBUG" => Some(Self::Debug),
+            "INFO" => Some(Self::Info),
+            "WARN" | "WARNING" => Some(Self::Warn),
+            "ERROR" => Some(Self::Error),
+            _ => None,
+        }
+    }
+}
+
+impl std::fmt::Display for LogLevel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Words searched for a level at the start of a line.
+const LEVEL_WORDS: usize = 4;
+
+/// The level of a plain line and the bytes its name covers.
+fn find_level(line: &str) -> Option<(LogLevel, Range<usize>)> {
+    let punctuation = |c: char| matches!(c, '[' | ']' | '(' | ')' | '<' | '>' | ':' | ',');
+    let mut start = 0;
+    for word in line.split(' ').filter(|word| !word.is_empty()).take(LEVEL_WORDS) {
+        let word_start = start + line[start..].find(word).unwrap_or(0);
+        start = word_start + word.len();
+
+        let trimmed = word.trim_start_matches(punctuation);
+        let lead = word.len() - trimmed.len();
+        let trimmed = trimmed.trim_end_matches(punctuation);
+        let (name, skip) = match trimmed.strip_prefix("level=") {
+            Some(value) => (value.trim_matches('"').to_uppercase(), trimmed.len() - value.len()),
+            None => (trimmed.to_string(), 0),
+        };
+        if let Some(level) = LogLevel::from_name(&name) {
+            let name_start = word_start + lead + skip;
+            return Some((level, name_start..word_start + lead + trimmed.len()));
+        }
+    }
+    None
+}
+
+/// Message type for log view.
+#[derive(Debug, Clone)]
+pub enum LogViewMsg {
+    /// Add lines.
+    Append(String),
+    /// Lines received from the channel.
+    Received(Vec<String>),
+    /// Remove every line.
+    Clear,
+    /// Show only lines at or above a level, or every line.
+    SetMinLevel(Option<LogLevel>),
+    /// Open the filter bar.
+    StartFilter,
+    /// Insert character into the filter.
+    InsertFilterChar(char),
+    /// Delete character from the filter.
+    DeleteFilterChar,
+    /// Close the filter bar, keeping the filter.
+    ConfirmFilter,
+    /// Close the filter bar and clear the filter.
+    ClearFilter,
+    /// Pause or resume following new lines.
+    TogglePause,
+    /// Turn line wrapping on or off.
+    ToggleWrap,
+    /// Resize the log view. Only used before it is drawn into an area.
+    Resize { width: usize, height: usize },
+    /// Scrolling and search.
+    Viewport(ViewportMsg),
+}
+
+/// A buffered log line.
+#[derive(Debug, Clone)]
+struct Entry {
+    text: String,
+    /// Detected or, for continuation lines, inherited level.
+    level: Option<LogLevel>,
+    /// Bytes of the level name to color.
+    level_span: Option<Range<usize>>,
+    /// Viewport lines the entry is drawn on; 0 when filtered out.
+    rows: usize,
+}
+
+/// A log tailing pane.
+#[derive(Debug, Clone)]
+#[must_use = "components do nothing unless used in a view or run with Program"]
+pub struct LogView {
+    viewport: Viewport,
+    entries: VecDeque<Entry>,
+    capacity: usize,
+    width: usize,
+    height: usize,
+    min_level: Option<LogLevel>,
+    filter: String,
+    /// Whether the filter bar is open.
+    filtering: bool,
+    paused: bool,
+    /// Lines shown since pausing.
+    unseen: usize,
+    wrap: bool,
+    focused: bool,
+    /// Colors by level, least severe first.
+    level_colors: [Color; 5],
+    filter_color: Color,
+    receiver: Option<Arc<Mutex<Receiver<String>>>>,
+}
+
+impl Default for LogView {
+    fn default() -> Self {
+        Self::new(80, 24)
+    }
+}
+
+impl LogView {
+    /// Create a log view with dimensions, following new lines.
+    pub fn new(width: usize, height: usize) -> Self {
+        Self {
+            viewport: Viewport::new(width, height).follow(true),
+            entries: VecDeque::new(),
+            capacity: 10_000,
+            width,
+            height,
+            min_level: None,
+            filter: String::new(),
+            filtering: false,
+            paused: false,
+            unseen: 0,
+            wrap: false,
+            focused: true,
+            level_colors: [
+                Color::MUTED,
+                Color::PRIMARY,
+                Color::SUCCESS,
+                Color::WARNING,
+                Color::ERROR,
+            ],
+            filter_color: Color::ACCENT,
+            receiver: None,
+        }
+    }
+
+    /// Set how many lines are kept; the oldest are dropped first.
+    pub fn capacity(mut self, capacity: usize) -> Self {
+        self.capacity = capacity.max(1);
+        self.evict();
+        self
+    }
+
+    /// Show only lines at or above `level`.
+    pub fn min_level(mut self, level: LogLevel) -> Self {
+        self.set_min_level(Some(level));
+        self
+    }
+
+    /// Show only lines containing `filter` (smart case).
+    pub fn filter(mut self, filter: impl Into<String>) -> Self {
+        self.set_filter(filter);
+        self
+    }
+
+    /// Set whether long lines wrap.
+    pub fn wrap(mut self, wrap: bool) -> Self {
+        self.set_wrap(wrap);
+        self
+    }
+
+    /// Set the color of a level's name.
+    pub fn level_color(mut self, level: LogLevel, color: Color) -> Self {
+        self.level_colors[level as usize] = color;
+        self.rebuild();
+        self
+    }
+
+    /// Set the filter bar color.
+    pub fn filter_color(mut self, color: Color) -> Self {
+        self.filter_color = color;
+        self
+    }
+
+    /// Read lines from a channel.
+    ///
+    /// [`Model::subscriptions`] checks the channel and delivers whatever
+    /// arrived as [`LogViewMsg::Received`].
+    pub fn receiver(mut self, receiver: Receiver<String>) -> Self {
+        self.receiver = Some(Arc::new(Mutex::new(receiver)));
+        self
+    }
+
+    /// Add lines.
+    pub fn append(&mut self, text: &str) {
+        let lines = if text.is_empty() { vec![""] } else { text.lines().collect() };
+        for line in lines {
+            self.push(line.to_string());
+        }
+    }
+
+    /// Remove every line.
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.unseen = 0;
+        self.viewport.set_content(String::new());
+    }
+
+    /// Get the number of buffered lines, including filtered-out ones.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Check if no lines are buffered.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Iterate over the buffered lines, oldest first.
+    pub fn lines(&self) -> impl Iterator<Item = &str> {
+        self.entries.iter().map(|entry| entry.text.as_str())
+    }
+
+    /// Get the number of lines that pass the filters.
+    pub fn visible_len(&self) -> usize {
+        self.entries.iter().filter(|entry| self.shows(entry)).count()
+    }
+
+    /// Get the minimum level shown.
+    pub fn current_min_level(&self) -> Option<LogLevel> {
+        self.min_level
+    }
+
+    /// Get the text filter.
+    pub fn filter_text(&self) -> &str {
+        &self.filter
+    }
+
+    /// Check if following is paused.
+    pub fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    /// Check if new lines will scroll into view.
+    pub fn is_following(&self) -> bool {
+        self.viewport.is_following()
+    }
+
+    /// Get the underlying viewport.
+    pub fn viewport(&self) -> &Viewport {
+        &self.viewport
+    }
+
+    /// Set focus state.
+    pub fn set_focused(&mut self, focused: bool) {
+        self.focused = focused;
+    }
+
+    /// Show only lines at or above a level in place, or every line.
+    pub fn set_min_level(&mut self, level: Option<LogLevel>) {
+        self.min_level = level;
+        self.rebuild();
+    }
+
+    /// Set the text filter in place.
+    pub fn set_filter(&mut self, filter: impl Into<String>) {
+        self.filter = filter.into();
+        self.rebuild();
+    }
+
+    /// Pause or resume following in place.
+    ///
+    /// While paused, new lines are buffered without moving the view;
+    /// resuming jumps to the bottom.
+    pub fn set_paused(&mut self, paused: bool) {
+        self.paused = paused;
+        self.unseen = 0;
+        self.viewport.set_follow(!paused);
+        self.layout();
+        if !paused {
+            self.viewport.update(ViewportMsg::ScrollToBottom);
+        }
+    }
+
+    /// Set whether long lines wrap in place.
+    pub fn set_wrap(&mut self, wrap: bool) {
+        self.wrap = wrap;
+        self.rebuild();
+    }
+
+    /// Set the size in place.
+    pub fn set_size(&mut self, width: usize, height: usize) {
+        self.width = width;
+        self.height = height;
+        self.rebuild();
+    }
+
+    /// Buffer a line and draw it if it passes the filters.
+    fn push(&mut self, text: String) {
+        let plain = strip_ansi(&text);
+        let detected = find_level(&plain);
+        // Lines without a level, like stack traces, continue the one before.
+        let level = match &detected {
+            Some((level, _)) => Some(*level),
+            None => self.entries.back().and_then(|entry| entry.level),
+        };
+        // Lines that bring their own colors are left alone.
+        let level_span = detected.filter(|_| plain.len() == text.len()).map(|(_, span)| span);
+        let mut entry = Entry { text, level, level_span, rows: 0 };
+
+        if self.shows(&entry) {
+            let rows = self.render_entry(&entry);
+            entry.rows = rows.len();
+            for row in &rows {
+                self.viewport.append(row);
+            }
+            if self.paused {
+                self.unseen += 1;
+            }
+        }
+        self.entries.push_back(entry);
+        self.evict();
+    }
+
+    /// Drop the oldest lines beyond the capacity.
+    fn evict(&mut self) {
+        while self.entries.len() > self.capacity {
+            if let Some(entry) = self.entries.pop_front() {
+                self.viewport.remove_front(entry.rows);
+            }
+        }
+    }
+
+    /// Whether an entry passes the level and text filters.
+    fn shows(&self, entry: &Entry) -> bool {
+        if let (Some(min), Some(level)) = (self.min_level, entry.level)
+            && level < min
+        {
+            return false;
+        }
+        if self.filter.is_empty() {
+            return true;
+        }
+        let text = strip_ansi(&entry.text);
+        if self.filter.chars().any(char::is_uppercase) {
+            text.contains(&self.filter)
+        } else {
+            text.to_lowercase().contains(&self.filter.to_lowercase())
+        }
+    }
+
+    /// The viewport lines an entry is drawn on.
+    fn render_entry(&self, entry: &Entry) -> Vec<String> {
+        let line = match (&entry.level_span, entry.level) {
+            (Some(span), Some(level)) => format!(
+                "{}{}{}\x1b[0m{}",
+                &entry.text[..span.start],
+                self.level_colors[level as usize].to_ansi_fg(),
+                &entry.text[span.clone()],
+                &entry.text[span.end..]
+            ),
+            _ => entry.text.clone(),
+        };
+        if !self.wrap || self.width == 0 {
+            return vec![line];
+        }
+        let rows = wrap(&line, self.width);
+        if rows.is_empty() { vec![String::new()] } else { rows }
+    }
+
+    /// Redraw every entry after a filter, wrap or size change.
+    fn rebuild(&mut self) {
+        self.layout();
+        let mut content = Vec::new();
+        let mut entries = std::mem::take(&mut self.entries);
+        for entry in &mut entries {
+            let rows = if self.shows(entry) { self.render_entry(entry) } else { Vec::new() };
+            entry.rows = rows.len();
+            content.extend(rows);
+        }
+        self.entries = entries;
+        self.viewport.set_content(content.join("\n"));
+    }
+
+    /// Whether the status line is shown.
+    fn status_rows(&self) -> usize {
+        usize::from(
+            self.paused || self.min_level.is_some() || self.filtering || !self.filter.is_empty(),
+        )
+    }
+
+    /// Size the viewport to leave room for the status line.
+    fn layout(&mut self) {
+        let following = self.viewport.is_following();
+        self.viewport.set_size(self.width, self.height.saturating_sub(self.status_rows()));
+        if following {
+            self.viewport.update(ViewportMsg::ScrollToBottom);
+        }
+    }
+
+    fn status_line(&self) -> String {
+        let mut parts = Vec::new();
+        if self.paused {
+            parts.push(match self.unseen {
+                0 => "paused".to_string(),
+                n => format!("paused, {n} new"),
+            });
+        }
+        if let Some(level) = self.min_level {
+            parts.push(format!("≥ {level}"));
+        }
+        let mut status = format!("{}{}", Color::MUTED.to_ansi_fg(), parts.join(" · "));
+        if self.filtering || !self.filter.is_empty() {
+            if !parts.is_empty() {
+                status.push_str(" · ");
+            }
+            status.push_str(&format!("{}&{}", self.filter_color.to_ansi_fg(), self.filter));
+        }
+        status.push_str("\x1b[0m");
+        status
+    }
+
+    /// Render into a `width` x `height` area.
+    fn render(&self, body: String, width: usize, height: usize) -> String {
+        if self.status_rows() == 0 {
+            return body;
+        }
+        // Keep the status line at the bottom.
+        let mut lines: Vec<&str> =
+            if body.is_empty() { Vec::new() } else { body.split('\n').collect() };
+        lines.resize(lines.len().max(height.saturating_sub(1)), "");
+        let status = crop(&self.status_line(), width);
+        lines.push(&status);
+        lines.join("\n")
+    }
+}
+
+impl Model for LogView {
+    type Message = LogViewMsg;
+
+    fn init(&self) -> Option<Cmd<Self::Message>> {
+        None
+    }
+
+    fn update(&mut self, msg: Self::Message) -> Option<Cmd<Self::Message>> {
+        match msg {
+            LogViewMsg::Append(text) => self.append(&text),
+            LogViewMsg::Received(lines) => {
+                for line in lines {
+                    self.append(&line);
+                }
+            },
+            LogViewMsg::Clear => self.clear(),
+            LogViewMsg::SetMinLevel(level) => self.set_min_level(level),
+            LogViewMsg::StartFilter => {
+                self.filtering = true;
+                self.layout();
+            },
+            LogViewMsg::InsertFilterChar(c) => {
+                self.filter.push(c);
+                self.rebuild();
+            },
+            LogViewMsg::DeleteFilterChar => {
+                self.filter.pop();
+                self.rebuild();
+            },
+            LogViewMsg::ConfirmFilter => {
+                self.filtering = false;
+                self.layout();
+            },
+            LogViewMsg::ClearFilter => {
+                self.filtering = false;
+                self.set_filter(String::new());
+            },
+            LogViewMsg::TogglePause => self.set_paused(!self.paused),
+            LogViewMsg::ToggleWrap => self.set_wrap(!self.wrap),
+            LogViewMsg::Resize { width, height } => self.set_size(width, height),
+            LogViewMsg::Viewport(msg) => {
+                self.viewport.update(msg);
+            },
+        }
+        None
+    }
+
+    fn view(&self) -> String {
+        self.render(self.viewport.view(), self.width, self.height)
+    }
+
+    fn view_in(&self, area: Rect) -> String {
+        let status = self.status_rows() as u16;
+        let body = Rect { height: area.height.saturating_sub(status), ..area };
+        self.render(self.viewport.view_in(body), area.width as usize, area.height as usize)
+    }
+
+    fn handle_event(&self, event: Event) -> Option<Self::Message> {
+        if !self.focused {
+            return None;
+        }
+
+        match event {
+            Event::Key(key) if self.filtering => match key.code {
+                KeyCode::Enter => Some(LogViewMsg::ConfirmFilter),
+                KeyCode::Esc => Some(LogViewMsg::ClearFilter),
+                KeyCode::Backspace => Some(LogViewMsg::DeleteFilterChar),
+                KeyCode::Char(c) if !key.modifiers.contains(KeyModifiers::CONTROL) => {
+                    Some(LogViewMsg::InsertFilterChar(c))
+                },
+                _ => None,
+            },
+            Event::Key(ref key) if !self.viewport.is_searching() => match key.code {
+                KeyCode::Char('&') => Some(LogViewMsg::StartFilter),
+                KeyCode::Char('p' | ' ') => Some(LogViewMsg::TogglePause),
+                KeyCode::Char('w') => Some(LogViewMsg::ToggleWrap),
+                KeyCode::Char('0') => Some(LogViewMsg::SetMinLevel(None)),
+                KeyCode::Char('1') => Some(LogViewMsg::SetMinLevel(Some(LogLevel::Error))),
+                KeyCode::Char('2') => Some(LogViewMsg::SetMinLevel(Some(LogLevel::Warn))),
+                KeyCode::Char('3') => Some(LogViewMsg::SetMinLevel(Some(LogLevel::Info))),
+                KeyCode::Char('4') => Some(LogViewMsg::SetMinLevel(Some(LogLevel::Debug))),
+                KeyCode::Char('5') => Some(LogViewMsg::SetMinLevel(Some(LogLevel::Trace))),
+                KeyCode::Esc if !self.filter.is_empty() => Some(LogViewMsg::ClearFilter),
+                _ => self.viewport.handle_event(event).map(LogViewMsg::Viewport),
+            },
+            // Once drawn with `view_in`, the area decides the size.
+            Event::Resize { width, height } if self.viewport.area().is_none() => {
+                Some(LogViewMsg::Resize { width: width as usize, height: height as usize })
+            },
+            _ => self.viewport.handle_event(event).map(LogViewMsg::Viewport),
+        }
+    }
+
+    fn subscriptions(&self) -> Sub<Self::Message> {
+        match &self.receiver {
+            Some(receiver) => Sub::channel(
+                format!("log-view-{:p}", Arc::as_ptr(receiver)),
+                Arc::clone(receiver),
+                POLL_INTERVAL,
+                LogViewMsg::Received,
+            ),
+            None => Sub::none(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn visible(logs: &LogView) -> Vec<String> {
+        strip_ansi(&logs.view()).lines().map(str::to_string).collect()
+    }
+
+    #[test]
+    fn test_detect_level() {
+        let cases = [
+            ("2024-05-01T12:00:00.123456Z  INFO server: listening", Some(LogLevel::Info)),
+            ("[2024-05-01T12:00:00Z WARN  app::db] slow query", Some(LogLevel::Warn)),
+            ("ERROR: disk full", Some(LogLevel::Error)),
+            ("ts=2024-05-01 level=debug msg=\"cache miss\"", Some(LogLevel::Debug)),
+            ("\x1b[2m2024\x1b[0m \x1b[35mTRACE\x1b[0m poll", Some(LogLevel::Trace)),
+            ("an error occurred", None),
+            ("one two three four ERROR", None),
+        ];
+        for (line, level) in cases {
+            assert_eq!(LogLevel::detect(line), level, "{line}");
+        }
+        assert_eq!(find_level("[x WARN] y"), Some((LogLevel::Warn, 3..7)));
+    }
+
+    #[test]
+    fn test_filters() {
+        let mut logs = LogView::new(40, 10);
+        logs.append("INFO starting\nERROR boom\n  at main.rs:3\nDEBUG Retrying\nplain");
+        assert_eq!(logs.len(), 5);
+
+        // The stack trace line continues the error.
+        logs.set_min_level(Some(LogLevel::Error));
+        assert_eq!(visible(&logs)[..2], ["ERROR boom", "  at main.rs:3"]);
+        assert_eq!(visible(&logs).last().map(String::as_str), Some("≥ ERROR"));
+
+        logs.set_min_level(None);
+        logs.set_filter("retry");
+        assert_eq!(logs.visible_len(), 1);
+        logs.set_filter("Retry");
+        assert_eq!(logs.visible_len(), 1);
+        logs.set_filter("RETRY");
+        assert_eq!(logs.visible_len(), 0);
+
+        // The level name is colored, the rest of the line isn't.
+        logs.set_filter("");
+        let view = logs.view();
+        assert!(view.contains(&format!("{}ERROR\x1b[0m boom", Color::ERROR.to_ansi_fg())));
+    }
+
+    #[test]
+    fn test_capacity_and_follow() {
+        let mut logs = LogView::new(20, 3).capacity(5);
+        for i in 0..8 {
+            logs.update(LogViewMsg::Append(format!("INFO line {i}")));
+        }
+        assert_eq!(logs.len(), 5);
+        assert_eq!(logs.lines().next(), Some("INFO line 3"));
+        assert_eq!(logs.viewport().total_lines(), 5);
+        assert_eq!(visible(&logs), ["INFO line 5", "INFO line 6", "INFO line 7"]);
+
+        logs.update(LogViewMsg::TogglePause);
+        logs.append("INFO line 8");
+        assert_eq!(visible(&logs), ["INFO line 5", "INFO line 6", "paused, 1 new"]);
+
+        logs.update(LogViewMsg::TogglePause);
+        assert!(logs.is_following());
+        assert_eq!(visible(&logs), ["INFO line 6", "INFO line 7", "INFO line 8"]);
+    }
+
+    #[test]
+    fn test_view_in_shorter_area() {
+        let mut logs = LogView::new(40, 24);
+        for i in 0..100 {
+            logs.append(&format!("INFO line {i}"));
+        }
+        let area = Rect::from_size(40, 4);
+        let shown = |logs: &LogView| -> Vec<String> {
+            strip_ansi(&logs.view_in(area)).lines().map(str::to_string).collect()
+        };
+        assert_eq!(shown(&logs), ["INFO line 96", "INFO line 97", "INFO line 98", "INFO line 99"]);
+
+        logs.append("INFO line 100");
+        logs.update(LogViewMsg::SetMinLevel(Some(LogLevel::Info)));
+        assert!(logs.is_following());
+        assert_eq!(shown(&logs), ["INFO line 98", "INFO line 99", "INFO line 100", "≥ INFO"]);
+        assert!(logs.handle_event(Event::Resize { width: 80, height: 50 }).is_none());
+    }
+
+    #[test]
+    fn test_wrap() {
+        let mut logs = LogView::new(10, 5).capacity(2).wrap(true);
+        logs.append("WARN a long warning");
+        assert_eq!(visible(&logs), ["WARN a", "long", "warning"]);
+
+        // Evicting a wrapped line removes all of its rows.
+        logs.append("INFO x");
+        logs.append("INFO y");
+        assert_eq!(visible(&logs), ["INFO x", "INFO y"]);
+    }
+
+    #[test]
+    fn test_keys_and_channel() {
+        let (tx, rx) = std::sync::mpsc::channel();
+        let mut logs = LogView::new(40, 5).receiver(rx);
+        let key = |c| {
+            Event::Key(crate::terminal::KeyEvent {
+                code: KeyCode::Char(c),
+                modifiers: KeyModifiers::NONE,
+            })
+        };
+
+        tx.send("WARN queued".to_string()).unwrap();
+        tx.send("INFO ok".to_string()).unwrap();
+        let entries = logs.subscriptions().into_entries();
+        let msg = (entries[0].msg_fn)().unwrap();
+        logs.update(msg);
+        assert_eq!(logs.len(), 2);
+        assert!((entries[0].msg_fn)().is_none());
+
+        for c in ['2', '&', 'q'] {
+            let msg = logs.handle_event(key(c)).unwrap();
+            logs.update(msg);
+        }
+        assert_eq!(logs.current_min_level(), Some(LogLevel::Warn));
+        assert_eq!(logs.filter_text(), "q");
+        assert_eq!(visible(&logs).last().map(String::as_str), Some("≥ WARN · &q"));
+    }
+}
@@ -0,0 +1,534 @@
+//! Markdown renderer (Glamour equivalent).
+//!
+//! Renders CommonMark into styled terminal text at a given width: headings,
+//! paragraphs, emphasis, lists, task lists, code blocks, block quotes, tables,
+//! rules and links. Colors come from the current [`Theme`](crate::style::Theme).
+//!
+//! Requires the `markdown` feature.
+//!
+//! # Example
+//!
+//! ```rust
+//! use teapot::components::{Markdown, Viewport};
+//!
+//! let notes = "# Release notes\n\n- **Faster** startup\n- Fixed `--help` output";
+//! let mut viewport = Viewport::new(60, 20);
+//! viewport.set_content(Markdown::new().width(60).render(notes));
+//!
+//! // Or render at the viewport's width directly.
+//! viewport.set_markdown(notes);
+//! ```
+
+use pulldown_cmark::{Alignment, Event, Options, Parser, Tag, TagEnd};
+
+use crate::style::{Color, hyperlinks_enabled, slice, truncate_with, width as str_width, wrap};
+
+const RESET: &str = "\x1b[0m";
+
+/// Renders Markdown to styled terminal text.
+#[derive(Debug, Clone)]
+#[must_use = "components do nothing unless used in a view or run with Program"]
+pub struct Markdown {
+    width: usize,
+    heading_color: Color,
+    link_color: Color,
+    code_color: Color,
+    quote_color: Color,
+    border_color: Color,
+}
+
+impl Default for Markdown {
+    fn default() -> Self {
+        Self {
+            width: 80,
+            heading_color: Color::PRIMARY,
+            link_color: Color::PRIMARY,
+            code_color: Color::ACCENT,
+            quote_color: Color::MUTED,
+            border_color: Color::BORDER,
+        }
+    }
+}
+
+impl Markdown {
+    /// Create a renderer with a width of 80 columns.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the width text is wrapped to.
+    pub fn width(mut self, width: usize) -> Self {
+        self.width = width.max(1);
+        self
+    }
+
+    /// Set the heading color.
+    pub fn heading_color(mut self, color: Color) -> Self {
+        self.heading_color = color;
+        self
+    }
+
+    /// Set the link color.
+    pub fn link_color(mut self, color: Color) -> Self {
+        self.link_color = color;
+        self
+    }
+
+    /// Set the inline and block code color.
+    pub fn code_color(mut self, color: Color) -> Self {
+        self.code_color = color;
+        self
+    }
+
+    /// Set the block quote color.
+    pub fn quote_color(mut self, color: Color) -> Self {
+        self.quote_color = color;
+        self
+    }
+
+    /// Set the color of rules, table borders and code block gutters.
+    pub fn border_color(mut self, color: Color) -> Self {
+        self.border_color = color;
+        self
+    }
+
+    /// Render Markdown to lines joined with `\n`.
+    pub fn render(&self, markdown: &str) -> String {
+        let options =
+            Options::ENABLE_TABLES | Options::ENABLE_STRIKETHROUGH | Options::ENABLE_TASKLISTS;
+        let mut renderer = Renderer::new(self);
+        for event in Parser::new_ext(markdown, options) {
+            renderer.event(event);
+        }
+        renderer.lines.join("\n")
+    }
+}
+
+/// A block that indents the blocks inside it.
+enum Container {
+    Quote { started: bool },
+    List { next: Option<u64> },
+    Item { marker: String, first_line: bool },
+}
+
+#[derive(Default)]
+struct TableState {
+    alignments: Vec<Alignment>,
+    rows: Vec<Vec<String>>,
+    header_rows: usize,
+}
+
+struct Renderer<'a> {
+    options: &'a Markdown,
+    lines: Vec<String>,
+    containers: Vec<Container>,
+    /// Styled inline text of the current block.
+    inline: String,
+    /// SGR sequences of open inline styles.
+    styles: Vec<String>,
+    /// Start of each open link in `inline`, with its URL.
+    links: Vec<(usize, String)>,
+    code: Option<String>,
+    table: Option<TableState>,
+    blank_pending: bool,
+}
+
+impl<'a> Renderer<'a> {
+    fn new(options: &'a Markdown) -> Self {
+        Self {
+            options,
+            lines: Vec::new(),
+            containers: Vec::new(),
+            inline: String::new(),
+            styles: Vec::new(),
+            links: Vec::new(),
+            code: None,
+            table: None,
+            blank_pending: false,
+        }
+    }
+
+    fn event(&mut self, event: Event<'_>) {
+        match event {
+            Event::Start(tag) => self.start(tag),
+            Event::End(tag) => self.end(tag),
+            Event::Text(text) => match &mut self.code {
+                Some(code) => code.push_str(&text),
+                None => self.inline.push_str(&text),
+            },
+            Event::Code(code) => {
+                let color = self.options.code_color.to_ansi_fg();
+                self.inline.push_str(&format!("{color}{code}{RESET}{}", self.current_style()));
+            },
+            Event::SoftBreak => self.inline.push(' '),
+            Event::HardBreak => self.inline.push('\n'),
+            Event::Rule => {
+                self.flush_paragraph();
+                let width = self.available_width();
+                let color = self.options.border_color.to_ansi_fg();
+                self.emit(vec![format!("{color}{}{RESET}", "─".repeat(width))]);
+            },
+            Event::TaskListMarker(checked) => {
+                self.inline.push_str(if checked { "[x] " } else { "[ ] " });
+            },
+            // Block HTML keeps its lines and is flushed when the block ends.
+            Event::Html(html) => self.inline.push_str(&html),
+            Event::InlineHtml(html) => self.inline.push_str(html.trim_end()),
+            Event::InlineMath(text) | Event::DisplayMath(text) => self.inline.push_str(&text),
+            Event::FootnoteReference(name) => self.inline.push_str(&format!("[{name}]")),
+        }
+    }
+
+    fn start(&mut self, tag: Tag<'_>) {
+        match tag {
+            Tag::Heading { level, .. } => {
+                self.flush_paragraph();
+                // On the style stack, so inline styles restore it when they end.
+                let color = self.options.heading_color.to_ansi_fg();
+                self.push_style(format!("\x1b[1m{color}"));
+                self.inline.push_str(&format!("{} ", "#".repeat(level as usize)));
+            },
+            Tag::BlockQuote(_) => {
+                self.flush_paragraph();
+                self.containers.push(Container::Quote { started: false });
+            },
+            Tag::CodeBlock(_) => {
+                self.flush_paragraph();
+                self.code = Some(String::new());
+            },
+            Tag::HtmlBlock => self.flush_paragraph(),
+            Tag::List(start) => {
+                self.flush_paragraph();
+                self.containers.push(Container::List { next: start });
+            },
+            Tag::Item => {
+                let marker = match self.containers.last_mut() {
+                    Some(Container::List { next: Some(n) }) => {
+                        *n += 1;
+                        format!("{}. ", *n - 1)
+                    },
+                    _ => {
+                        let depth = self
+                            .containers
+                            .iter()
+                            .filter(|c| matches!(c, Container::List { .. }))
+                            .count();
+                        ["• ", "◦ ", "▪ "][(depth.saturating_sub(1)) % 3].to_string()
+                    },
+                };
+                self.containers.push(Container::Item { marker, first_line: true });
+            },
+            Tag::Table(alignments) => {
+                self.flush_paragraph();
+                self.table = Some(TableState { alignments, ..Default::default() });
+            },
+            Tag::TableHead | Tag::TableRow => {
+                if let Some(table) = &mut self.table {
+                    table.rows.push(Vec::new());
+                }
+            },
+            Tag::TableCell => self.inline.clear(),
+            Tag::Emphasis => self.push_style("\x1b[3m".to_string()),
+            Tag::Strong => self.push_style("\x1b[1m".to_string()),
+            Tag::Strikethrough => self.push_style("\x1b[9m".to_string()),
+            Tag::Link { dest_url, .. } | Tag::Image { dest_url, .. } => {
+                let style = format!("\x1b[4m{}", self.options.link_color.to_ansi_fg());
+                self.push_style(style);
+                self.links.push((self.inline.len(), dest_url.to_string()));
+            },
+            _ => {},
+        }
+    }
+
+    fn end(&mut self, tag: TagEnd) {
+        match tag {
+            TagEnd::Paragraph | TagEnd::HtmlBlock => self.flush_paragraph(),
+            TagEnd::Heading(_) => {
+                self.styles.pop();
+                let text = std::mem::take(&mut self.inline);
+                let lines = wrap(&format!("{text}{RESET}"), self.available_width());
+                self.emit(lines);
+            },
+            TagEnd::BlockQuote(_) => {
+                self.flush_paragraph();
+                self.containers.pop();
+                self.blank_pending = true;
+            },
+            TagEnd::CodeBlock => {
+                let code = self.code.take().unwrap_or_default();
+                self.render_code(&code);
+            },
+            TagEnd::List(_) => {
+                self.flush_paragraph();
+                self.containers.pop();
+                // Nested lists continue their parent item.
+                if !self.containers.iter().any(|c| matches!(c, Container::List { .. })) {
+                    self.blank_pending = true;
+                }
+            },
+            TagEnd::Item => {
+                self.flush_paragraph();
+                self.containers.pop();
+            },
+            TagEnd::TableCell => {
+                let cell = std::mem::take(&mut self.inline);
+                if let Some(row) = self.table.as_mut().and_then(|t| t.rows.last_mut()) {
+                    row.push(cell.trim().to_string());
+                }
+            },
+            TagEnd::TableHead => {
+                if let Some(table) = &mut self.table {
+                    table.header_rows = table.rows.len();
+                }
+            },
+            TagEnd::Table => {
+                if let Some(table) = self.table.take() {
+                    self.render_table(table);
+                }
+            },
+            TagEnd::Emphasis | TagEnd::Strong | TagEnd::Strikethrough => self.pop_style(),
+            TagEnd::Link | TagEnd::Image => {
+                self.pop_style();
+                if let Some((start, url)) = self.links.pop() {
+                    let text = self.inline.split_off(start);
+                    let linked = self.link(&text, &url);
+                    self.inline.push_str(&linked);
+                }
+            },
+            _ => {},
+        }
+    }
+
+    fn push_style(&mut self, style: String) {
+        self.inline.push_str(&style);
+        self.styles.push(style);
+    }
+
+    fn pop_style(&mut self) {
+        self.styles.pop();
+        self.inline.push_str(RESET);
+        self.inline.push_str(&self.current_style());
+    }
+
+    fn current_style(&self) -> String {
+        self.styles.concat()
+    }
+
+    /// Link text to a URL, showing the URL after it when hyperlinks are off.
+    fn link(&self, text: &str, url: &str) -> String {
+        if hyperlinks_enabled() {
+            return crate::style::hyperlink(text, url);
+        }
+        if crate::style::strip_ansi(text) == url || url.is_empty() {
+            return text.to_string();
+        }
+        let muted = Color::MUTED.to_ansi_fg();
+        format!("{text}{muted} ({url}){RESET}{}", self.current_style())
+    }
+
+    /// Emit the pending inline text as a wrapped paragraph.
+    fn flush_paragraph(&mut self) {
+        let text = std::mem::take(&mut self.inline);
+        if crate::style::strip_ansi(&text).trim().is_empty() {
+            return;
+        }
+        let lines = wrap(text.trim_end(), self.available_width());
+        self.emit(lines);
+    }
+
+    fn render_code(&mut self, code: &str) {
+        let gutter = format!("{}│{RESET} ", self.options.border_color.to_ansi_fg());
+        let color = self.options.code_color.to_ansi_fg();
+        let width = self.available_width().saturating_sub(2).max(1);
+
+        let mut lines = Vec::new();
+        for line in crate::style::expand_tabs(code.trim_end_matches('\n'), 4).lines() {
+            // Code is hard-wrapped so no characters are lost or reflowed.
+            let line_width = str_width(line).max(1);
+            for start in (0..line_width).step_by(width) {
+                let chunk = slice(line, start, start + width);
+                lines.push(format!("{gutter}{color}{chunk}{RESET}"));
+            }
+        }
+        self.emit(lines);
+    }
+
+    fn render_table(&mut self, table: TableState) {
+        let columns = table.rows.iter().map(Vec::len).max().unwrap_or(0);
+        if columns == 0 {
+            return;
+        }
+
+        let mut widths = vec![1; columns];
+        for row in &table.rows {
+            for (i, cell) in row.iter().enumerate() {
+                widths[i] = widths[i].max(str_width(cell));
+            }
+        }
+
+        // Shrink the widest columns until the table fits.
+        let available = self.available_width();
+        let chrome = 3 * columns + 1;
+        while widths.iter().sum::<usize>() + chrome > available {
+            let Some(widest) = (0..columns).max_by_key(|&i| widths[i]) else { break };
+            if widths[widest] <= 1 {
+                break;
+            }
+            widths[widest] -= 1;
+        }
+
+        let border = self.options.border_color.to_ansi_fg();
+        let rule = |left: &str, mid: &str, right: &str| {
+            let parts: Vec<String> = widths.iter().map(|w| "─".repeat(w + 2)).collect();
+            format!("{border}{left}{}{right}{RESET}", parts.join(mid))
+        };
+
+        let mut lines = vec![rule("┌", "┬", "┐")];
+        for (index, row) in table.rows.iter().enumerate() {
+            let mut line = format!("{border}│{RESET}");
+            for (i, &column_width) in widths.iter().enumerate() {
+                let cell = row.get(i).map(String::as_str).unwrap_or("");
+                let cell = truncate_with(cell, column_width, "…");
+                let padding = column_width.saturating_sub(str_width(&cell));
+                let (left, right) = match table.alignments.get(i) {
+                    Some(Alignment::Right) => (padding, 0),
+                    Some(Alignment::Center) => (padding / 2, padding - padding / 2),
+                    _ => (0, padding),
+                };
+                let bold = if index < table.header_rows { "\x1b[1m" } else { "" };
+                line.push_str(&format!(
+                    " {}{bold}{cell}{RESET}{} {border}│{RESET}",
+                    " ".repeat(left),
+                    " ".repeat(right)
+                ));
+            }
+            lines.push(line);
+            if index + 1 == table.header_rows && table.rows.len() > table.header_rows {
+                lines.push(rule("├", "┼", "┤"));
+            }
+        }
+        lines.push(rule("└", "┴", "┘"));
+        self.emit(lines);
+    }
+
+    /// Width left after container indentation.
+    fn available_width(&self) -> usize {
+        let indent: usize = self
+            .containers
+            .iter()
+            .map(|c| match c {
+                Container::Quote { .. } => 2,
+                Container::List { .. } => 0,
+                Container::Item { marker, .. } => str_width(marker),
+            })
+            .sum();
+        self.options.width.saturating_sub(indent).max(1)
+    }
+
+    /// Add a block's lines, prefixed by the containers around it.
+    fn emit(&mut self, block: Vec<String>) {
+        let in_list = self.containers.iter().any(|c| matches!(c, Container::List { .. }));
+        if self.blank_pending && !self.lines.is_empty() {
+            let prefix = self.prefix(true);
+            self.lines.push(prefix.trim_end().to_string());
+        }
+        self.blank_pending = !in_list;
+
+        for line in block {
+            let prefix = self.prefix(false);
+            self.lines.push(format!("{prefix}{line}"));
+        }
+    }
+
+    /// Prefix for the next line. Blank lines don't use up item markers.
+    fn prefix(&mut self, blank: bool) -> String {
+        let quote = format!("{}│{RESET} ", self.options.quote_color.to_ansi_fg());
+        let mut prefix = String::new();
+        for container in &mut self.containers {
+            match container {
+                // A blank line before a quote's first line sits outside it.
+                Container::Quote { started } => {
+                    if *started || !blank {
+                        prefix.push_str(&quote);
+                        *started |= !blank;
+                    }
+                },
+                Container::List { .. } => {},
+                Container::Item { marker, first_line } => {
+                    if *first_line && !blank {
+                        prefix.push_str(marker);
+                        *first_line = false;
+                    } else {
+                        prefix.push_str(&" ".repeat(str_width(marker)));
+                    }
+                },
+            }
+        }
+        prefix
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::style::strip_ansi;
+
+    fn plain(markdown: &str, width: usize) -> String {
+        strip_ansi(&Markdown::new().width(width).render(markdown))
+    }
+
+    #[test]
+    fn test_headings_and_paragraphs() {
+        let output = plain("# Title\n\nSome *emphasis* and **strong** text here.\n\n## Next", 20);
+        assert_eq!(output, "# Title\n\nSome emphasis and\nstrong text here.\n\n## Next");
+    }
+
+    #[test]
+    fn test_heading_keeps_style_after_inline() {
+        let output = Markdown::new().width(40).render("# H *em* `c` done");
+        let heading = format!("\x1b[1m{}", Color::PRIMARY.to_ansi_fg());
+        // Emphasis and code end by restoring the heading style.
+        assert!(output.ends_with(&format!("{RESET}{heading} done{RESET}")));
+        assert_eq!(output.matches(&format!("{RESET}{heading}")).count(), 2);
+    }
+
+    #[test]
+    fn test_lists() {
+        let output = plain("- one\n- two\n  - nested\n\n1. first\n2. [x] done", 40);
+        assert_eq!(output, "• one\n• two\n  ◦ nested\n\n1. first\n2. [x] done");
+
+        assert_eq!(plain("- a\n  - b\n- c", 40), "• a\n  ◦ b\n• c");
+        assert_eq!(plain("1. x\n   1. y\n2. z", 40), "1. x\n   1. y\n2. z");
+    }
+
+    #[test]
+    fn test_code_and_quotes() {
+        let output = plain("```\nfn main() {}\n```\n\n> quoted\n> text", 40);
+        assert_eq!(output, "│ fn main() {}\n\n│ quoted text");
+    }
+
+    #[test]
+    fn test_html_block() {
+        let output = plain("<div>\nhi\n</div>\n\nNext paragraph", 40);
+        assert_eq!(output, "<div>\nhi\n</div>\n\nNext paragraph");
+        assert_eq!(plain("Text with <b>inline</b> tags", 40), "Text with <b>inline</b> tags");
+    }
+
+    #[test]
+    fn test_table() {
+        let output = plain("| Name | Qty |\n|------|----:|\n| tea | 2 |", 40);
+        let lines: Vec<&str> = output.lines().collect();
+        assert_eq!(lines[1], "│ Name │ Qty │");
+        assert_eq!(lines[3], "│ tea  │   2 │");
+        assert_eq!(lines.len(), 5);
+    }
+
+    #[test]
+    fn test_wraps_to_width() {
+        let markdown =
+            "> a long quoted paragraph that has to wrap\n\n- a list item that also wraps";
+        let output = Markdown::new().width(16).render(markdown);
+        assert!(output.lines().all(|line| str_width(line) <= 16));
+        assert!(strip_ansi(&output).contains("\n  that also\n  wraps"));
+    }
+}
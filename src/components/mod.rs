@@ -14,6 +14,15 @@
 //!
 //! This pattern is used consistently across all components.
 //!
+//! # Mouse
+//!
+//! With mouse capture on (see `ProgramOptions::fullscreen`), interactive
+//! components react to clicks and the scroll wheel. Mouse events carry
+//! terminal coordinates, so each component remembers where it was drawn in a
+//! [`HitArea`](crate::layout::HitArea): `view_in` records it automatically,
+//! and `set_area` does so for components placed by hand. A component that was
+//! never given an area ignores the mouse.
+//!
 //! This module provides composable widgets that implement the Model trait:
 //!
 //! - [`Spinner`] - Animated loading indicator
@@ -25,7 +34,9 @@
 //! - [`Confirm`] - Yes/No confirmation
 //! - [`Viewport`] - Scrollable content area
 //! - [`List`] - Filterable, paginated list
+//! - [`LogView`] - Log tailing pane with level colors and filtering
 //! - [`Table`] - Scrollable data table
+//! - [`TableSource`] / [`ListSource`] - On-demand rows for virtualized tables and lists
 //! - [`MultiProgress`] - Multiple parallel progress bars
 //! - [`FilePicker`] - File/directory browser
 //! - [`TabBar`] - Horizontal tab bar with keyboard hints
@@ -35,16 +46,21 @@
 //! - [`TaskProgressView`] - Full-screen task progress with worker execution
 //! - [`TitleBar`] - Decorative title bar with slash separators
 //! - [`FooterHints`] - Keyboard shortcut hints footer
+//! - `Markdown` - Markdown renderer for viewports (`markdown` feature)
 
 pub mod confirm;
 pub mod file_picker;
 pub mod footer_hints;
 pub mod list;
+pub mod log_view;
+#[cfg(feature = "markdown")]
+pub mod markdown;
 pub mod modal;
 pub mod multi_progress;
 pub mod multi_select;
 pub mod progress;
 pub mod select;
+pub mod source;
 pub mod spinner;
 pub mod status_badge;
 pub mod tab_bar;
@@ -59,16 +75,20 @@ pub mod viewport;
 pub use confirm::{Confirm, ConfirmMsg};
 pub use file_picker::{FileEntry, FilePicker, FilePickerMsg};
 pub use footer_hints::{FooterHints, FooterHintsMsg};
-pub use list::{List, ListMsg};
+pub use list::{DefaultDelegate, List, ListDelegate, ListItem, ListMsg};
+pub use log_view::{LogLevel, LogView, LogViewMsg};
+#[cfg(feature = "markdown")]
+pub use markdown::Markdown;
 pub use modal::{Modal, ModalBorder, ModalHint};
 pub use multi_progress::{MultiProgress, MultiProgressMsg, Task, TaskStatus};
 pub use multi_select::{MultiSelect, MultiSelectMsg};
 pub use progress::{Progress, ProgressMsg};
 pub use select::{Select, SelectMsg};
+pub use source::{ListSource, TableSource};
 pub use spinner::{Spinner, SpinnerMsg, SpinnerStyle};
 pub use status_badge::{BadgeVariant, StatusBadge, StatusBadgeMsg};
 pub use tab_bar::{Tab, TabBar, TabBarMsg};
-pub use table::{Align, Column, Table, TableMsg};
+pub use table::{Align, CellComparator, Column, ExportFormat, SortKey, SortOrder, Table, TableMsg};
 pub use task_list::{TaskItem, TaskList, TaskListMsg, TaskState};
 pub use task_progress::{
     ConfirmationConfig, HintConfig, Phase, StepExecutor, StepResult, TaskProgressConfig,
@@ -93,3 +113,9 @@ pub enum ComponentMsg {
     /// The component was cancelled.
     Cancel,
 }
+
+/// Lines scrolled per mouse wheel notch.
+pub(crate) const WHEEL_LINES: usize = 3;
+
+/// Columns scrolled per horizontal scroll step.
+pub(crate) const H_SCROLL_STEP: usize = 4;
@@ -18,7 +18,11 @@
 //! let output = modal.render_overlay(80, 24, &background_content);
 //! ```
 
-use crate::{style::Color, util::measure_text};
+use crate::{
+    layout::{Compositor, Layer, Rect},
+    style::{Color, crop, truncate_with},
+    util::measure_text,
+};
 
 /// Border style for the modal.
 #[derive(Debug, Clone, Copy, Default)]
@@ -96,6 +100,8 @@ pub struct Modal {
     hint_desc_color: Color,
     /// Horizontal padding (applied to left and right of content).
     padding: usize,
+    /// Whether to dim the background in overlays.
+    dim_background: bool,
 }
 
 impl Modal {
@@ -109,12 +115,13 @@ impl Modal {
             content: Vec::new(),
             hints: Vec::new(),
             border: ModalBorder::default(),
-            border_color: Color::BrightBlack,
+            border_color: Color::BORDER,
             title_color: Color::Default,
             content_color: Color::Default,
             hint_key_color: Color::Default,
-            hint_desc_color: Color::BrightBlack,
+            hint_desc_color: Color::MUTED,
             padding: 1,
+            dim_background: false,
         }
     }
 
@@ -188,6 +195,12 @@ impl Modal {
         self
     }
 
+    /// Dim the background when rendered with [`render_overlay`](Self::render_overlay).
+    pub fn dim_background(mut self, dim: bool) -> Self {
+        self.dim_background = dim;
+        self
+    }
+
     /// Get the inner width (excluding borders).
     fn inner_width(&self) -> usize {
         self.width.saturating_sub(2) // left + right border
@@ -224,14 +237,10 @@ impl Modal {
                 fill_char,
                 fill_char,
                 self.title,
-                fill_char.repeat(title_w.saturating_sub(self.title.len() + 5)),
+                fill_char.repeat(title_w.saturating_sub(measure_text(&self.title) + 5)),
                 fill_char
             );
-            let truncated = if title_content.len() > title_w {
-                title_content[..title_w].to_string()
-            } else {
-                title_content
-            };
+            let truncated = crop(&title_content, title_w);
             let left_pad = " ".repeat(self.padding);
             let right_pad = " ".repeat(self.padding);
             lines.push(format!(
@@ -248,23 +257,7 @@ impl Modal {
         let content_w = inner_w.saturating_sub(self.padding * 2);
         let left_pad = " ".repeat(self.padding);
         for line in &self.content {
-            let display_line = if measure_text(line) > content_w {
-                // Truncate if too long
-                let mut truncated = String::new();
-                let mut width = 0;
-                for c in line.chars() {
-                    let cw = unicode_width::UnicodeWidthChar::width(c).unwrap_or(0);
-                    if width + cw > content_w.saturating_sub(1) {
-                        truncated.push('…');
-                        break;
-                    }
-                    truncated.push(c);
-                    width += cw;
-                }
-                truncated
-            } else {
-                line.clone()
-            };
+            let display_line = truncate_with(line, content_w, "…");
 
             let right_pad = inner_w.saturating_sub(self.padding + measure_text(&display_line));
             lines.push(format!(
@@ -340,84 +333,38 @@ impl Modal {
 
     /// Render the modal overlaid on background content.
     ///
-    /// The modal is centered on a terminal of `term_width` x `term_height`.
-    /// The background content is shown around the modal.
+    /// The modal is centered on a terminal of `term_width` x `term_height`
+    /// and composited over the background, which stays visible around it.
     pub fn render_overlay(
         &self,
         term_width: usize,
         term_height: usize,
         background: &str,
     ) -> String {
-        let modal_lines = self.render_lines();
-        let modal_height = modal_lines.len();
-        let modal_width = self.width;
-
-        // Calculate centering offsets
-        let start_row = term_height.saturating_sub(modal_height) / 2;
-        let start_col = term_width.saturating_sub(modal_width) / 2;
-
-        // Split background into lines
-        let bg_lines: Vec<&str> = background.lines().collect();
-
-        let mut output = String::new();
-
-        for row in 0..term_height {
-            let line = if row >= start_row && row < start_row + modal_height {
-                // This row contains part of the modal
-                let modal_row = row - start_row;
-                let modal_line = &modal_lines[modal_row];
-
-                // Compose modal line centered at position
-                compose_overlay_line(modal_line, start_col, modal_width, term_width)
-            } else {
-                // Pure background line
-                let bg_line = bg_lines.get(row).copied().unwrap_or("");
-                pad_to_width(bg_line, term_width)
-            };
-
-            output.push_str(&line);
-            if row < term_height - 1 {
-                output.push_str("\r\n");
-            }
-        }
-
-        output
-    }
-}
-
-/// Compose a line with modal overlay on background.
-fn compose_overlay_line(
-    modal_line: &str,
-    start_col: usize,
-    modal_width: usize,
-    term_width: usize,
-) -> String {
-    let reset = "\x1b[0m";
-    let mut result = String::new();
-
-    // Left padding
-    result.push_str(&" ".repeat(start_col));
-
-    // Modal content
-    result.push_str(modal_line);
-    result.push_str(reset);
-
-    // Right padding
-    let right_start = start_col + modal_width;
-    if right_start < term_width {
-        result.push_str(&" ".repeat(term_width - right_start));
+        let clamp = |v: usize| v.min(u16::MAX as usize) as u16;
+        self.view_in(Rect::from_size(clamp(term_width), clamp(term_height)), background)
+            .replace('\n', "\r\n")
     }
 
-    result
-}
-
-/// Pad a line to a given width.
-fn pad_to_width(line: &str, width: usize) -> String {
-    let line_width = measure_text(line);
-    if line_width >= width {
-        line.to_string()
-    } else {
-        format!("{}{}", line, " ".repeat(width - line_width))
+    /// Render the modal centered in `area` over `background`, shrinking it
+    /// to fit.
+    ///
+    /// Lines are joined with `\n`, as in [`Model::view_in`](crate::Model::view_in).
+    pub fn view_in(&self, area: Rect, background: &str) -> String {
+        let fitted = Self {
+            width: self.width.min(area.width as usize),
+            height: self.height.min(area.height as usize),
+            ..self.clone()
+        };
+        Compositor::new(area.width, area.height)
+            .layer(Layer::new(background))
+            .layer(
+                Layer::new(fitted.render_lines().join("\n"))
+                    .centered()
+                    .z(1)
+                    .dim_below(self.dim_background),
+            )
+            .render()
     }
 }
 
@@ -474,4 +421,30 @@ mod tests {
         assert!(double_out.contains("╔"));
         assert!(rounded_out.contains("╭"));
     }
+
+    #[test]
+    fn test_modal_overlay_keeps_background() {
+        let background = "x".repeat(10);
+        let background = [background.as_str(); 5].join("\n");
+        let modal = Modal::new(4, 2).border(ModalBorder::Ascii);
+
+        let output = modal.render_overlay(10, 5, &background);
+        let lines: Vec<String> = output.split("\r\n").map(crate::style::strip_ansi).collect();
+
+        assert_eq!(lines.len(), 5);
+        assert_eq!(modal.render_lines().len(), 2);
+        assert_eq!(lines[0], "xxxxxxxxxx");
+        assert_eq!(lines[1], "xxx+--+xxx");
+        assert_eq!(lines[2], "xxx+--+xxx");
+        assert_eq!(lines[3], "xxxxxxxxxx");
+    }
+
+    #[test]
+    fn test_modal_view_in_shrinks_to_area() {
+        let modal = Modal::new(40, 10).border(ModalBorder::Ascii).content("hello");
+        let output = modal.view_in(Rect::new(5, 5, 8, 4), "");
+        let lines: Vec<String> = output.lines().map(crate::style::strip_ansi).collect();
+
+        assert_eq!(lines, ["+------+", "| hel… |", "+------+", "        "]);
+    }
 }
@@ -129,10 +129,10 @@ impl MultiProgress {
             show_count: false,
             filled_char: '█',
             empty_char: '░',
-            in_progress_color: Color::Cyan,
-            completed_color: Color::Green,
-            failed_color: Color::Red,
-            empty_color: Color::BrightBlack,
+            in_progress_color: Color::PRIMARY,
+            completed_color: Color::SUCCESS,
+            failed_color: Color::ERROR,
+            empty_color: Color::MUTED,
             remove_completed: false,
             show_summary: true,
         }
@@ -418,7 +418,7 @@ impl Model for MultiProgress {
         }
 
         if self.tasks.is_empty() {
-            output.push_str(&format!("{}(no tasks){}", Color::BrightBlack.to_ansi_fg(), "\x1b[0m"));
+            output.push_str(&format!("{}(no tasks){}", Color::MUTED.to_ansi_fg(), "\x1b[0m"));
             return output;
         }
 
@@ -474,7 +474,7 @@ impl Model for MultiProgress {
         if self.show_summary && !self.tasks.is_empty() {
             output.push_str(&format!(
                 "\n\n{}─────────────────────────────{}",
-                Color::BrightBlack.to_ansi_fg(),
+                Color::MUTED.to_ansi_fg(),
                 "\x1b[0m"
             ));
 
@@ -12,12 +12,13 @@
 //! ```
 
 use crate::{
+    layout::{HitArea, Rect},
     runtime::{
         Cmd, Model,
         accessible::{Accessible, AccessibleInput},
     },
     style::Color,
-    terminal::{Event, KeyCode},
+    terminal::{Event, KeyCode, MouseButton, MouseEvent, MouseEventKind},
 };
 
 /// Message type for multi-select.
@@ -29,6 +30,8 @@ pub enum MultiSelectMsg {
     Down,
     /// Toggle current item.
     Toggle,
+    /// Item clicked, by index. Moves the cursor there and toggles it.
+    Click(usize),
     /// Select all items.
     SelectAll,
     /// Deselect all items.
@@ -58,8 +61,13 @@ pub struct MultiSelect<T> {
     unchecked_char: &'static str,
     selected_color: Color,
     checked_color: Color,
+    unchecked_color: Color,
+    error_color: Color,
+    help_color: Color,
     min_selections: Option<usize>,
     max_selections: Option<usize>,
+    /// Where the select was last drawn.
+    area: HitArea,
 }
 
 impl<T: Clone> Default for MultiSelect<T> {
@@ -74,10 +82,14 @@ impl<T: Clone> Default for MultiSelect<T> {
             cursor_char: "❯",
             checked_char: "◉",
             unchecked_char: "○",
-            selected_color: Color::Cyan,
-            checked_color: Color::Green,
+            selected_color: Color::SELECTION,
+            checked_color: Color::SUCCESS,
+            unchecked_color: Color::MUTED,
+            error_color: Color::WARNING,
+            help_color: Color::MUTED,
             min_selections: None,
             max_selections: None,
+            area: HitArea::new(),
         }
     }
 }
@@ -100,6 +112,36 @@ impl<T: Clone> MultiSelect<T> {
         self
     }
 
+    /// Set the cursor row color.
+    pub fn selected_color(mut self, color: Color) -> Self {
+        self.selected_color = color;
+        self
+    }
+
+    /// Set the checked mark color.
+    pub fn checked_color(mut self, color: Color) -> Self {
+        self.checked_color = color;
+        self
+    }
+
+    /// Set the unchecked mark color.
+    pub fn unchecked_color(mut self, color: Color) -> Self {
+        self.unchecked_color = color;
+        self
+    }
+
+    /// Set the color of the minimum-selection warning.
+    pub fn error_color(mut self, color: Color) -> Self {
+        self.error_color = color;
+        self
+    }
+
+    /// Set the help text color.
+    pub fn help_color(mut self, color: Color) -> Self {
+        self.help_color = color;
+        self
+    }
+
     /// Set minimum required selections.
     pub fn min(mut self, min: usize) -> Self {
         self.min_selections = Some(min);
@@ -122,6 +164,21 @@ impl<T: Clone> MultiSelect<T> {
         self.options.iter().filter(|(_, _, s)| *s).count()
     }
 
+    /// Get the display labels of all options, in order.
+    pub fn labels(&self) -> Vec<&str> {
+        self.options.iter().map(|(_, label, _)| label.as_str()).collect()
+    }
+
+    /// Get the minimum required selections, if any.
+    pub fn min_selections(&self) -> Option<usize> {
+        self.min_selections
+    }
+
+    /// Get the maximum allowed selections, if any.
+    pub fn max_selections(&self) -> Option<usize> {
+        self.max_selections
+    }
+
     /// Check if selection meets minimum requirement.
     pub fn meets_minimum(&self) -> bool {
         self.min_selections.is_none_or(|min| self.selected_count() >= min)
@@ -137,6 +194,11 @@ impl<T: Clone> MultiSelect<T> {
         self.submitted
     }
 
+    /// Clear the submitted state so the value can be edited again.
+    pub fn reopen(&mut self) {
+        self.submitted = false;
+    }
+
     /// Check if cancelled.
     pub fn is_cancelled(&self) -> bool {
         self.cancelled
@@ -147,6 +209,36 @@ impl<T: Clone> MultiSelect<T> {
         self.focused = focused;
     }
 
+    /// Check if the select is focused.
+    pub fn is_focused(&self) -> bool {
+        self.focused
+    }
+
+    /// Record where the select is drawn, for mouse hit-testing.
+    ///
+    /// [`Model::view_in`] records this automatically.
+    pub fn set_area(&mut self, area: Rect) {
+        self.area.set(area);
+    }
+
+    /// Where the select was last drawn.
+    pub fn area(&self) -> Option<Rect> {
+        self.area.get()
+    }
+
+    fn handle_mouse(&self, mouse: MouseEvent) -> Option<MultiSelectMsg> {
+        let (_, row) = self.area.hit(mouse.column, mouse.row)?;
+        match mouse.kind {
+            MouseEventKind::Down(MouseButton::Left) => {
+                let index = (row as usize).checked_sub(usize::from(!self.title.is_empty()))?;
+                (index < self.options.len()).then_some(MultiSelectMsg::Click(index))
+            },
+            MouseEventKind::ScrollUp => Some(MultiSelectMsg::Up),
+            MouseEventKind::ScrollDown => Some(MultiSelectMsg::Down),
+            _ => None,
+        }
+    }
+
     fn move_up(&mut self) {
         if self.cursor > 0 {
             self.cursor -= 1;
@@ -213,6 +305,12 @@ impl<T: Clone + Send + 'static> Model for MultiSelect<T> {
             MultiSelectMsg::Up => self.move_up(),
             MultiSelectMsg::Down => self.move_down(),
             MultiSelectMsg::Toggle => self.toggle(),
+            MultiSelectMsg::Click(index) => {
+                if index < self.options.len() {
+                    self.cursor = index;
+                    self.toggle();
+                }
+            },
             MultiSelectMsg::SelectAll => self.select_all(),
             MultiSelectMsg::DeselectAll => self.deselect_all(),
             MultiSelectMsg::Submit => {
@@ -235,7 +333,7 @@ impl<T: Clone + Send + 'static> Model for MultiSelect<T> {
             output.push_str(&format!("? {} ", self.title));
             output.push_str(&format!(
                 "{}({} selected){}",
-                Color::BrightBlack.to_ansi_fg(),
+                self.help_color.to_ansi_fg(),
                 self.selected_count(),
                 "\x1b[0m"
             ));
@@ -251,7 +349,7 @@ impl<T: Clone + Send + 'static> Model for MultiSelect<T> {
             let check_color = if *checked {
                 self.checked_color.to_ansi_fg()
             } else {
-                Color::BrightBlack.to_ansi_fg()
+                self.unchecked_color.to_ansi_fg()
             };
 
             let label_color = if is_cursor {
@@ -286,7 +384,7 @@ impl<T: Clone + Send + 'static> Model for MultiSelect<T> {
         {
             output.push_str(&format!(
                 "\n{}(Select at least {}){}",
-                Color::Yellow.to_ansi_fg(),
+                self.error_color.to_ansi_fg(),
                 min,
                 "\x1b[0m"
             ));
@@ -295,6 +393,11 @@ impl<T: Clone + Send + 'static> Model for MultiSelect<T> {
         output
     }
 
+    fn view_in(&self, area: Rect) -> String {
+        self.area.set(area);
+        self.view()
+    }
+
     fn handle_event(&self, event: Event) -> Option<Self::Message> {
         if !self.focused {
             return None;
@@ -311,6 +414,7 @@ impl<T: Clone + Send + 'static> Model for MultiSelect<T> {
                 KeyCode::Esc | KeyCode::Char('q') => Some(MultiSelectMsg::Cancel),
                 _ => None,
             },
+            Event::Mouse(mouse) => self.handle_mouse(mouse),
             _ => None,
         }
     }
@@ -15,7 +15,7 @@
 
 use crate::{
     runtime::{Cmd, Model},
-    style::Color,
+    style::{Color, Gradient},
     terminal::Event,
 };
 
@@ -46,6 +46,7 @@ pub struct Progress {
     empty_char: char,
     filled_color: Color,
     empty_color: Color,
+    gradient: Option<Gradient>,
     complete: bool,
 }
 
@@ -67,8 +68,9 @@ impl Progress {
             show_count: false,
             filled_char: '█',
             empty_char: '░',
-            filled_color: Color::Cyan,
-            empty_color: Color::BrightBlack,
+            filled_color: Color::PRIMARY,
+            empty_color: Color::MUTED,
+            gradient: None,
             complete: false,
         }
     }
@@ -133,6 +135,15 @@ impl Progress {
         self
     }
 
+    /// Fill the bar with a gradient instead of a solid color.
+    ///
+    /// The gradient spans the full bar width, so it is revealed as progress
+    /// advances.
+    pub fn gradient(mut self, gradient: Gradient) -> Self {
+        self.gradient = Some(gradient);
+        self
+    }
+
     /// Get the current progress as a percentage.
     pub fn percentage(&self) -> f64 {
         if self.total == 0 { 100.0 } else { (self.current as f64 / self.total as f64) * 100.0 }
@@ -196,17 +207,24 @@ impl Model for Progress {
         let filled_count = ((pct / 100.0) * self.width as f64).round() as usize;
         let empty_count = self.width.saturating_sub(filled_count);
 
-        let filled = self.filled_char.to_string().repeat(filled_count);
+        let filled = match &self.gradient {
+            Some(gradient) => gradient
+                .colors(self.width)
+                .iter()
+                .take(filled_count)
+                .map(|color| format!("{}{}", color.to_ansi_fg(), self.filled_char))
+                .collect(),
+            None => {
+                format!(
+                    "{}{}",
+                    self.filled_color.to_ansi_fg(),
+                    self.filled_char.to_string().repeat(filled_count)
+                )
+            },
+        };
         let empty = self.empty_char.to_string().repeat(empty_count);
 
-        let bar = format!(
-            "{}{}{}{}{}",
-            self.filled_color.to_ansi_fg(),
-            filled,
-            self.empty_color.to_ansi_fg(),
-            empty,
-            "\x1b[0m"
-        );
+        let bar = format!("{}{}{}{}", filled, self.empty_color.to_ansi_fg(), empty, "\x1b[0m");
 
         let mut parts = Vec::new();
 
@@ -239,17 +257,22 @@ impl Progress {
         Self::new()
             .filled_char('=')
             .empty_char('-')
-            .filled_color(Color::Green)
-            .empty_color(Color::BrightBlack)
+            .filled_color(Color::SUCCESS)
+            .empty_color(Color::MUTED)
     }
 
-    /// Block style with gradient colors.
+    /// Block style with solid colors.
     pub fn blocks() -> Self {
         Self::new()
             .filled_char('█')
             .empty_char('░')
-            .filled_color(Color::Cyan)
-            .empty_color(Color::BrightBlack)
+            .filled_color(Color::PRIMARY)
+            .empty_color(Color::MUTED)
+    }
+
+    /// Block style with a purple-to-pink gradient fill.
+    pub fn gradient_blocks() -> Self {
+        Self::blocks().gradient(Gradient::new([Color::Rgb(90, 86, 224), Color::Rgb(238, 111, 248)]))
     }
 
     /// Dots style.
@@ -257,8 +280,8 @@ impl Progress {
         Self::new()
             .filled_char('●')
             .empty_char('○')
-            .filled_color(Color::Green)
-            .empty_color(Color::BrightBlack)
+            .filled_color(Color::SUCCESS)
+            .empty_color(Color::MUTED)
     }
 }
 
@@ -296,4 +319,20 @@ mod tests {
         assert!(view.contains("Loading"));
         assert!(view.contains("50%"));
     }
+
+    #[test]
+    fn test_progress_gradient() {
+        let red = Color::Rgb(255, 0, 0);
+        let blue = Color::Rgb(0, 0, 255);
+        let progress = Progress::new()
+            .width(4)
+            .current(50)
+            .gradient(Gradient::new([red.clone(), blue.clone()]));
+        let view = progress.view();
+        assert!(view.contains(&red.to_ansi_fg()));
+        assert!(!view.contains(&blue.to_ansi_fg()));
+
+        let view = progress.current(100).view();
+        assert!(view.contains(&blue.to_ansi_fg()));
+    }
 }
@@ -12,12 +12,13 @@
 //! ```
 
 use crate::{
+    layout::{HitArea, Rect},
     runtime::{
         Cmd, Model,
         accessible::{Accessible, AccessibleInput},
     },
     style::Color,
-    terminal::{Event, KeyCode},
+    terminal::{Event, KeyCode, MouseButton, MouseEvent, MouseEventKind},
 };
 
 /// Message type for select.
@@ -33,6 +34,8 @@ pub enum SelectMsg {
     Last,
     /// Submit selection.
     Submit,
+    /// Option clicked, by index. Clicking the current option submits it.
+    Click(usize),
     /// Cancel selection.
     Cancel,
     /// Focus the select.
@@ -54,6 +57,8 @@ pub struct Select<T> {
     cursor_char: &'static str,
     selected_color: Color,
     unselected_color: Color,
+    /// Where the select was last drawn.
+    area: HitArea,
 }
 
 impl<T: Clone> Default for Select<T> {
@@ -66,8 +71,9 @@ impl<T: Clone> Default for Select<T> {
             submitted: false,
             cancelled: false,
             cursor_char: "❯",
-            selected_color: Color::Cyan,
+            selected_color: Color::SELECTION,
             unselected_color: Color::Default,
+            area: HitArea::new(),
         }
     }
 }
@@ -96,6 +102,12 @@ impl<T: Clone> Select<T> {
         self
     }
 
+    /// Set the unselected item color.
+    pub fn unselected_color(mut self, color: Color) -> Self {
+        self.unselected_color = color;
+        self
+    }
+
     /// Get the current cursor position.
     pub fn cursor(&self) -> usize {
         self.cursor
@@ -115,11 +127,21 @@ impl<T: Clone> Select<T> {
         self.options.get(self.cursor).map(|(v, _)| v)
     }
 
+    /// Get the display labels of all options, in order.
+    pub fn labels(&self) -> Vec<&str> {
+        self.options.iter().map(|(_, label)| label.as_str()).collect()
+    }
+
     /// Check if submitted.
     pub fn is_submitted(&self) -> bool {
         self.submitted
     }
 
+    /// Clear the submitted state so the value can be edited again.
+    pub fn reopen(&mut self) {
+        self.submitted = false;
+    }
+
     /// Check if cancelled.
     pub fn is_cancelled(&self) -> bool {
         self.cancelled
@@ -130,6 +152,36 @@ impl<T: Clone> Select<T> {
         self.focused = focused;
     }
 
+    /// Check if the select is focused.
+    pub fn is_focused(&self) -> bool {
+        self.focused
+    }
+
+    /// Record where the select is drawn, for mouse hit-testing.
+    ///
+    /// [`Model::view_in`] records this automatically.
+    pub fn set_area(&mut self, area: Rect) {
+        self.area.set(area);
+    }
+
+    /// Where the select was last drawn.
+    pub fn area(&self) -> Option<Rect> {
+        self.area.get()
+    }
+
+    fn handle_mouse(&self, mouse: MouseEvent) -> Option<SelectMsg> {
+        let (_, row) = self.area.hit(mouse.column, mouse.row)?;
+        match mouse.kind {
+            MouseEventKind::Down(MouseButton::Left) => {
+                let index = (row as usize).checked_sub(usize::from(!self.title.is_empty()))?;
+                (index < self.options.len()).then_some(SelectMsg::Click(index))
+            },
+            MouseEventKind::ScrollUp => Some(SelectMsg::Up),
+            MouseEventKind::ScrollDown => Some(SelectMsg::Down),
+            _ => None,
+        }
+    }
+
     fn move_up(&mut self) {
         if self.cursor > 0 {
             self.cursor -= 1;
@@ -175,6 +227,13 @@ impl<T: Clone + Send + 'static> Model for Select<T> {
             SelectMsg::First => self.cursor = 0,
             SelectMsg::Last => self.cursor = self.options.len().saturating_sub(1),
             SelectMsg::Submit => self.submitted = true,
+            SelectMsg::Click(index) => {
+                if index == self.cursor {
+                    self.submitted = true;
+                } else if index < self.options.len() {
+                    self.cursor = index;
+                }
+            },
             SelectMsg::Cancel => self.cancelled = true,
             SelectMsg::Focus => self.focused = true,
             SelectMsg::Blur => self.focused = false,
@@ -221,6 +280,11 @@ impl<T: Clone + Send + 'static> Model for Select<T> {
         output
     }
 
+    fn view_in(&self, area: Rect) -> String {
+        self.area.set(area);
+        self.view()
+    }
+
     fn handle_event(&self, event: Event) -> Option<Self::Message> {
         if !self.focused {
             return None;
@@ -236,6 +300,7 @@ impl<T: Clone + Send + 'static> Model for Select<T> {
                 KeyCode::Esc | KeyCode::Char('q') => Some(SelectMsg::Cancel),
                 _ => None,
             },
+            Event::Mouse(mouse) => self.handle_mouse(mouse),
             _ => None,
         }
     }
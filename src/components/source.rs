@@ -0,0 +1,127 @@
+//! Data sources for virtualized tables and lists.
+//!
+//! A [`Table`](super::Table) or [`List`](super::List) normally owns all of
+//! its rows. For very large or remote data, give it a source instead: the
+//! component only asks for the rows it is about to draw, and a source can
+//! load pages in the background and report back with a [`Cmd`].
+//!
+//! # Example
+//!
+//! ```rust
+//! use teapot::components::{Column, Table, TableSource};
+//!
+//! struct Squares;
+//!
+//! impl TableSource for Squares {
+//!     fn len(&self) -> usize {
+//!         10_000_000
+//!     }
+//!
+//!     fn row(&self, index: usize) -> Option<Vec<String>> {
+//!         Some(vec![index.to_string(), (index * index).to_string()])
+//!     }
+//! }
+//!
+//! let table = Table::new()
+//!     .columns(vec![Column::new("n").width(8), Column::new("n²").width(16)])
+//!     .source(Squares);
+//! ```
+
+use std::{ops::Range, sync::Arc};
+
+use crate::runtime::Cmd;
+
+/// Rows for a [`Table`](super::Table), produced on demand.
+pub trait TableSource: Send + Sync {
+    /// Total number of rows.
+    fn len(&self) -> usize;
+
+    /// Check if there are no rows.
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// The cells of row `index`, or `None` while it is still loading.
+    fn row(&self, index: usize) -> Option<Vec<String>>;
+
+    /// Start loading the rows in `range`, which the table is about to draw.
+    ///
+    /// Return a command that completes once they are available so the table
+    /// redraws. The default does nothing, for sources that are always ready.
+    fn fetch(&self, range: Range<usize>) -> Option<Cmd<()>> {
+        let _ = range;
+        None
+    }
+}
+
+/// Item labels for a [`List`](super::List), produced on demand.
+pub trait ListSource: Send + Sync {
+    /// Total number of items.
+    fn len(&self) -> usize;
+
+    /// Check if there are no items.
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// The label of item `index`, or `None` while it is still loading.
+    fn label(&self, index: usize) -> Option<String>;
+
+    /// Start loading the items in `range`, which the list is about to draw.
+    ///
+    /// Return a command that completes once they are available so the list
+    /// redraws. The default does nothing, for sources that are always ready.
+    fn fetch(&self, range: Range<usize>) -> Option<Cmd<()>> {
+        let _ = range;
+        None
+    }
+}
+
+impl TableSource for Vec<Vec<String>> {
+    fn len(&self) -> usize {
+        self.len()
+    }
+
+    fn row(&self, index: usize) -> Option<Vec<String>> {
+        self.get(index).cloned()
+    }
+}
+
+impl ListSource for Vec<String> {
+    fn len(&self) -> usize {
+        self.len()
+    }
+
+    fn label(&self, index: usize) -> Option<String> {
+        self.get(index).cloned()
+    }
+}
+
+/// A shared source handle that components can clone and debug-print.
+pub(crate) struct Shared<S: ?Sized>(pub(crate) Arc<S>);
+
+impl<S: ?Sized> Clone for Shared<S> {
+    fn clone(&self) -> Self {
+        Self(Arc::clone(&self.0))
+    }
+}
+
+impl std::fmt::Debug for Shared<dyn TableSource> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TableSource").field("len", &self.0.len()).finish()
+    }
+}
+
+impl std::fmt::Debug for Shared<dyn ListSource> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ListSource").field("len", &self.0.len()).finish()
+    }
+}
+
+/// Rows to request for a viewport: the visible ones plus the next page.
+pub(crate) fn prefetch_range((start, end): (usize, usize), len: usize) -> Range<usize> {
+    // The source may have shrunk since the range was computed.
+    let end = end.min(len);
+    let start = start.min(end);
+    start..(end + end.saturating_sub(start)).min(len)
+}
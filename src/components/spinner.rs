@@ -107,7 +107,7 @@ impl Spinner {
             style: SpinnerStyle::default(),
             frame: 0,
             message: String::new(),
-            color: Color::Cyan,
+            color: Color::PRIMARY,
             running: true,
         }
     }
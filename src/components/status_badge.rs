@@ -54,11 +54,11 @@ impl BadgeVariant {
     /// Get the color for this variant.
     pub fn color(&self) -> Color {
         match self {
-            BadgeVariant::Success => Color::Green,
-            BadgeVariant::Error => Color::Red,
-            BadgeVariant::Warning => Color::Yellow,
-            BadgeVariant::Info => Color::Blue,
-            BadgeVariant::Neutral => Color::BrightBlack,
+            BadgeVariant::Success => Color::SUCCESS,
+            BadgeVariant::Error => Color::ERROR,
+            BadgeVariant::Warning => Color::WARNING,
+            BadgeVariant::Info => Color::PRIMARY,
+            BadgeVariant::Neutral => Color::MUTED,
         }
     }
 }
@@ -224,9 +224,9 @@ mod tests {
 
     #[test]
     fn test_variant_colors() {
-        assert!(matches!(BadgeVariant::Success.color(), Color::Green));
-        assert!(matches!(BadgeVariant::Error.color(), Color::Red));
-        assert!(matches!(BadgeVariant::Warning.color(), Color::Yellow));
+        assert_eq!(BadgeVariant::Success.color(), Color::SUCCESS);
+        assert_eq!(BadgeVariant::Error.color(), Color::ERROR);
+        assert_eq!(BadgeVariant::Warning.color(), Color::WARNING);
     }
 
     #[test]
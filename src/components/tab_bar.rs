@@ -18,9 +18,10 @@
 //! ```
 
 use crate::{
+    layout::{HitArea, Rect},
     runtime::{Cmd, Model},
-    style::Color,
-    terminal::{Event, KeyCode},
+    style::{Color, width},
+    terminal::{Event, KeyCode, MouseButton, MouseEventKind},
 };
 
 /// A single tab in the tab bar.
@@ -77,6 +78,8 @@ pub struct TabBar {
     key_color: Color,
     separator: String,
     width: Option<usize>,
+    /// Where the tab bar was last drawn.
+    area: HitArea,
 }
 
 impl Default for TabBar {
@@ -84,12 +87,13 @@ impl Default for TabBar {
         Self {
             tabs: Vec::new(),
             selected: String::new(),
-            active_color: Color::Cyan,
+            active_color: Color::PRIMARY,
             active_bg_color: None,
-            inactive_color: Color::BrightBlack,
-            key_color: Color::Cyan,
+            inactive_color: Color::MUTED,
+            key_color: Color::PRIMARY,
             separator: " ".to_string(),
             width: None,
+            area: HitArea::new(),
         }
     }
 }
@@ -176,6 +180,31 @@ impl TabBar {
         self.selected = id.into();
     }
 
+    /// Record where the tab bar is drawn, for mouse hit-testing.
+    ///
+    /// [`Model::view_in`] records this automatically.
+    pub fn set_area(&mut self, area: Rect) {
+        self.area.set(area);
+    }
+
+    /// Where the tab bar was last drawn.
+    pub fn area(&self) -> Option<Rect> {
+        self.area.get()
+    }
+
+    /// Get the tab drawn at column `x` of the bar.
+    pub fn tab_at(&self, x: u16) -> Option<&Tab> {
+        let mut left = 0;
+        for tab in &self.tabs {
+            let right = left + width(&tab.label);
+            if (left..right).contains(&(x as usize)) {
+                return Some(tab);
+            }
+            left = right + width(&self.separator);
+        }
+        None
+    }
+
     /// Select the next tab.
     fn select_next(&mut self) {
         if let Some(idx) = self.selected_index() {
@@ -286,6 +315,11 @@ impl Model for TabBar {
         output
     }
 
+    fn view_in(&self, area: Rect) -> String {
+        self.area.set(area);
+        self.view()
+    }
+
     fn handle_event(&self, event: Event) -> Option<Self::Message> {
         match event {
             Event::Key(key) => match key.code {
@@ -297,6 +331,13 @@ impl Model for TabBar {
                 },
                 _ => None,
             },
+            Event::Mouse(mouse) if mouse.kind == MouseEventKind::Down(MouseButton::Left) => {
+                let (x, y) = self.area.hit(mouse.column, mouse.row)?;
+                if y > 0 {
+                    return None;
+                }
+                self.tab_at(x).map(|tab| TabBarMsg::Select(tab.id.clone()))
+            },
             _ => None,
         }
     }
@@ -305,6 +346,7 @@ impl Model for TabBar {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::terminal::MouseEvent;
 
     #[test]
     fn test_tab_creation() {
@@ -359,4 +401,19 @@ mod tests {
         assert_eq!(bar.tab_for_key('s'), Some("services"));
         assert_eq!(bar.tab_for_key('x'), None);
     }
+
+    #[test]
+    fn test_tab_click() {
+        let mut bar = TabBar::new().tabs(vec![Tab::new("a", "Alpha"), Tab::new("b", "Beta")]);
+        bar.set_area(Rect::new(0, 2, 20, 1));
+        let click = |column, row| {
+            Event::Mouse(MouseEvent::new(MouseEventKind::Down(MouseButton::Left), column, row))
+        };
+
+        assert_eq!(bar.tab_at(5).map(|t| t.id.as_str()), None);
+        assert!(bar.handle_event(click(7, 0)).is_none());
+        let msg = bar.handle_event(click(7, 2)).unwrap();
+        bar.update(msg);
+        assert_eq!(bar.selected_id(), "b");
+    }
 }
@@ -1,6 +1,15 @@
 //! Table component for displaying tabular data.
 //!
 //! A scrollable data table with keyboard navigation and column support.
+//! Columns with a [`SortKey`] can be sorted with `s` or a header click, and
+//! `/` opens a filter bar that narrows the rows. With
+//! [`Table::multi_select`], a checkbox column lets users pick several rows
+//! for a bulk action.
+//!
+//! Number keys `1`-`9` show and hide columns. In cell selection mode `<` and
+//! `>` resize the current column, and dragging a header border resizes with
+//! the mouse. [`Table::frozen_columns`] keeps leading columns in place while
+//! the rest scroll horizontally.
 //!
 //! # Example
 //!
@@ -21,12 +30,30 @@
 //!     .height(10);
 //! ```
 
+use std::{
+    borrow::Cow,
+    cmp::Ordering,
+    collections::{BTreeSet, HashMap},
+    io::{self, Write},
+    ops::Range,
+    sync::Arc,
+};
+
+use super::{
+    H_SCROLL_STEP, WHEEL_LINES,
+    source::{Shared, TableSource, prefetch_range},
+};
 use crate::{
+    layout::{HitArea, Rect},
     runtime::{Cmd, Model},
-    style::Color,
-    terminal::{Event, KeyCode, KeyModifiers},
+    style::{Color, crop, hyperlink, slice, strip_ansi, width},
+    terminal::{Event, KeyCode, KeyModifiers, MouseButton, MouseEvent, MouseEventKind},
+    util::ScrollState,
 };
 
+/// Width of the checkbox column's `[x]` cell.
+const CHECKBOX_WIDTH: usize = 3;
+
 /// Column alignment.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -41,7 +68,11 @@ pub enum Align {
 }
 
 /// Column definition for the table.
+///
+/// Build it with [`Column::new`] and the builder methods; fields may be
+/// added in minor releases.
 #[derive(Debug, Clone)]
+#[non_exhaustive]
 #[must_use = "components do nothing unless used in a view or run with Program"]
 pub struct Column {
     /// Column header text.
@@ -52,12 +83,29 @@ pub struct Column {
     pub align: Align,
     /// Whether this column should grow to fill remaining space.
     pub grow: bool,
+    /// How cells compare when sorting by this column (`None` = not sortable).
+    pub sort: Option<SortKey>,
+    /// Smallest width when auto-sized or resized (0 = no minimum).
+    pub min_width: usize,
+    /// Largest width when auto-sized, grown or resized (0 = no maximum).
+    pub max_width: usize,
+    /// Whether the column is hidden.
+    pub hidden: bool,
 }
 
 impl Column {
     /// Create a new column with a title.
     pub fn new(title: impl Into<String>) -> Self {
-        Self { title: title.into(), width: 0, align: Align::Left, grow: false }
+        Self {
+            title: title.into(),
+            width: 0,
+            align: Align::Left,
+            grow: false,
+            sort: None,
+            min_width: 0,
+            max_width: 0,
+            hidden: false,
+        }
     }
 
     /// Set the column width.
@@ -80,6 +128,203 @@ impl Column {
         self.grow = true;
         self
     }
+
+    /// Make the column sortable, comparing cells with `key`.
+    pub fn sort_key(mut self, key: SortKey) -> Self {
+        self.sort = Some(key);
+        self
+    }
+
+    /// Set the smallest width for an auto-sized or resized column.
+    pub fn min_width(mut self, width: usize) -> Self {
+        self.min_width = width;
+        self
+    }
+
+    /// Set the largest width for an auto-sized, growing or resized column.
+    pub fn max_width(mut self, width: usize) -> Self {
+        self.max_width = width;
+        self
+    }
+
+    /// Hide the column. Users can show it again with its number key.
+    pub fn hidden(mut self, hidden: bool) -> Self {
+        self.hidden = hidden;
+        self
+    }
+
+    /// Clamp a width to the column's bounds.
+    fn clamp_width(&self, width: usize) -> usize {
+        let width = width.max(self.min_width).max(1);
+        if self.max_width > 0 { width.min(self.max_width.max(1)) } else { width }
+    }
+}
+
+/// Type alias for a custom cell comparator.
+pub type CellComparator = Arc<dyn Fn(&str, &str) -> Ordering + Send + Sync>;
+
+/// How a column's cells are compared when sorting.
+#[derive(Clone)]
+pub enum SortKey {
+    /// Plain string order.
+    Lexical,
+    /// Numeric value; cells that aren't numbers sort after those that are.
+    Numeric,
+    /// Case-insensitive, with runs of digits compared as numbers, so `file2`
+    /// sorts before `file10`.
+    Natural,
+    /// A custom comparator.
+    Custom(CellComparator),
+}
+
+impl SortKey {
+    /// Sort with a custom comparator.
+    pub fn custom<F>(compare: F) -> Self
+    where
+        F: Fn(&str, &str) -> Ordering + Send + Sync + 'static,
+    {
+        Self::Custom(Arc::new(compare))
+    }
+
+    /// Compare two cells.
+    pub fn compare(&self, a: &str, b: &str) -> Ordering {
+        match self {
+            Self::Lexical => a.cmp(b),
+            Self::Numeric => {
+                let parse = |s: &str| s.trim().parse::<f64>().ok().filter(|n| !n.is_nan());
+                match (parse(a), parse(b)) {
+                    (Some(x), Some(y)) => x.total_cmp(&y),
+                    (Some(_), None) => Ordering::Less,
+                    (None, Some(_)) => Ordering::Greater,
+                    (None, None) => a.cmp(b),
+                }
+            },
+            Self::Natural => natural_cmp(a, b),
+            Self::Custom(compare) => compare(a, b),
+        }
+    }
+}
+
+impl std::fmt::Debug for SortKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Lexical => f.write_str("Lexical"),
+            Self::Numeric => f.write_str("Numeric"),
+            Self::Natural => f.write_str("Natural"),
+            Self::Custom(_) => f.write_str("Custom(..)"),
+        }
+    }
+}
+
+/// Quote a CSV field if it contains a separator, quote or line break.
+fn csv_field(text: &str) -> Cow<'_, str> {
+    if text.contains([',', '"', '\n', '\r']) {
+        Cow::Owned(format!("\"{}\"", text.replace('"', "\"\"")))
+    } else {
+        Cow::Borrowed(text)
+    }
+}
+
+/// Replace the characters TSV can't hold with spaces.
+fn tsv_field(text: &str) -> Cow<'_, str> {
+    if text.contains(['\t', '\n', '\r']) {
+        Cow::Owned(text.replace(['\t', '\n', '\r'], " "))
+    } else {
+        Cow::Borrowed(text)
+    }
+}
+
+/// Encode a string as a JSON string literal.
+fn json_string(text: &str) -> String {
+    let mut out = String::with_capacity(text.len() + 2);
+    out.push('"');
+    for c in text.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if c.is_control() => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+/// Escape pipes and flatten line breaks for a Markdown table cell.
+fn markdown_cell(text: &str) -> String {
+    text.replace('|', "\\|").replace("\r\n", " ").replace(['\n', '\r'], " ")
+}
+
+/// Compare strings case-insensitively, treating digit runs as numbers.
+fn natural_cmp(a: &str, b: &str) -> Ordering {
+    let (mut a, mut b) = (a.chars().peekable(), b.chars().peekable());
+    loop {
+        let (x, y) = match (a.peek(), b.peek()) {
+            (None, None) => return Ordering::Equal,
+            (None, Some(_)) => return Ordering::Less,
+            (Some(_), None) => return Ordering::Greater,
+            (Some(&x), Some(&y)) => (x, y),
+        };
+
+        let ordering = if x.is_ascii_digit() && y.is_ascii_digit() {
+            let digits = |chars: &mut std::iter::Peekable<std::str::Chars>| {
+                let mut run = String::new();
+                while let Some(c) = chars.next_if(char::is_ascii_digit) {
+                    run.push(c);
+                }
+                run
+            };
+            let (x, y) = (digits(&mut a), digits(&mut b));
+            let (xs, ys) = (x.trim_start_matches('0'), y.trim_start_matches('0'));
+            xs.len().cmp(&ys.len()).then_with(|| xs.cmp(ys)).then_with(|| x.len().cmp(&y.len()))
+        } else {
+            a.next();
+            b.next();
+            x.to_lowercase().cmp(y.to_lowercase())
+        };
+
+        if ordering != Ordering::Equal {
+            return ordering;
+        }
+    }
+}
+
+/// Sort direction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SortOrder {
+    /// Smallest first.
+    Ascending,
+    /// Largest first.
+    Descending,
+}
+
+/// Output format for [`Table::export`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ExportFormat {
+    /// Comma-separated values with a header row, quoted as in RFC 4180.
+    Csv,
+    /// Tab-separated values with a header row. Tabs and line breaks inside
+    /// cells become spaces.
+    Tsv,
+    /// A JSON array with one object per row, keyed by column title.
+    Json,
+    /// A GitHub-flavored Markdown table, aligned like the columns.
+    Markdown,
+}
+
+/// A per-column filter predicate.
+#[derive(Clone)]
+struct CellFilter(Arc<dyn Fn(&str) -> bool + Send + Sync>);
+
+impl std::fmt::Debug for CellFilter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("CellFilter(..)")
+    }
 }
 
 /// Message type for table.
@@ -103,6 +348,63 @@ pub enum TableMsg {
     Right,
     /// Submit selection.
     Submit,
+    /// Sort by a column: ascending, then descending, then unsorted.
+    SortBy(usize),
+    /// Step through the sortable columns, each ascending then descending,
+    /// and finally back to unsorted.
+    CycleSort,
+    /// Open the filter bar.
+    StartFilter,
+    /// Insert character into filter.
+    InsertFilterChar(char),
+    /// Delete character from filter.
+    DeleteFilterChar,
+    /// Close the filter bar, keeping the filter.
+    ApplyFilter,
+    /// Clear the filter and close the filter bar.
+    ClearFilter,
+    /// Cell clicked. `col` is `None` when the click landed on a border.
+    /// Clicking the current row (or cell, in cell selection mode) submits it.
+    Click {
+        /// Row index.
+        row: usize,
+        /// Column index.
+        col: Option<usize>,
+    },
+    /// Check or uncheck a row, by position in the sorted and filtered rows,
+    /// and move the cursor to it.
+    ToggleRow(usize),
+    /// Check every row from the last toggled row (or the cursor) to a
+    /// position, and move the cursor there.
+    SelectTo(usize),
+    /// Check every row that passes the filter, or uncheck them if they
+    /// already are.
+    SelectAll,
+    /// Uncheck every row.
+    ClearSelection,
+    /// Set a column's width.
+    SetColumnWidth {
+        /// Column index.
+        col: usize,
+        /// New width, clamped to the column's bounds.
+        width: usize,
+    },
+    /// Start dragging a column's right border to resize it.
+    StartResize(usize),
+    /// Stop resizing.
+    EndResize,
+    /// Show or hide a column. The last visible column can't be hidden.
+    ToggleColumn(usize),
+    /// Scroll the rows up by a number of lines without moving the cursor.
+    ScrollUp(usize),
+    /// Scroll the rows down by a number of lines without moving the cursor.
+    ScrollDown(usize),
+    /// Scroll the unfrozen columns left by a number of cells.
+    ScrollLeft(usize),
+    /// Scroll the unfrozen columns right by a number of cells.
+    ScrollRight(usize),
+    /// Rows requested from the source have arrived.
+    Loaded,
     /// Cancel selection.
     Cancel,
     /// Focus the table.
@@ -117,18 +419,49 @@ pub enum TableMsg {
 pub struct Table {
     columns: Vec<Column>,
     rows: Vec<Vec<String>>,
-    cursor_row: usize,
+    /// Rows produced on demand, used instead of `rows` when set.
+    source: Option<Shared<dyn TableSource>>,
+    /// Row indices in display order, after filtering and sorting.
+    order: Vec<usize>,
+    sort: Option<(usize, SortOrder)>,
+    filterable: bool,
+    /// Whether the filter bar has keyboard input.
+    filtering: bool,
+    filter: String,
+    column_filters: HashMap<usize, CellFilter>,
+    filter_color: Color,
+    /// Row cursor (in display order), vertical offset and horizontal
+    /// scroll offset for wide tables.
+    scroll: ScrollState,
     cursor_col: usize,
-    offset: usize,
     height: usize,
+    /// Whether `height` was set, capping the area drawn into.
+    fixed_height: bool,
     /// Maximum width for the table (0 = unlimited).
     width: usize,
-    /// Horizontal scroll offset for wide tables.
-    h_scroll_offset: usize,
+    /// Leading columns that stay in place while the rest scroll horizontally.
+    frozen_columns: usize,
+    /// Column whose border is being dragged.
+    resizing: Option<usize>,
+    /// Whether the wheel scrolled the rows away from the cursor.
+    wheel_scrolled: bool,
     focused: bool,
     submitted: bool,
     cancelled: bool,
     cell_selection: bool,
+    /// Whether `1`-`9` show and hide columns.
+    column_toggle_keys: bool,
+    /// Whether rows can be checked for bulk actions.
+    multi_select: bool,
+    /// Checked rows, by index into the rows. When `all_checked` is set these
+    /// are the unchecked rows instead.
+    checked: BTreeSet<usize>,
+    /// Whether every row of a source is checked, so select-all doesn't have
+    /// to visit each one.
+    all_checked: bool,
+    /// Row that range selection extends from.
+    anchor: Option<usize>,
+    checked_color: Color,
     show_header: bool,
     show_borders: bool,
     header_color: Color,
@@ -137,6 +470,10 @@ pub struct Table {
     border_color: Color,
     row_color: Color,
     alt_row_color: Option<Color>,
+    /// Hyperlinks by (row, column).
+    links: HashMap<(usize, usize), String>,
+    /// Where the table was last drawn.
+    area: HitArea,
 }
 
 impl Default for Table {
@@ -144,24 +481,42 @@ impl Default for Table {
         Self {
             columns: Vec::new(),
             rows: Vec::new(),
-            cursor_row: 0,
+            source: None,
+            order: Vec::new(),
+            sort: None,
+            filterable: true,
+            filtering: false,
+            filter: String::new(),
+            column_filters: HashMap::new(),
+            filter_color: Color::ACCENT,
+            scroll: ScrollState::new(),
             cursor_col: 0,
-            offset: 0,
             height: 10,
+            fixed_height: false,
             width: 0,
-            h_scroll_offset: 0,
+            frozen_columns: 0,
+            resizing: None,
+            wheel_scrolled: false,
             focused: true,
             submitted: false,
             cancelled: false,
             cell_selection: false,
+            column_toggle_keys: false,
+            multi_select: false,
+            checked: BTreeSet::new(),
+            all_checked: false,
+            anchor: None,
+            checked_color: Color::SUCCESS,
             show_header: true,
             show_borders: true,
-            header_color: Color::Cyan,
-            selected_row_color: Color::Cyan,
-            selected_cell_color: Color::Green,
-            border_color: Color::BrightBlack,
+            header_color: Color::PRIMARY,
+            selected_row_color: Color::SELECTION,
+            selected_cell_color: Color::SUCCESS,
+            border_color: Color::BORDER,
             row_color: Color::Default,
             alt_row_color: None,
+            links: HashMap::new(),
+            area: HitArea::new(),
         }
     }
 }
@@ -175,10 +530,11 @@ impl Table {
     /// Set the columns.
     pub fn columns(mut self, columns: Vec<Column>) -> Self {
         self.columns = columns;
+        self.rebuild_order();
         self
     }
 
-    /// Set the rows.
+    /// Set the rows, replacing any source.
     pub fn rows<I, R, S>(mut self, rows: I) -> Self
     where
         I: IntoIterator<Item = R>,
@@ -187,12 +543,62 @@ impl Table {
     {
         self.rows =
             rows.into_iter().map(|row| row.into_iter().map(|s| s.into()).collect()).collect();
+        self.source = None;
+        self.rebuild_order();
+        self
+    }
+
+    /// Read rows from a source instead of owning them.
+    ///
+    /// Only the rows being drawn are requested, so the source can be
+    /// arbitrarily large. Sorting and filtering apply to owned rows only, and
+    /// auto-sized columns are measured from the visible rows, so fixed column
+    /// widths avoid jitter while scrolling.
+    pub fn source(mut self, source: impl TableSource + 'static) -> Self {
+        self.set_source(source);
+        self
+    }
+
+    /// Sort by a column. The column needs a [`SortKey`].
+    pub fn sort_by(mut self, col: usize, order: SortOrder) -> Self {
+        self.set_sort(Some((col, order)));
+        self
+    }
+
+    /// Set whether `/` opens the filter bar.
+    pub fn filterable(mut self, filterable: bool) -> Self {
+        self.filterable = filterable;
+        self
+    }
+
+    /// Set the filter text.
+    pub fn filter(mut self, filter: impl Into<String>) -> Self {
+        self.set_filter(filter);
+        self
+    }
+
+    /// Only show rows whose cell in `col` satisfies `predicate`.
+    pub fn column_filter<F>(mut self, col: usize, predicate: F) -> Self
+    where
+        F: Fn(&str) -> bool + Send + Sync + 'static,
+    {
+        self.set_column_filter(col, predicate);
+        self
+    }
+
+    /// Set the filter bar color.
+    pub fn filter_color(mut self, color: Color) -> Self {
+        self.filter_color = color;
         self
     }
 
     /// Set the visible height (number of rows).
+    ///
+    /// In [`Model::view_in`] this is the most the table takes; left unset,
+    /// the table fills the area.
     pub fn height(mut self, height: usize) -> Self {
         self.height = height;
+        self.fixed_height = true;
         self
     }
 
@@ -210,7 +616,14 @@ impl Table {
     /// This determines how many characters of the table content are hidden
     /// on the left side when the table is wider than the available width.
     pub fn with_h_scroll_offset(mut self, offset: usize) -> Self {
-        self.h_scroll_offset = offset;
+        self.scroll.set_h_offset(offset);
+        self
+    }
+
+    /// Keep the first `count` visible columns (and the checkbox column) in
+    /// place while the rest scroll horizontally.
+    pub fn frozen_columns(mut self, count: usize) -> Self {
+        self.frozen_columns = count;
         self
     }
 
@@ -220,6 +633,27 @@ impl Table {
         self
     }
 
+    /// Let the `1`-`9` keys show and hide the first nine columns.
+    pub fn column_toggle_keys(mut self, enabled: bool) -> Self {
+        self.column_toggle_keys = enabled;
+        self
+    }
+
+    /// Enable multi-row selection with a checkbox column.
+    ///
+    /// Space toggles the current row, Shift+Up/Down extend the selection,
+    /// `a` checks or unchecks every row and Esc clears it.
+    pub fn multi_select(mut self, enabled: bool) -> Self {
+        self.multi_select = enabled;
+        self
+    }
+
+    /// Set the color of checked rows' checkboxes.
+    pub fn checked_color(mut self, color: Color) -> Self {
+        self.checked_color = color;
+        self
+    }
+
     /// Set whether to show the header row.
     pub fn show_header(mut self, show: bool) -> Self {
         self.show_header = show;
@@ -262,6 +696,15 @@ impl Table {
         self
     }
 
+    /// Link a cell to a URL.
+    ///
+    /// Linked cells are rendered as OSC 8 hyperlinks, or as plain text when
+    /// hyperlinks aren't enabled. Links follow the cell position, not its value.
+    pub fn cell_link(mut self, row: usize, col: usize, url: impl Into<String>) -> Self {
+        self.set_cell_link(row, col, url);
+        self
+    }
+
     /// Set the focus state.
     ///
     /// When focused is false, the table will not show selection highlighting,
@@ -273,13 +716,13 @@ impl Table {
 
     /// Set the initial cursor row position.
     pub fn with_cursor_row(mut self, row: usize) -> Self {
-        self.cursor_row = row;
+        self.scroll.set_selected(row);
         self
     }
 
     /// Set the scroll offset.
     pub fn with_offset(mut self, offset: usize) -> Self {
-        self.offset = offset;
+        self.scroll.set_offset(offset);
         self
     }
 
@@ -305,9 +748,99 @@ impl Table {
         Model::view(self)
     }
 
-    /// Get the current row cursor position.
+    /// Write the rows as they are shown: sorted, filtered and with hidden
+    /// columns left out. Styles and links are stripped from cells.
+    ///
+    /// For a table backed by a [`TableSource`], rows the source hasn't
+    /// loaded are skipped.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing fails.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use teapot::components::{Column, ExportFormat, Table};
+    ///
+    /// let table = Table::new()
+    ///     .columns(vec![Column::new("Name"), Column::new("City")])
+    ///     .rows(vec![vec!["Alice", "Paris, TX"]]);
+    ///
+    /// let mut csv = Vec::new();
+    /// table.export(ExportFormat::Csv, &mut csv).unwrap();
+    /// assert_eq!(String::from_utf8(csv).unwrap(), "Name,City\nAlice,\"Paris, TX\"\n");
+    /// ```
+    pub fn export(&self, format: ExportFormat, mut writer: impl Write) -> io::Result<()> {
+        let columns: Vec<&Column> = self.visible_columns().map(|c| &self.columns[c]).collect();
+        let rows = (0..self.display_len())
+            .filter_map(|p| self.row_index(p))
+            .filter_map(|i| self.row(i))
+            .map(|row| {
+                self.visible_columns()
+                    .map(|c| strip_ansi(row.get(c).map_or("", String::as_str)))
+                    .collect::<Vec<_>>()
+            });
+
+        match format {
+            ExportFormat::Csv | ExportFormat::Tsv => {
+                let (separator, field): (&str, fn(&str) -> Cow<'_, str>) = match format {
+                    ExportFormat::Csv => (",", csv_field),
+                    _ => ("\t", tsv_field),
+                };
+                let header: Vec<_> = columns.iter().map(|c| field(&c.title)).collect();
+                writeln!(writer, "{}", header.join(separator))?;
+                for row in rows {
+                    let cells: Vec<_> = row.iter().map(|cell| field(cell)).collect();
+                    writeln!(writer, "{}", cells.join(separator))?;
+                }
+            },
+            ExportFormat::Json => {
+                write!(writer, "[")?;
+                for (i, row) in rows.enumerate() {
+                    write!(writer, "{}\n  {{", if i == 0 { "" } else { "," })?;
+                    for (j, (column, cell)) in columns.iter().zip(&row).enumerate() {
+                        let separator = if j == 0 { "" } else { ", " };
+                        write!(
+                            writer,
+                            "{separator}{}: {}",
+                            json_string(&column.title),
+                            json_string(cell)
+                        )?;
+                    }
+                    write!(writer, "}}")?;
+                }
+                writeln!(writer, "\n]")?;
+            },
+            ExportFormat::Markdown => {
+                let header: Vec<_> = columns.iter().map(|c| markdown_cell(&c.title)).collect();
+                writeln!(writer, "| {} |", header.join(" | "))?;
+                let rule: Vec<_> = columns
+                    .iter()
+                    .map(|c| match c.align {
+                        Align::Left => "---",
+                        Align::Center => ":---:",
+                        Align::Right => "---:",
+                    })
+                    .collect();
+                writeln!(writer, "| {} |", rule.join(" | "))?;
+                for row in rows {
+                    let cells: Vec<_> = row.iter().map(|cell| markdown_cell(cell)).collect();
+                    writeln!(writer, "| {} |", cells.join(" | "))?;
+                }
+            },
+        }
+        writer.flush()
+    }
+
+    /// Get the current row cursor position, in display order.
     pub fn cursor_row(&self) -> usize {
-        self.cursor_row
+        self.scroll.selected()
+    }
+
+    /// Get the index into the rows of the row under the cursor.
+    pub fn current_row_index(&self) -> Option<usize> {
+        self.row_index(self.scroll.selected())
     }
 
     /// Get the current column cursor position.
@@ -317,7 +850,12 @@ impl Table {
 
     /// Get the total number of rows.
     pub fn row_count(&self) -> usize {
-        self.rows.len()
+        self.source.as_ref().map_or(self.rows.len(), |source| source.0.len())
+    }
+
+    /// Get the number of rows that pass the filter.
+    pub fn filtered_count(&self) -> usize {
+        self.display_len()
     }
 
     /// Get the total number of columns.
@@ -327,12 +865,26 @@ impl Table {
 
     /// Get the selected row if submitted.
     pub fn selected_row(&self) -> Option<&Vec<String>> {
-        if self.submitted && !self.rows.is_empty() { self.rows.get(self.cursor_row) } else { None }
+        if self.submitted { self.current_row() } else { None }
     }
 
     /// Get the current row regardless of submit state.
+    ///
+    /// Always `None` for a table backed by a [`TableSource`]; use
+    /// [`Table::current_row_index`] and [`Table::row`] instead.
     pub fn current_row(&self) -> Option<&Vec<String>> {
-        self.rows.get(self.cursor_row)
+        if self.source.is_some() {
+            return None;
+        }
+        self.rows.get(self.current_row_index()?)
+    }
+
+    /// Get the cells of a row by index, from the owned rows or the source.
+    pub fn row(&self, index: usize) -> Option<Cow<'_, [String]>> {
+        match &self.source {
+            Some(source) => source.0.row(index).map(Cow::Owned),
+            None => self.rows.get(index).map(|row| Cow::Borrowed(row.as_slice())),
+        }
     }
 
     /// Get the selected cell value if in cell selection mode and submitted.
@@ -341,8 +893,10 @@ impl Table {
     }
 
     /// Get the current cell value.
+    ///
+    /// Always `None` for a table backed by a [`TableSource`].
     pub fn current_cell(&self) -> Option<&str> {
-        self.rows.get(self.cursor_row).and_then(|row| row.get(self.cursor_col)).map(|s| s.as_str())
+        self.current_row().and_then(|row| row.get(self.cursor_col)).map(|s| s.as_str())
     }
 
     /// Check if submitted.
@@ -355,12 +909,125 @@ impl Table {
         self.cancelled
     }
 
+    /// Get the indices of the checked rows, in ascending order.
+    ///
+    /// Rows stay checked while hidden by the filter. After select-all on a
+    /// [`TableSource`], this lists every row of the source.
+    pub fn selected_indices(&self) -> Vec<usize> {
+        if self.all_checked {
+            (0..self.row_count()).filter(|i| !self.checked.contains(i)).collect()
+        } else {
+            self.checked.iter().copied().collect()
+        }
+    }
+
+    /// Get the checked rows, in their original order.
+    ///
+    /// Always empty for a table backed by a [`TableSource`]; use
+    /// [`Table::selected_indices`] instead.
+    pub fn selected_rows(&self) -> Vec<&Vec<String>> {
+        self.checked.iter().filter_map(|&i| self.rows.get(i)).collect()
+    }
+
+    /// Check if a row is checked, by index into the rows.
+    pub fn is_row_selected(&self, index: usize) -> bool {
+        if self.all_checked {
+            index < self.row_count() && !self.checked.contains(&index)
+        } else {
+            self.checked.contains(&index)
+        }
+    }
+
+    /// Check or uncheck a row in place, by index into the rows.
+    pub fn set_row_selected(&mut self, index: usize, selected: bool) {
+        if selected && index >= self.row_count() {
+            return;
+        }
+        if selected == self.all_checked {
+            self.checked.remove(&index);
+        } else {
+            self.checked.insert(index);
+        }
+    }
+
+    /// Uncheck every row.
+    pub fn clear_selection(&mut self) {
+        self.checked.clear();
+        self.all_checked = false;
+        self.anchor = None;
+    }
+
+    /// Check if any row is checked.
+    fn has_selection(&self) -> bool {
+        if self.all_checked {
+            self.checked.len() < self.row_count()
+        } else {
+            !self.checked.is_empty()
+        }
+    }
+
     /// Set focus state.
     pub fn set_focused(&mut self, focused: bool) {
         self.focused = focused;
     }
 
-    /// Set rows dynamically.
+    /// Link a cell to a URL in place.
+    pub fn set_cell_link(&mut self, row: usize, col: usize, url: impl Into<String>) {
+        self.links.insert((row, col), url.into());
+    }
+
+    /// Get a cell's link.
+    pub fn cell_link_at(&self, row: usize, col: usize) -> Option<&str> {
+        self.links.get(&(row, col)).map(String::as_str)
+    }
+
+    /// Set a column's width in place, clamped to its bounds.
+    ///
+    /// An auto-sized column becomes fixed-width.
+    pub fn set_column_width(&mut self, col: usize, width: usize) {
+        if let Some(column) = self.columns.get_mut(col) {
+            column.width = column.clamp_width(width);
+        }
+    }
+
+    /// Get the width a column is drawn at, or 0 if it is hidden.
+    pub fn column_width(&self, col: usize) -> usize {
+        match self.columns.get(col) {
+            Some(column) if !column.hidden => self.calculate_widths()[col],
+            _ => 0,
+        }
+    }
+
+    /// Show or hide a column in place.
+    pub fn set_column_visible(&mut self, col: usize, visible: bool) {
+        if let Some(column) = self.columns.get_mut(col) {
+            column.hidden = !visible;
+        }
+        if self.columns.get(self.cursor_col).is_some_and(|c| c.hidden) {
+            let cursor = self.cursor_col;
+            let next = self.visible_columns().find(|&c| c > cursor);
+            self.cursor_col = next.or_else(|| self.visible_columns().last()).unwrap_or(0);
+        }
+    }
+
+    /// Check if a column is shown.
+    pub fn is_column_visible(&self, col: usize) -> bool {
+        self.columns.get(col).is_some_and(|c| !c.hidden)
+    }
+
+    /// Record where the table is drawn, for mouse hit-testing.
+    ///
+    /// [`Model::view_in`] records this automatically.
+    pub fn set_area(&mut self, area: Rect) {
+        self.area.set(area);
+    }
+
+    /// Where the table was last drawn.
+    pub fn area(&self) -> Option<Rect> {
+        self.area.get()
+    }
+
+    /// Set rows dynamically, replacing any source.
     pub fn set_rows<I, R, S>(&mut self, rows: I)
     where
         I: IntoIterator<Item = R>,
@@ -369,116 +1036,606 @@ impl Table {
     {
         self.rows =
             rows.into_iter().map(|row| row.into_iter().map(|s| s.into()).collect()).collect();
-        self.cursor_row = self.cursor_row.min(self.rows.len().saturating_sub(1));
-        self.ensure_visible();
+        self.source = None;
+        self.clear_selection();
+        self.rebuild_order();
+    }
+
+    /// Read rows from a source in place, replacing any owned rows.
+    pub fn set_source(&mut self, source: impl TableSource + 'static) {
+        self.rows.clear();
+        self.source = Some(Shared(Arc::new(source)));
+        self.clear_selection();
+        self.rebuild_order();
+    }
+
+    /// Get the sort column and direction.
+    pub fn sorted_by(&self) -> Option<(usize, SortOrder)> {
+        self.sort
+    }
+
+    /// Sort by a column, or restore the original order with `None`.
+    ///
+    /// Columns without a [`SortKey`] are ignored. The cursor stays on the
+    /// same row.
+    pub fn set_sort(&mut self, sort: Option<(usize, SortOrder)>) {
+        if sort.is_none_or(|(col, _)| self.is_sortable(col)) {
+            self.sort = sort;
+            self.rebuild_order();
+        }
+    }
+
+    /// Get the filter text.
+    pub fn filter_text(&self) -> &str {
+        &self.filter
+    }
+
+    /// Set the filter text.
+    ///
+    /// Rows must match every whitespace-separated term, case-insensitively.
+    /// A plain term matches any cell; `column:text` only matches cells in the
+    /// column with that title.
+    pub fn set_filter(&mut self, filter: impl Into<String>) {
+        self.filter = filter.into();
+        self.rebuild_order();
+    }
+
+    /// Only show rows whose cell in `col` satisfies `predicate`, in place.
+    pub fn set_column_filter<F>(&mut self, col: usize, predicate: F)
+    where
+        F: Fn(&str) -> bool + Send + Sync + 'static,
+    {
+        self.column_filters.insert(col, CellFilter(Arc::new(predicate)));
+        self.rebuild_order();
+    }
+
+    /// Remove all column filters.
+    pub fn clear_column_filters(&mut self) {
+        self.column_filters.clear();
+        self.rebuild_order();
+    }
+
+    /// Check if the filter bar has keyboard input.
+    pub fn is_filtering(&self) -> bool {
+        self.filtering
+    }
+
+    fn is_sortable(&self, col: usize) -> bool {
+        self.source.is_none() && self.columns.get(col).is_some_and(|c| c.sort.is_some())
+    }
+
+    fn toggle_sort(&mut self, col: usize) {
+        let sort = match self.sort {
+            Some((c, SortOrder::Ascending)) if c == col => Some((col, SortOrder::Descending)),
+            Some((c, SortOrder::Descending)) if c == col => None,
+            _ => Some((col, SortOrder::Ascending)),
+        };
+        self.set_sort(sort);
+    }
+
+    fn cycle_sort(&mut self) {
+        let next_column = |after: Option<usize>| {
+            (after.map_or(0, |c| c + 1)..self.columns.len()).find(|&c| self.is_sortable(c))
+        };
+        let sort = match self.sort {
+            None => next_column(None).map(|c| (c, SortOrder::Ascending)),
+            Some((c, SortOrder::Ascending)) => Some((c, SortOrder::Descending)),
+            Some((c, SortOrder::Descending)) => {
+                next_column(Some(c)).map(|c| (c, SortOrder::Ascending))
+            },
+        };
+        self.set_sort(sort);
+    }
+
+    fn matches_filter(&self, row: &[String]) -> bool {
+        let cell = |col: usize| row.get(col).map_or("", String::as_str);
+        self.column_filters.iter().all(|(col, filter)| (filter.0)(cell(*col)))
+            && self.filter.split_whitespace().all(|term| {
+                let term = term.to_lowercase();
+                if let Some((name, text)) = term.split_once(':')
+                    && let Some(col) =
+                        self.columns.iter().position(|c| c.title.to_lowercase() == name)
+                {
+                    return cell(col).to_lowercase().contains(text);
+                }
+                row.iter().any(|cell| cell.to_lowercase().contains(&term))
+            })
+    }
+
+    /// Filter and sort the rows, keeping the cursor on the same row.
+    fn rebuild_order(&mut self) {
+        if let Some(source) = &self.source {
+            self.order.clear();
+            let len = source.0.len();
+            self.scroll.clamp(len, self.height);
+            return;
+        }
+        let current = self.current_row_index();
+
+        let mut order: Vec<usize> =
+            (0..self.rows.len()).filter(|&i| self.matches_filter(&self.rows[i])).collect();
+        if let Some((col, direction)) = self.sort
+            && let Some(key) = self.columns.get(col).and_then(|c| c.sort.as_ref())
+        {
+            let cell = |i: usize| self.rows[i].get(col).map_or("", String::as_str);
+            order.sort_by(|&a, &b| {
+                let ordering = key.compare(cell(a), cell(b));
+                if direction == SortOrder::Descending { ordering.reverse() } else { ordering }
+            });
+        }
+        self.order = order;
+
+        let row = current
+            .and_then(|row| self.order.iter().position(|&i| i == row))
+            .unwrap_or(self.scroll.selected());
+        self.scroll.select(row, self.order.len(), self.height);
+    }
+
+    /// Number of rows in display order.
+    fn display_len(&self) -> usize {
+        self.source.as_ref().map_or(self.order.len(), |source| source.0.len())
+    }
+
+    /// Index into the rows of the row at a display position.
+    fn row_index(&self, display: usize) -> Option<usize> {
+        match &self.source {
+            Some(source) => (display < source.0.len()).then_some(display),
+            None => self.order.get(display).copied(),
+        }
+    }
+
+    /// Position in display order of a row, by index into the rows.
+    fn position_of(&self, row: usize) -> Option<usize> {
+        match &self.source {
+            Some(source) => (row < source.0.len()).then_some(row),
+            None => self.order.iter().position(|&i| i == row),
+        }
+    }
+
+    fn toggle_row(&mut self, position: usize) {
+        let Some(row) = self.row_index(position) else { return };
+        self.set_row_selected(row, !self.is_row_selected(row));
+        self.anchor = Some(row);
+        self.scroll.select(position, self.display_len(), self.height);
+    }
+
+    fn select_to(&mut self, position: usize) {
+        let len = self.display_len();
+        if len == 0 {
+            return;
+        }
+        let position = position.min(len - 1);
+        let from = self
+            .anchor
+            .and_then(|row| self.position_of(row))
+            .unwrap_or_else(|| self.scroll.selected());
+        self.anchor = self.row_index(from);
+        for p in from.min(position)..=from.max(position) {
+            if let Some(row) = self.row_index(p) {
+                self.set_row_selected(row, true);
+            }
+        }
+        self.scroll.select(position, len, self.height);
+    }
+
+    fn select_all(&mut self) {
+        // Sources can be huge and are never filtered, so flip a flag instead.
+        if self.source.is_some() {
+            let all = self.all_selected();
+            self.clear_selection();
+            self.all_checked = !all;
+            return;
+        }
+
+        let rows: Vec<usize> = (0..self.display_len()).filter_map(|p| self.row_index(p)).collect();
+        if rows.iter().all(|row| self.checked.contains(row)) {
+            for row in &rows {
+                self.checked.remove(row);
+            }
+        } else {
+            self.checked.extend(rows);
+        }
+    }
+
+    /// Check if every shown row is checked.
+    fn all_selected(&self) -> bool {
+        if self.source.is_some() {
+            return self.all_checked && self.checked.is_empty() && self.display_len() > 0;
+        }
+        self.display_len() > 0
+            && (0..self.display_len())
+                .filter_map(|p| self.row_index(p))
+                .all(|row| self.checked.contains(&row))
+    }
+
+    /// Columns taken by the checkbox cell, including padding and border.
+    fn checkbox_width(&self) -> usize {
+        if self.multi_select { CHECKBOX_WIDTH + 2 + usize::from(self.show_borders) } else { 0 }
+    }
+
+    /// Render the checkbox cell (without padding) for a row.
+    fn checkbox(&self, checked: bool) -> String {
+        if checked {
+            format!("{}[x]{}", self.checked_color.to_ansi_fg(), "\x1b[0m")
+        } else {
+            format!("{}[ ]{}", Color::MUTED.to_ansi_fg(), "\x1b[0m")
+        }
+    }
+
+    /// Ask the source for the rows about to be drawn.
+    fn fetch(&self) -> Option<Cmd<TableMsg>> {
+        let source = self.source.as_ref()?;
+        let len = source.0.len();
+        let range = prefetch_range(self.visible_range(self.shown_height()), len);
+        source.0.fetch(range).map(|cmd| cmd.map(|()| TableMsg::Loaded))
     }
 
     fn move_up(&mut self) {
-        if self.cursor_row > 0 {
-            self.cursor_row -= 1;
-            self.ensure_visible();
+        self.scroll.select_prev();
+    }
+
+    fn move_down(&mut self) {
+        self.scroll.select_next(self.display_len(), self.height);
+    }
+
+    fn move_left(&mut self) {
+        if !self.cell_selection {
+            self.scroll.scroll_left(H_SCROLL_STEP);
+            return;
+        }
+        let cursor = self.cursor_col;
+        if let Some(col) = self.visible_columns().filter(|&c| c < cursor).last() {
+            self.cursor_col = col;
+            self.scroll_to_column(col);
+        }
+    }
+
+    fn move_right(&mut self) {
+        if !self.cell_selection {
+            self.scroll_right(H_SCROLL_STEP);
+            return;
+        }
+        let cursor = self.cursor_col;
+        let next = self.visible_columns().find(|&c| c > cursor);
+        if let Some(col) = next {
+            self.cursor_col = col;
+            self.scroll_to_column(col);
+        }
+    }
+
+    fn toggle_column(&mut self, col: usize) {
+        let visible = self.is_column_visible(col);
+        if col < self.columns.len() && !(visible && self.visible_columns().count() == 1) {
+            self.set_column_visible(col, !visible);
+            self.scroll_right(0);
+        }
+    }
+
+    /// Indices of the columns that aren't hidden.
+    fn visible_columns(&self) -> impl Iterator<Item = usize> + '_ {
+        self.columns.iter().enumerate().filter(|(_, c)| !c.hidden).map(|(i, _)| i)
+    }
+
+    /// Width available for drawing, or 0 if unlimited.
+    fn view_width(&self) -> usize {
+        self.area.get().map_or(self.width, |area| self.fitted_width(area))
+    }
+
+    /// Cells available when drawn into `area`, capped by `width` if set.
+    fn fitted_width(&self, area: Rect) -> usize {
+        match self.width {
+            0 => area.width as usize,
+            width => width.min(area.width as usize),
+        }
+    }
+
+    /// Cells taken by the frozen columns, including the leading border and
+    /// the checkbox column. 0 when nothing is frozen.
+    fn frozen_width(&self, widths: &[usize]) -> usize {
+        if self.frozen_columns == 0 {
+            return 0;
+        }
+        let border = usize::from(self.show_borders);
+        self.column_spans(widths)
+            .get(self.frozen_columns - 1)
+            .map_or_else(|| self.calculate_content_width(widths), |(_, span)| span.end + border)
+    }
+
+    /// The cells (padding included) each visible column takes up, as
+    /// `(column, range)` in unscrolled coordinates.
+    fn column_spans(&self, widths: &[usize]) -> Vec<(usize, Range<usize>)> {
+        let border = usize::from(self.show_borders);
+        let mut x = border + self.checkbox_width();
+        self.visible_columns()
+            .map(|col| {
+                let span = x..x + widths[col] + 2;
+                x = span.end + border;
+                (col, span)
+            })
+            .collect()
+    }
+
+    /// Convert a screen column to unscrolled coordinates.
+    fn content_x(&self, x: u16, widths: &[usize]) -> usize {
+        let x = x as usize;
+        if x < self.frozen_width(widths) { x } else { x + self.scroll.h_offset() }
+    }
+
+    /// Largest useful horizontal scroll offset.
+    fn max_h_offset(&self, widths: &[usize]) -> usize {
+        let view = self.view_width();
+        if view == 0 {
+            return 0;
+        }
+        let view = view.saturating_sub(self.frozen_width(widths).min(view));
+        let scrolled = self.calculate_content_width(widths) - self.frozen_width(widths);
+        scrolled.saturating_sub(view)
+    }
+
+    fn scroll_right(&mut self, step: usize) {
+        let max = self.max_h_offset(&self.calculate_widths_for(self.view_width()));
+        self.scroll.scroll_right(step, max);
+    }
+
+    /// Scroll horizontally so a column is fully in view.
+    fn scroll_to_column(&mut self, col: usize) {
+        let view = self.view_width();
+        let widths = self.calculate_widths_for(view);
+        let frozen = self.frozen_width(&widths);
+        let Some((_, span)) = self.column_spans(&widths).into_iter().find(|(c, _)| *c == col)
+        else {
+            return;
+        };
+        if view == 0 || span.start < frozen {
+            return;
+        }
+        let h = self.scroll.h_offset();
+        let start = span.start - frozen;
+        let end = span.end + usize::from(self.show_borders) - frozen;
+        let room = view.saturating_sub(frozen);
+        if start < h {
+            self.scroll.set_h_offset(start);
+        } else if end > h + room {
+            self.scroll.set_h_offset((end - room).min(start));
+        }
+    }
+
+    fn move_first(&mut self) {
+        self.scroll.select_first();
+    }
+
+    fn move_last(&mut self) {
+        self.scroll.select_last(self.display_len(), self.height);
+    }
+
+    fn page_up(&mut self) {
+        self.scroll.page_up(self.height);
+    }
+
+    fn page_down(&mut self) {
+        self.scroll.page_down(self.display_len(), self.height);
+    }
+
+    /// Visible rows for a given height, keeping the cursor in view unless
+    /// the wheel scrolled away from it.
+    fn visible_range(&self, height: usize) -> (usize, usize) {
+        let len = self.display_len();
+        if self.wheel_scrolled {
+            let start = self.scroll.offset().min(len.saturating_sub(height));
+            return (start, (start + height).min(len));
         }
+        self.scroll.visible_range(len, height)
+    }
+
+    fn scroll_rows_up(&mut self, lines: usize) {
+        let (start, _) = self.visible_range(self.shown_height());
+        self.scroll.set_offset(start.saturating_sub(lines));
+        self.wheel_scrolled = true;
+    }
+
+    fn scroll_rows_down(&mut self, lines: usize) {
+        let height = self.shown_height();
+        let (start, _) = self.visible_range(height);
+        self.scroll.set_offset((start + lines).min(self.display_len().saturating_sub(height)));
+        self.wheel_scrolled = true;
     }
 
-    fn move_down(&mut self) {
-        if self.cursor_row < self.rows.len().saturating_sub(1) {
-            self.cursor_row += 1;
-            self.ensure_visible();
+    fn shows_filter_bar(&self) -> bool {
+        self.filtering || !self.filter.is_empty()
+    }
+
+    /// Rows above the data: filter bar, borders and header.
+    fn chrome_top(&self) -> usize {
+        let table = match (self.show_borders, self.show_header) {
+            (true, true) => 3,
+            (true, false) | (false, true) => 1,
+            (false, false) => 0,
+        };
+        usize::from(self.shows_filter_bar()) + table
+    }
+
+    /// Data rows available when drawn into `area`.
+    fn fitted_height(&self, area: Rect) -> usize {
+        let chrome = self.chrome_top() + usize::from(self.show_borders);
+
+        // Leave room for the scroll indicators when the rows don't all fit.
+        let mut height = (area.height as usize).saturating_sub(chrome);
+        if self.display_len() > height {
+            height = height.saturating_sub(2);
+        }
+        if self.fixed_height {
+            height = height.min(self.height);
         }
+        height.max(1)
     }
 
-    fn move_left(&mut self) {
-        if self.cell_selection && self.cursor_col > 0 {
-            self.cursor_col -= 1;
-        }
+    /// Data rows in the area last drawn into, or `height` before that.
+    fn shown_height(&self) -> usize {
+        self.area.get().map_or(self.height, |area| self.fitted_height(area))
     }
 
-    fn move_right(&mut self) {
-        if self.cell_selection && self.cursor_col < self.columns.len().saturating_sub(1) {
-            self.cursor_col += 1;
-        }
+    /// Row index drawn on screen row `y`.
+    fn row_at(&self, y: u16) -> Option<usize> {
+        let (start, end) = self.visible_range(self.shown_height());
+        let top = self.chrome_top() + usize::from(start > 0);
+        let index = start + (y as usize).checked_sub(top)?;
+        (index < end).then_some(index)
     }
 
-    fn move_first(&mut self) {
-        self.cursor_row = 0;
-        self.offset = 0;
+    /// Column index drawn at screen column `x`, or `None` on a border or
+    /// the checkbox column.
+    fn column_at(&self, x: u16) -> Option<usize> {
+        let widths = self.calculate_widths_for(self.view_width());
+        let x = self.content_x(x, &widths);
+        self.column_spans(&widths).into_iter().find(|(_, span)| span.contains(&x)).map(|(c, _)| c)
     }
 
-    fn move_last(&mut self) {
-        self.cursor_row = self.rows.len().saturating_sub(1);
-        self.ensure_visible();
+    /// Column whose right border is drawn at screen column `x`.
+    fn border_at(&self, x: u16) -> Option<usize> {
+        let widths = self.calculate_widths_for(self.view_width());
+        let x = self.content_x(x, &widths);
+        let spans = self.column_spans(&widths);
+        spans
+            .into_iter()
+            .find(|(_, span)| span.end == x)
+            .filter(|_| self.show_borders)
+            .map(|(c, _)| c)
     }
 
-    fn page_up(&mut self) {
-        let page_size = self.height.saturating_sub(1);
-        self.cursor_row = self.cursor_row.saturating_sub(page_size);
-        self.ensure_visible();
+    /// Width a column gets when its right border is dragged to screen
+    /// column `x`.
+    fn width_at(&self, col: usize, x: u16) -> Option<usize> {
+        let widths = self.calculate_widths_for(self.view_width());
+        let x = self.content_x(x, &widths);
+        let (_, span) = self.column_spans(&widths).into_iter().find(|(c, _)| *c == col)?;
+        Some(x.saturating_sub(span.start + 2))
     }
 
-    fn page_down(&mut self) {
-        let page_size = self.height.saturating_sub(1);
-        self.cursor_row = (self.cursor_row + page_size).min(self.rows.len().saturating_sub(1));
-        self.ensure_visible();
+    /// Whether screen column `x` is inside the checkbox cell.
+    fn on_checkbox(&self, x: u16) -> bool {
+        let widths = self.calculate_widths_for(self.view_width());
+        let x = self.content_x(x, &widths);
+        let start = usize::from(self.show_borders);
+        self.multi_select && (start..start + CHECKBOX_WIDTH + 2).contains(&x)
     }
 
-    fn ensure_visible(&mut self) {
-        if self.cursor_row < self.offset {
-            self.offset = self.cursor_row;
-        }
-        if self.cursor_row >= self.offset + self.height {
-            self.offset = self.cursor_row.saturating_sub(self.height - 1);
+    fn handle_mouse(&self, mouse: MouseEvent) -> Option<TableMsg> {
+        let (x, y) = self.area.hit(mouse.column, mouse.row)?;
+        match mouse.kind {
+            MouseEventKind::Down(MouseButton::Left) => {
+                let header = usize::from(self.shows_filter_bar()) + usize::from(self.show_borders);
+                if self.show_header && y as usize == header {
+                    if self.on_checkbox(x) {
+                        return Some(TableMsg::SelectAll);
+                    }
+                    if let Some(col) = self.border_at(x) {
+                        return Some(TableMsg::StartResize(col));
+                    }
+                    return self
+                        .column_at(x)
+                        .filter(|&c| self.is_sortable(c))
+                        .map(TableMsg::SortBy);
+                }
+                let row = self.row_at(y)?;
+                if self.multi_select && mouse.modifiers.contains(KeyModifiers::SHIFT) {
+                    Some(TableMsg::SelectTo(row))
+                } else if self.on_checkbox(x) {
+                    Some(TableMsg::ToggleRow(row))
+                } else {
+                    Some(TableMsg::Click { row, col: self.column_at(x) })
+                }
+            },
+            MouseEventKind::Drag(MouseButton::Left) => {
+                let col = self.resizing?;
+                self.width_at(col, x).map(|width| TableMsg::SetColumnWidth { col, width })
+            },
+            MouseEventKind::Up(MouseButton::Left) => self.resizing.map(|_| TableMsg::EndResize),
+            MouseEventKind::ScrollUp => Some(TableMsg::ScrollUp(WHEEL_LINES)),
+            MouseEventKind::ScrollDown => Some(TableMsg::ScrollDown(WHEEL_LINES)),
+            MouseEventKind::ScrollLeft => Some(TableMsg::ScrollLeft(H_SCROLL_STEP)),
+            MouseEventKind::ScrollRight => Some(TableMsg::ScrollRight(H_SCROLL_STEP)),
+            _ => None,
         }
     }
 
-    fn visible_range(&self) -> (usize, usize) {
-        let start = self.offset;
-        let end = (self.offset + self.height).min(self.rows.len());
-        (start, end)
+    fn click(&mut self, row: usize, col: Option<usize>) {
+        if row >= self.display_len() {
+            return;
+        }
+        let same_cell = !self.cell_selection || col.is_none_or(|col| col == self.cursor_col);
+        if row == self.scroll.selected() && same_cell {
+            self.submitted = true;
+            return;
+        }
+        self.scroll.select(row, self.display_len(), self.height);
+        if self.cell_selection
+            && let Some(col) = col
+        {
+            self.cursor_col = col;
+        }
     }
 
     /// Calculate column widths (auto-size if width is 0).
     ///
     /// If a column is marked as `grow`, it will expand to fill remaining space.
     fn calculate_widths(&self) -> Vec<usize> {
+        self.calculate_widths_for(self.width)
+    }
+
+    /// Calculate column widths for a maximum table width (0 = unlimited).
+    fn calculate_widths_for(&self, table_width: usize) -> Vec<usize> {
+        // A source may be huge, so only measure the rows on screen.
+        let sampled: Vec<Cow<'_, [String]>> = match &self.source {
+            Some(_) if self.columns.iter().all(|c| c.width > 0) => Vec::new(),
+            Some(_) => {
+                let (start, end) = self.visible_range(self.height);
+                (start..end).filter_map(|i| self.row(i)).collect()
+            },
+            None => self.rows.iter().map(|row| Cow::Borrowed(row.as_slice())).collect(),
+        };
+
         // First pass: calculate base widths for all columns
         let mut widths: Vec<usize> = self
             .columns
             .iter()
             .enumerate()
             .map(|(col_idx, col)| {
-                if col.width > 0 {
+                if col.hidden {
+                    0
+                } else if col.width > 0 {
                     col.width
                 } else {
-                    // Auto-calculate based on content
-                    let header_len = col.title.chars().count();
-                    let max_data_len = self
-                        .rows
+                    // Auto-calculate based on content, leaving room for the
+                    // sort indicator.
+                    let indicator = if col.sort.is_some() { 2 } else { 0 };
+                    let header_len = width(&col.title) + indicator;
+                    let max_data_len = sampled
                         .iter()
                         .filter_map(|row| row.get(col_idx))
-                        .map(|s| s.chars().count())
+                        .map(|s| width(s))
                         .max()
                         .unwrap_or(0);
-                    header_len.max(max_data_len).max(3) // Minimum 3 chars
+                    col.clamp_width(header_len.max(max_data_len).max(3)) // Minimum 3 chars
                 }
             })
             .collect();
 
         // If we have a table width and a growth column, expand it
-        if self.width > 0
-            && let Some(grow_idx) = self.columns.iter().position(|c| c.grow)
+        if table_width > 0
+            && let Some(grow_idx) = self.columns.iter().position(|c| c.grow && !c.hidden)
         {
             // Calculate current total width
             // Each column has: space + content + space (2 chars padding per column)
             let padding_per_col = 2;
             let current_width: usize =
-                widths.iter().sum::<usize>() + widths.len() * padding_per_col;
+                widths.iter().sum::<usize>() + self.visible_columns().count() * padding_per_col;
 
             // Calculate remaining space
-            let remaining = self.width.saturating_sub(current_width);
+            let remaining = table_width.saturating_sub(current_width + self.checkbox_width());
 
             // Add remaining space to growth column
             if remaining > 0 {
-                widths[grow_idx] += remaining;
+                widths[grow_idx] = self.columns[grow_idx].clamp_width(widths[grow_idx] + remaining);
             }
         }
 
@@ -487,9 +1644,9 @@ impl Table {
 
     /// Align text within a width.
     fn align_text(&self, text: &str, width: usize, align: Align) -> String {
-        let text_len = text.chars().count();
+        let text_len = crate::style::width(text);
         if text_len >= width {
-            text.chars().take(width).collect()
+            crop(text, width)
         } else {
             let padding = width - text_len;
             match align {
@@ -507,9 +1664,14 @@ impl Table {
     /// Render a horizontal border line.
     fn render_border(&self, widths: &[usize], left: &str, mid: &str, right: &str) -> String {
         let mut line = format!("{}{}", self.border_color.to_ansi_fg(), left);
-        for (i, width) in widths.iter().enumerate() {
-            line.push_str(&"─".repeat(*width + 2));
-            if i < widths.len() - 1 {
+        if self.multi_select {
+            line.push_str(&"─".repeat(CHECKBOX_WIDTH + 2));
+            line.push_str(mid);
+        }
+        let visible: Vec<usize> = self.visible_columns().collect();
+        for (i, col) in visible.iter().enumerate() {
+            line.push_str(&"─".repeat(widths[*col] + 2));
+            if i < visible.len() - 1 {
                 line.push_str(mid);
             }
         }
@@ -524,15 +1686,18 @@ impl Table {
             return 0;
         }
 
-        if self.show_borders {
+        // Hidden columns have a width of 0.
+        let count = self.visible_columns().count();
+        let cells = if self.show_borders {
             // │ cell1 │ cell2 │ ... │
             // Each cell has +3 (space before, after, and border)
             // Plus 1 for leading border
-            widths.len() + widths.iter().sum::<usize>() + widths.len() * 2
+            1 + widths.iter().sum::<usize>() + count * 3
         } else {
             // " cell1  cell2  ... " (space around each cell)
-            widths.iter().sum::<usize>() + widths.len() * 2
-        }
+            widths.iter().sum::<usize>() + count * 2
+        };
+        cells + self.checkbox_width()
     }
 
     /// Get the total content width of the table.
@@ -543,122 +1708,60 @@ impl Table {
         self.calculate_content_width(&widths)
     }
 
-    /// Apply horizontal scroll to a line of text.
+    /// Apply horizontal scroll to a line of text, then clip it to `max_width`
+    /// (0 = unlimited).
     ///
-    /// Strips ANSI codes for width calculation, applies scroll offset,
-    /// then clips to max width.
-    fn apply_h_scroll(&self, line: &str, max_width: usize) -> String {
-        if max_width == 0 && self.h_scroll_offset == 0 {
+    /// The first `frozen` cells stay in place.
+    fn apply_h_scroll(&self, line: &str, frozen: usize, max_width: usize) -> String {
+        if max_width == 0 && self.scroll.h_offset() == 0 {
             return line.to_string();
         }
 
-        // We need to handle ANSI escape codes properly
-        // Strategy: render the full line, then slice by visible characters
-        let mut result = String::new();
-        let mut visible_pos = 0;
-        let mut in_escape = false;
-        let mut escape_seq = String::new();
-        let mut active_style = String::new();
-
-        let target_start = self.h_scroll_offset;
-        let target_end = if max_width > 0 { self.h_scroll_offset + max_width } else { usize::MAX };
-
-        for ch in line.chars() {
-            if in_escape {
-                escape_seq.push(ch);
-                if ch == 'm' {
-                    in_escape = false;
-                    // Track active style for restoration after scroll
-                    if escape_seq.contains("\x1b[0m") {
-                        active_style.clear();
-                    } else {
-                        active_style = escape_seq.clone();
-                    }
-                    // Always include escape sequences in output if we're in visible range
-                    if visible_pos >= target_start && visible_pos < target_end {
-                        result.push_str(&escape_seq);
-                    }
-                    escape_seq.clear();
-                }
-            } else if ch == '\x1b' {
-                in_escape = true;
-                escape_seq.push(ch);
-            } else {
-                // Visible character
-                if visible_pos >= target_start && visible_pos < target_end {
-                    // If this is the first visible character and we have an active style,
-                    // apply it
-                    if visible_pos == target_start && !active_style.is_empty() && result.is_empty()
-                    {
-                        result.push_str(&active_style);
-                    }
-                    result.push(ch);
-                }
-                visible_pos += 1;
-
-                if visible_pos >= target_end {
-                    break;
-                }
-            }
-        }
-
-        // Always reset at the end if we output anything
-        if !result.is_empty() && !result.ends_with("\x1b[0m") {
-            result.push_str("\x1b[0m");
+        let end = if max_width > 0 { self.scroll.h_offset() + max_width } else { usize::MAX };
+        if frozen == 0 {
+            return slice(line, self.scroll.h_offset(), end);
         }
-
-        result
+        let frozen = if max_width > 0 { frozen.min(max_width) } else { frozen };
+        let head = slice(line, 0, frozen);
+        head + &slice(line, frozen + self.scroll.h_offset(), end)
     }
 }
 
-impl Model for Table {
-    type Message = TableMsg;
-
-    fn init(&self) -> Option<Cmd<Self::Message>> {
-        None
-    }
-
-    fn update(&mut self, msg: Self::Message) -> Option<Cmd<Self::Message>> {
-        match msg {
-            TableMsg::Up => self.move_up(),
-            TableMsg::Down => self.move_down(),
-            TableMsg::Left => self.move_left(),
-            TableMsg::Right => self.move_right(),
-            TableMsg::First => self.move_first(),
-            TableMsg::Last => self.move_last(),
-            TableMsg::PageUp => self.page_up(),
-            TableMsg::PageDown => self.page_down(),
-            TableMsg::Submit => {
-                if !self.rows.is_empty() {
-                    self.submitted = true;
-                }
-            },
-            TableMsg::Cancel => self.cancelled = true,
-            TableMsg::Focus => self.focused = true,
-            TableMsg::Blur => self.focused = false,
+impl Table {
+    /// Render into `width` cells and `height` rows. When not `interactive`,
+    /// every row is drawn with no cursor, filter bar or scrolling.
+    fn render_sized(&self, width: usize, height: usize, interactive: bool) -> String {
+        if self.columns.is_empty() {
+            return format!(" {}Nothing to display.{} ", Color::MUTED.to_ansi_fg(), "\x1b[0m");
         }
-        None
-    }
 
-    fn view(&self) -> String {
-        if self.columns.is_empty() {
-            return format!(
-                " {}Nothing to display.{} ",
-                Color::BrightBlack.to_ansi_fg(),
+        let widths = self.calculate_widths_for(width);
+        let frozen = self.frozen_width(&widths);
+        let effective_width = width;
+        let needs_scroll = interactive && (effective_width > 0 || self.scroll.h_offset() > 0);
+        let mut output = String::new();
+
+        // Filter bar
+        if interactive && self.shows_filter_bar() {
+            let line = format!(
+                "{}/ {}{}",
+                self.filter_color.to_ansi_fg(),
+                if self.filter.is_empty() {
+                    format!("{}Type to filter...", Color::MUTED.to_ansi_fg())
+                } else {
+                    self.filter.clone()
+                },
                 "\x1b[0m"
             );
+            output.push_str(&if width > 0 { crop(&line, width) } else { line });
+            output.push('\n');
         }
 
-        let widths = self.calculate_widths();
-        let effective_width = if self.width > 0 { self.width } else { 0 };
-        let needs_scroll = effective_width > 0 || self.h_scroll_offset > 0;
-        let mut output = String::new();
-
         // Top border
         if self.show_borders {
             let border = self.render_border(&widths, "┌", "┬", "┐");
             if needs_scroll {
-                output.push_str(&self.apply_h_scroll(&border, effective_width));
+                output.push_str(&self.apply_h_scroll(&border, frozen, effective_width));
             } else {
                 output.push_str(&border);
             }
@@ -671,9 +1774,28 @@ impl Model for Table {
             if self.show_borders {
                 header_line.push_str(&format!("{}│{}", self.border_color.to_ansi_fg(), "\x1b[0m"));
             }
+            if self.multi_select {
+                header_line.push_str(&format!(" {} ", self.checkbox(self.all_selected())));
+                if self.show_borders {
+                    header_line.push_str(&format!(
+                        "{}│{}",
+                        self.border_color.to_ansi_fg(),
+                        "\x1b[0m"
+                    ));
+                }
+            }
 
-            for (i, col) in self.columns.iter().enumerate() {
-                let text = self.align_text(&col.title, widths[i], col.align);
+            for (i, col) in self.columns.iter().enumerate().filter(|(_, c)| !c.hidden) {
+                let title = match self.sort {
+                    Some((sorted, SortOrder::Ascending)) if sorted == i => {
+                        format!("{} ▲", col.title)
+                    },
+                    Some((sorted, SortOrder::Descending)) if sorted == i => {
+                        format!("{} ▼", col.title)
+                    },
+                    _ => col.title.clone(),
+                };
+                let text = self.align_text(&title, widths[i], col.align);
                 header_line.push_str(&format!(
                     "{}\x1b[1m {} {}\x1b[0m",
                     self.header_color.to_ansi_fg(),
@@ -689,7 +1811,7 @@ impl Model for Table {
                 }
             }
             if needs_scroll {
-                output.push_str(&self.apply_h_scroll(&header_line, effective_width));
+                output.push_str(&self.apply_h_scroll(&header_line, frozen, effective_width));
             } else {
                 output.push_str(&header_line);
             }
@@ -699,7 +1821,7 @@ impl Model for Table {
             if self.show_borders {
                 let sep = self.render_border(&widths, "├", "┼", "┤");
                 if needs_scroll {
-                    output.push_str(&self.apply_h_scroll(&sep, effective_width));
+                    output.push_str(&self.apply_h_scroll(&sep, frozen, effective_width));
                 } else {
                     output.push_str(&sep);
                 }
@@ -708,31 +1830,35 @@ impl Model for Table {
         }
 
         // Data rows
-        if self.rows.is_empty() {
-            output.push_str(&format!("{}(no data){}", Color::BrightBlack.to_ansi_fg(), "\x1b[0m"));
+        if self.display_len() == 0 {
+            let message = if self.rows.is_empty() { "(no data)" } else { "(no matching rows)" };
+            output.push_str(&format!("{}{}{}", Color::MUTED.to_ansi_fg(), message, "\x1b[0m"));
         } else {
-            let (start, end) = self.visible_range();
+            let (start, end) =
+                if interactive { self.visible_range(height) } else { (0, self.display_len()) };
 
             // Scroll indicator (top)
             if start > 0 {
                 output.push_str(&format!(
                     "{}  ↑ {} more rows{}",
-                    Color::BrightBlack.to_ansi_fg(),
+                    Color::MUTED.to_ansi_fg(),
                     start,
                     "\x1b[0m\n"
                 ));
             }
 
-            for (view_idx, row_idx) in (start..end).enumerate() {
-                let row = &self.rows[row_idx];
+            for (view_idx, display_idx) in (start..end).enumerate() {
+                let Some(row_idx) = self.row_index(display_idx) else { break };
+                let row = self.row(row_idx);
                 // Only show selection when focused
-                let is_selected_row = self.focused && row_idx == self.cursor_row;
+                let is_selected_row =
+                    interactive && self.focused && display_idx == self.scroll.selected();
 
                 // Determine row color
                 let row_color = if is_selected_row {
                     self.selected_row_color.clone()
                 } else if let Some(ref alt) = self.alt_row_color {
-                    if row_idx % 2 == 1 { alt.clone() } else { self.row_color.clone() }
+                    if display_idx % 2 == 1 { alt.clone() } else { self.row_color.clone() }
                 } else {
                     self.row_color.clone()
                 };
@@ -742,10 +1868,30 @@ impl Model for Table {
                 if self.show_borders {
                     row_line.push_str(&format!("{}│{}", self.border_color.to_ansi_fg(), "\x1b[0m"));
                 }
+                if self.multi_select {
+                    row_line
+                        .push_str(&format!(" {} ", self.checkbox(self.is_row_selected(row_idx))));
+                    if self.show_borders {
+                        row_line.push_str(&format!(
+                            "{}│{}",
+                            self.border_color.to_ansi_fg(),
+                            "\x1b[0m"
+                        ));
+                    }
+                }
 
-                for (col_idx, col) in self.columns.iter().enumerate() {
-                    let cell_value = row.get(col_idx).map(|s| s.as_str()).unwrap_or("");
-                    let text = self.align_text(cell_value, widths[col_idx], col.align);
+                for (col_idx, col) in self.columns.iter().enumerate().filter(|(_, c)| !c.hidden) {
+                    // Rows still loading from a source show a placeholder.
+                    let cell_value = match &row {
+                        Some(row) => row.get(col_idx).map(|s| s.as_str()).unwrap_or(""),
+                        None => "…",
+                    };
+                    let text = match self.links.get(&(row_idx, col_idx)) {
+                        Some(url) => {
+                            self.align_text(&hyperlink(cell_value, url), widths[col_idx], col.align)
+                        },
+                        None => self.align_text(cell_value, widths[col_idx], col.align),
+                    };
 
                     let is_selected_cell =
                         self.cell_selection && is_selected_row && col_idx == self.cursor_col;
@@ -780,7 +1926,7 @@ impl Model for Table {
 
                 // Apply horizontal scroll to the row and pad to width if needed
                 if needs_scroll {
-                    output.push_str(&self.apply_h_scroll(&row_line, effective_width));
+                    output.push_str(&self.apply_h_scroll(&row_line, frozen, effective_width));
                 } else {
                     output.push_str(&row_line);
                 }
@@ -791,14 +1937,14 @@ impl Model for Table {
             }
 
             // Scroll indicator (bottom)
-            let remaining = self.rows.len().saturating_sub(end);
+            let remaining = self.display_len().saturating_sub(end);
             if remaining > 0 {
                 if !self.show_borders {
                     output.push('\n');
                 }
                 output.push_str(&format!(
                     "{}  ↓ {} more rows{}",
-                    Color::BrightBlack.to_ansi_fg(),
+                    Color::MUTED.to_ansi_fg(),
                     remaining,
                     "\x1b[0m"
                 ));
@@ -806,7 +1952,7 @@ impl Model for Table {
                 // Bottom border (only if no scroll indicator)
                 let border = self.render_border(&widths, "└", "┴", "┘");
                 if needs_scroll {
-                    output.push_str(&self.apply_h_scroll(&border, effective_width));
+                    output.push_str(&self.apply_h_scroll(&border, frozen, effective_width));
                 } else {
                     output.push_str(&border);
                 }
@@ -815,6 +1961,96 @@ impl Model for Table {
 
         output
     }
+}
+
+impl Model for Table {
+    type Message = TableMsg;
+
+    fn init(&self) -> Option<Cmd<Self::Message>> {
+        self.fetch()
+    }
+
+    fn update(&mut self, msg: Self::Message) -> Option<Cmd<Self::Message>> {
+        // A source may have shrunk since the last update.
+        if self.source.is_some() {
+            self.scroll.clamp(self.display_len(), self.height);
+        }
+
+        // Anything else brings the cursor back into view.
+        if !matches!(msg, TableMsg::Loaded | TableMsg::ScrollUp(_) | TableMsg::ScrollDown(_)) {
+            self.wheel_scrolled = false;
+        }
+
+        match msg {
+            TableMsg::Loaded => return None,
+            TableMsg::Up => self.move_up(),
+            TableMsg::Down => self.move_down(),
+            TableMsg::Left => self.move_left(),
+            TableMsg::Right => self.move_right(),
+            TableMsg::First => self.move_first(),
+            TableMsg::Last => self.move_last(),
+            TableMsg::PageUp => self.page_up(),
+            TableMsg::PageDown => self.page_down(),
+            TableMsg::Submit => {
+                if self.display_len() > 0 {
+                    self.submitted = true;
+                }
+            },
+            TableMsg::SortBy(col) => self.toggle_sort(col),
+            TableMsg::CycleSort => self.cycle_sort(),
+            TableMsg::StartFilter => self.filtering = self.filterable && self.source.is_none(),
+            TableMsg::InsertFilterChar(c) => {
+                self.filter.push(c);
+                self.rebuild_order();
+            },
+            TableMsg::DeleteFilterChar => {
+                if self.filter.pop().is_some() {
+                    self.rebuild_order();
+                }
+            },
+            TableMsg::ApplyFilter => self.filtering = false,
+            TableMsg::ClearFilter => {
+                self.filtering = false;
+                self.set_filter(String::new());
+            },
+            TableMsg::Click { row, col } => self.click(row, col),
+            TableMsg::ToggleRow(position) => self.toggle_row(position),
+            TableMsg::SelectTo(position) => self.select_to(position),
+            TableMsg::SelectAll => self.select_all(),
+            TableMsg::SetColumnWidth { col, width } => {
+                self.set_column_width(col, width);
+                self.scroll_right(0);
+            },
+            TableMsg::StartResize(col) => self.resizing = Some(col),
+            TableMsg::EndResize => self.resizing = None,
+            TableMsg::ToggleColumn(col) => self.toggle_column(col),
+            TableMsg::ScrollUp(lines) => self.scroll_rows_up(lines),
+            TableMsg::ScrollDown(lines) => self.scroll_rows_down(lines),
+            TableMsg::ScrollLeft(step) => self.scroll.scroll_left(step),
+            TableMsg::ScrollRight(step) => self.scroll_right(step),
+            TableMsg::ClearSelection => self.clear_selection(),
+            TableMsg::Cancel => self.cancelled = true,
+            TableMsg::Focus => self.focused = true,
+            TableMsg::Blur => self.focused = false,
+        }
+        self.fetch()
+    }
+
+    fn view(&self) -> String {
+        self.render_sized(self.width, self.height, true)
+    }
+
+    fn view_in(&self, area: Rect) -> String {
+        self.area.set(area);
+        crate::layout::clip(
+            &self.render_sized(self.fitted_width(area), self.fitted_height(area), true),
+            area,
+        )
+    }
+
+    fn view_static(&self) -> String {
+        self.render_sized(0, 0, false)
+    }
 
     fn handle_event(&self, event: Event) -> Option<Self::Message> {
         if !self.focused {
@@ -832,9 +2068,40 @@ impl Model for Table {
                     };
                 }
 
+                if self.filtering {
+                    return match key.code {
+                        KeyCode::Up => Some(TableMsg::Up),
+                        KeyCode::Down => Some(TableMsg::Down),
+                        KeyCode::Enter => Some(TableMsg::ApplyFilter),
+                        KeyCode::Esc => Some(TableMsg::ClearFilter),
+                        KeyCode::Backspace => Some(TableMsg::DeleteFilterChar),
+                        KeyCode::Char(c) => Some(TableMsg::InsertFilterChar(c)),
+                        _ => None,
+                    };
+                }
+
+                let cursor = self.scroll.selected();
+                if self.multi_select && key.modifiers.contains(KeyModifiers::SHIFT) {
+                    match key.code {
+                        KeyCode::Up => return Some(TableMsg::SelectTo(cursor.saturating_sub(1))),
+                        KeyCode::Down => return Some(TableMsg::SelectTo(cursor + 1)),
+                        _ => {},
+                    }
+                }
+
                 match key.code {
                     KeyCode::Up | KeyCode::Char('k') => Some(TableMsg::Up),
                     KeyCode::Down | KeyCode::Char('j') => Some(TableMsg::Down),
+                    KeyCode::Char(' ') if self.multi_select => Some(TableMsg::ToggleRow(cursor)),
+                    KeyCode::Char('a') if self.multi_select => Some(TableMsg::SelectAll),
+                    KeyCode::Char(c @ '1'..='9') if self.column_toggle_keys => {
+                        Some(TableMsg::ToggleColumn(c as usize - '1' as usize))
+                    },
+                    KeyCode::Char(c @ ('<' | '>')) if self.cell_selection => {
+                        let width = self.column_width(self.cursor_col);
+                        let width = if c == '<' { width.saturating_sub(1) } else { width + 1 };
+                        Some(TableMsg::SetColumnWidth { col: self.cursor_col, width })
+                    },
                     KeyCode::Left | KeyCode::Char('h') => Some(TableMsg::Left),
                     KeyCode::Right | KeyCode::Char('l') => Some(TableMsg::Right),
                     KeyCode::Home => Some(TableMsg::First),
@@ -842,10 +2109,20 @@ impl Model for Table {
                     KeyCode::PageUp => Some(TableMsg::PageUp),
                     KeyCode::PageDown => Some(TableMsg::PageDown),
                     KeyCode::Enter | KeyCode::Char(' ') => Some(TableMsg::Submit),
+                    KeyCode::Char('/') if self.filterable && self.source.is_none() => {
+                        Some(TableMsg::StartFilter)
+                    },
+                    KeyCode::Char('s') if self.cell_selection => {
+                        Some(TableMsg::SortBy(self.cursor_col))
+                    },
+                    KeyCode::Char('s') => Some(TableMsg::CycleSort),
+                    KeyCode::Esc if !self.filter.is_empty() => Some(TableMsg::ClearFilter),
+                    KeyCode::Esc if self.has_selection() => Some(TableMsg::ClearSelection),
                     KeyCode::Esc | KeyCode::Char('q') => Some(TableMsg::Cancel),
                     _ => None,
                 }
             },
+            Event::Mouse(mouse) => self.handle_mouse(mouse),
             _ => None,
         }
     }
@@ -921,16 +2198,41 @@ mod tests {
             .rows((1..=10).map(|i| vec![i.to_string()]).collect::<Vec<_>>())
             .height(3);
 
-        assert_eq!(table.visible_range(), (0, 3));
+        assert_eq!(table.visible_range(table.height), (0, 3));
         table.move_down();
         table.move_down();
         table.move_down();
         assert_eq!(table.cursor_row(), 3);
-        assert_eq!(table.visible_range(), (1, 4));
+        assert_eq!(table.visible_range(table.height), (1, 4));
 
         table.move_last();
         assert_eq!(table.cursor_row(), 9);
-        assert_eq!(table.visible_range(), (7, 10));
+        assert_eq!(table.visible_range(table.height), (7, 10));
+    }
+
+    #[test]
+    fn test_wheel_scroll() {
+        let mut table = Table::new()
+            .columns(vec![Column::new("Num")])
+            .rows((1..=10).map(|i| vec![i.to_string()]).collect::<Vec<_>>())
+            .height(3);
+
+        // The wheel moves the rows, not the cursor.
+        table.update(TableMsg::ScrollDown(WHEEL_LINES));
+        assert_eq!(table.cursor_row(), 0);
+        assert_eq!(table.visible_range(table.height), (3, 6));
+        table.update(TableMsg::ScrollDown(WHEEL_LINES * 3));
+        assert_eq!(table.visible_range(table.height), (7, 10));
+
+        table.update(TableMsg::Last);
+        table.update(TableMsg::ScrollUp(WHEEL_LINES));
+        assert_eq!(table.cursor_row(), 9);
+        assert_eq!(table.visible_range(table.height), (4, 7));
+
+        // Moving the cursor brings it back into view.
+        table.update(TableMsg::Up);
+        assert_eq!(table.cursor_row(), 8);
+        assert_eq!(table.visible_range(table.height), (6, 9));
     }
 
     #[test]
@@ -952,4 +2254,504 @@ mod tests {
         assert_eq!(widths[0], 9); // "Alexander" is longest
         assert_eq!(widths[1], 3); // "Age" header (min 3)
     }
+
+    #[test]
+    fn test_view_in_area() {
+        let mut table = Table::new()
+            .columns(vec![Column::new("N")])
+            .rows((0..20).map(|i| vec![i.to_string()]))
+            .height(3);
+        for _ in 0..6 {
+            table.update(TableMsg::Down);
+        }
+
+        // The height caps the area: the header, 3 rows and their indicators.
+        let view = table.view_in(Rect::from_size(12, 10));
+        let lines: Vec<&str> = view.lines().collect();
+        assert_eq!(lines.len(), 8);
+        assert!(lines.iter().all(|line| crate::style::width(line) <= 12));
+
+        // The cursor stays visible.
+        let text = crate::style::strip_ansi(&view);
+        assert!(text.contains(" 6 "));
+        assert!(text.contains("↓"));
+    }
+
+    #[test]
+    fn test_cell_links_keep_alignment() {
+        let rows = vec![vec!["docs".to_string(), "1".to_string()]];
+        let columns = vec![Column::new("Name"), Column::new("N")];
+        let plain = Table::new().columns(columns.clone()).rows(rows.clone()).focused(false);
+        let linked = plain.clone().cell_link(0, 0, "https://example.com");
+
+        assert_eq!(linked.cell_link_at(0, 0), Some("https://example.com"));
+        assert_eq!(
+            crate::style::strip_ansi(&linked.render()),
+            crate::style::strip_ansi(&plain.render())
+        );
+    }
+
+    #[test]
+    fn test_mouse_click() {
+        let mut table = Table::new()
+            .columns(vec![Column::new("A").width(3), Column::new("B").width(3)])
+            .rows(vec![vec!["1", "2"], vec!["3", "4"]])
+            .cell_selection(true);
+        table.set_area(Rect::new(0, 0, 80, 24));
+        let click = |column, row| {
+            Event::Mouse(MouseEvent::new(MouseEventKind::Down(MouseButton::Left), column, row))
+        };
+
+        // Top border, header and separator sit above the data;
+        // "│ 1   │ 2   │" puts column B at x = 7..12.
+        assert!(table.handle_event(click(2, 1)).is_none());
+        let msg = table.handle_event(click(8, 4)).unwrap();
+        table.update(msg);
+        assert_eq!((table.cursor_row(), table.cursor_col()), (1, 1));
+        assert_eq!(table.current_cell(), Some("4"));
+
+        let msg = table.handle_event(click(8, 4)).unwrap();
+        table.update(msg);
+        assert!(table.is_submitted());
+
+        let msg = table.handle_event(Event::Mouse(MouseEvent::new(MouseEventKind::ScrollUp, 0, 0)));
+        assert!(matches!(msg, Some(TableMsg::ScrollUp(WHEEL_LINES))));
+    }
+
+    fn sortable_table() -> Table {
+        Table::new()
+            .columns(vec![
+                Column::new("Name").sort_key(SortKey::Natural),
+                Column::new("Size").sort_key(SortKey::Numeric),
+                Column::new("Kind"),
+            ])
+            .rows(vec![
+                vec!["file10", "2.5", "log"],
+                vec!["File2", "10", "txt"],
+                vec!["file1", "n/a", "log"],
+            ])
+    }
+
+    #[test]
+    fn test_sort_keys() {
+        assert_eq!(SortKey::Natural.compare("file2", "file10"), Ordering::Less);
+        assert_eq!(SortKey::Natural.compare("a01", "a1"), Ordering::Greater);
+        assert_eq!(SortKey::Lexical.compare("file2", "file10"), Ordering::Greater);
+        assert_eq!(SortKey::Numeric.compare("9", "10.5"), Ordering::Less);
+        assert_eq!(SortKey::Numeric.compare("-", "3"), Ordering::Greater);
+        let by_len = SortKey::custom(|a, b| a.len().cmp(&b.len()));
+        assert_eq!(by_len.compare("bb", "a"), Ordering::Greater);
+    }
+
+    #[test]
+    fn test_sort_keeps_selection() {
+        let mut table = sortable_table();
+        table.update(TableMsg::Down);
+        assert_eq!(table.current_row().unwrap()[0], "File2");
+
+        table.update(TableMsg::SortBy(0));
+        assert_eq!(table.sorted_by(), Some((0, SortOrder::Ascending)));
+        assert_eq!(table.cursor_row(), 1);
+        assert_eq!(table.current_row().unwrap()[0], "File2");
+        assert!(crate::style::strip_ansi(&table.render()).contains("Name ▲"));
+
+        table.update(TableMsg::SortBy(0));
+        assert_eq!(table.current_row_index(), Some(1));
+        assert_eq!(table.cursor_row(), 1);
+
+        // Unsortable columns are ignored; numeric sort puts "n/a" last.
+        table.update(TableMsg::SortBy(2));
+        assert_eq!(table.sorted_by(), Some((0, SortOrder::Descending)));
+        table.update(TableMsg::CycleSort);
+        table.update(TableMsg::Last);
+        assert_eq!(table.sorted_by(), Some((1, SortOrder::Ascending)));
+        assert_eq!(table.current_row().unwrap()[1], "n/a");
+        table.update(TableMsg::CycleSort);
+        table.update(TableMsg::CycleSort);
+        assert_eq!(table.sorted_by(), None);
+    }
+
+    #[test]
+    fn test_filter() {
+        let mut table = sortable_table();
+        for c in ['/', 'l', 'o', 'g'] {
+            let key =
+                crate::terminal::KeyEvent { code: KeyCode::Char(c), modifiers: KeyModifiers::NONE };
+            let msg = table.handle_event(Event::Key(key)).unwrap();
+            table.update(msg);
+        }
+        assert!(table.is_filtering());
+        assert_eq!(table.filtered_count(), 2);
+        assert!(crate::style::strip_ansi(&table.render()).starts_with("/ log\n"));
+
+        table.set_filter("kind:txt");
+        assert_eq!(table.current_row().unwrap()[0], "File2");
+        table.set_filter("name:txt");
+        assert_eq!(table.filtered_count(), 0);
+        assert!(crate::style::strip_ansi(&table.render()).contains("(no matching rows)"));
+
+        table.update(TableMsg::ClearFilter);
+        table.set_column_filter(1, |size| size.parse::<f64>().is_ok_and(|n| n > 5.0));
+        assert_eq!(table.filtered_count(), 1);
+        assert_eq!(table.current_row().unwrap()[0], "File2");
+    }
+
+    #[test]
+    fn test_header_click_sorts() {
+        let mut table = sortable_table();
+        let click = Event::Mouse(MouseEvent::new(MouseEventKind::Down(MouseButton::Left), 2, 1));
+        assert!(table.handle_event(click.clone()).is_none());
+
+        table.set_area(Rect::new(0, 0, 80, 24));
+        let msg = table.handle_event(click).unwrap();
+        assert!(matches!(msg, TableMsg::SortBy(0)));
+    }
+
+    #[test]
+    fn test_source() {
+        use std::{
+            ops::Range,
+            sync::{Mutex, atomic::AtomicUsize},
+        };
+
+        /// Counts row reads and records fetched ranges; rows past 1000 are
+        /// "still loading".
+        #[derive(Default)]
+        struct Numbers {
+            reads: AtomicUsize,
+            fetched: Mutex<Vec<Range<usize>>>,
+        }
+
+        impl TableSource for Arc<Numbers> {
+            fn len(&self) -> usize {
+                1_000_000
+            }
+
+            fn row(&self, index: usize) -> Option<Vec<String>> {
+                self.reads.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+                (index < 1000).then(|| vec![index.to_string()])
+            }
+
+            fn fetch(&self, range: Range<usize>) -> Option<Cmd<()>> {
+                self.fetched.lock().unwrap().push(range);
+                Some(Cmd::none())
+            }
+        }
+
+        let numbers = Arc::new(Numbers::default());
+        let mut table = Table::new()
+            .columns(vec![Column::new("n").width(8).sort_key(SortKey::Numeric)])
+            .source(Arc::clone(&numbers))
+            .height(5);
+        assert_eq!(table.row_count(), 1_000_000);
+        assert!(table.init().is_some());
+        assert_eq!(numbers.fetched.lock().unwrap().last(), Some(&(0..10)));
+
+        let view = crate::style::strip_ansi(&table.render());
+        assert!(view.contains(" 4 ") && !view.contains(" 5 "));
+        assert!(view.contains("999995 more rows"));
+        assert!(numbers.reads.load(std::sync::atomic::Ordering::Relaxed) <= 5);
+
+        // Sorting and filtering don't apply to a source.
+        table.update(TableMsg::SortBy(0));
+        assert_eq!(table.sorted_by(), None);
+        table.update(TableMsg::StartFilter);
+        assert!(!table.is_filtering());
+
+        table.update(TableMsg::Last);
+        assert_eq!(table.current_row_index(), Some(999_999));
+        assert!(table.current_row().is_none());
+        assert_eq!(numbers.fetched.lock().unwrap().last(), Some(&(999_995..1_000_000)));
+        assert!(crate::style::strip_ansi(&table.render()).contains("│ …"));
+        assert_eq!(table.row(3).as_deref(), Some(&["3".to_string()][..]));
+
+        // Select-all flips a flag rather than checking a million rows.
+        table.update(TableMsg::SelectAll);
+        assert!(table.is_row_selected(999_999));
+        table.update(TableMsg::ToggleRow(5));
+        assert!(!table.is_row_selected(5) && table.is_row_selected(6));
+        table.update(TableMsg::SelectAll);
+        assert!(table.is_row_selected(5));
+        table.update(TableMsg::SelectAll);
+        assert!(!table.is_row_selected(0));
+        assert!(!table.is_row_selected(1_000_000));
+
+        // Owned rows replace the source.
+        table.set_rows(vec![vec!["x"]]);
+        assert_eq!(table.row_count(), 1);
+        assert_eq!(table.current_row().map(|row| row[0].as_str()), Some("x"));
+    }
+
+    #[test]
+    fn test_shrinking_source() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        struct Shrinking(Arc<AtomicUsize>);
+
+        impl TableSource for Shrinking {
+            fn len(&self) -> usize {
+                self.0.load(Ordering::Relaxed)
+            }
+
+            fn row(&self, index: usize) -> Option<Vec<String>> {
+                (index < self.len()).then(|| vec![index.to_string()])
+            }
+
+            fn fetch(&self, _range: std::ops::Range<usize>) -> Option<Cmd<()>> {
+                Some(Cmd::none())
+            }
+        }
+
+        let len = Arc::new(AtomicUsize::new(100));
+        let mut table = Table::new()
+            .columns(vec![Column::new("n").width(4)])
+            .source(Shrinking(Arc::clone(&len)))
+            .height(5);
+        table.update(TableMsg::Last);
+
+        len.store(3, Ordering::Relaxed);
+        table.update(TableMsg::Up);
+        assert_eq!(table.current_row_index(), Some(1));
+        assert!(crate::style::strip_ansi(&table.render()).contains(" 2 "));
+
+        len.store(0, Ordering::Relaxed);
+        table.update(TableMsg::Down);
+        assert_eq!(table.row_count(), 0);
+    }
+
+    #[test]
+    fn test_multi_select() {
+        let mut table = sortable_table().multi_select(true).sort_by(0, SortOrder::Ascending);
+        let key = |code, modifiers| Event::Key(crate::terminal::KeyEvent { code, modifiers });
+        let press = |table: &mut Table, code, modifiers| {
+            let msg = table.handle_event(key(code, modifiers)).unwrap();
+            table.update(msg);
+        };
+
+        // Display order is file1, File2, file10.
+        press(&mut table, KeyCode::Home, KeyModifiers::NONE);
+        press(&mut table, KeyCode::Char(' '), KeyModifiers::NONE);
+        assert_eq!(table.selected_indices(), vec![2]);
+        assert!(!table.is_submitted());
+        press(&mut table, KeyCode::Down, KeyModifiers::SHIFT);
+        press(&mut table, KeyCode::Down, KeyModifiers::SHIFT);
+        assert_eq!(table.cursor_row(), 2);
+        assert_eq!(table.selected_rows().len(), 3);
+        assert_eq!(table.selected_rows()[0][0], "file10");
+
+        press(&mut table, KeyCode::Char('a'), KeyModifiers::NONE);
+        assert!(table.selected_indices().is_empty());
+        press(&mut table, KeyCode::Char('a'), KeyModifiers::NONE);
+        assert_eq!(table.selected_indices(), vec![0, 1, 2]);
+        press(&mut table, KeyCode::Esc, KeyModifiers::NONE);
+        assert!(table.selected_indices().is_empty());
+
+        // Checked rows survive filtering; select-all only touches visible rows.
+        table.set_row_selected(1, true);
+        table.set_filter("log");
+        table.update(TableMsg::SelectAll);
+        assert_eq!(table.selected_indices(), vec![0, 1, 2]);
+        table.update(TableMsg::SelectAll);
+        assert_eq!(table.selected_indices(), vec![1]);
+        assert!(table.is_row_selected(1));
+
+        let view = crate::style::strip_ansi(&table.render());
+        assert!(view.contains("│ [ ] │ file1 "));
+    }
+
+    #[test]
+    fn test_checkbox_click() {
+        let mut table = sortable_table().multi_select(true);
+        table.set_area(Rect::new(0, 0, 80, 24));
+        let press = |column, row, modifiers| {
+            Event::Mouse(MouseEvent {
+                kind: MouseEventKind::Down(MouseButton::Left),
+                column,
+                row,
+                modifiers,
+            })
+        };
+
+        let msg = table.handle_event(press(2, 4, KeyModifiers::NONE)).unwrap();
+        assert!(matches!(msg, TableMsg::ToggleRow(1)));
+        table.update(msg);
+        let msg = table.handle_event(press(9, 5, KeyModifiers::SHIFT)).unwrap();
+        table.update(msg);
+        assert_eq!(table.selected_indices(), vec![1, 2]);
+        assert!(matches!(
+            table.handle_event(press(9, 4, KeyModifiers::NONE)),
+            Some(TableMsg::Click { row: 1, col: Some(0) })
+        ));
+        assert!(matches!(
+            table.handle_event(press(3, 1, KeyModifiers::NONE)),
+            Some(TableMsg::SelectAll)
+        ));
+    }
+
+    fn wide_table() -> Table {
+        Table::new()
+            .columns(vec![
+                Column::new("Id"),
+                Column::new("Description").max_width(12),
+                Column::new("Owner").min_width(8),
+                Column::new("State"),
+            ])
+            .rows(vec![
+                vec!["1", "A very long description indeed", "ann", "open"],
+                vec!["2", "Short", "bob", "closed"],
+            ])
+    }
+
+    #[test]
+    fn test_column_bounds() {
+        let mut table = wide_table();
+        assert_eq!(table.column_width(0), 3);
+        assert_eq!(table.column_width(1), 12);
+        assert_eq!(table.column_width(2), 8);
+
+        table.set_column_width(1, 40);
+        assert_eq!(table.column_width(1), 12);
+        table.set_column_width(2, 2);
+        assert_eq!(table.column_width(2), 8);
+        table.set_column_width(0, 0);
+        assert_eq!(table.column_width(0), 1);
+
+        let grown = Table::new()
+            .columns(vec![Column::new("a").width(4), Column::new("b").grow().max_width(10)])
+            .width(40);
+        assert_eq!(grown.column_width(1), 10);
+    }
+
+    #[test]
+    fn test_hidden_columns() {
+        let key = |c| {
+            Event::Key(crate::terminal::KeyEvent {
+                code: KeyCode::Char(c),
+                modifiers: KeyModifiers::NONE,
+            })
+        };
+        assert!(wide_table().handle_event(key('2')).is_none());
+
+        let mut table = wide_table().cell_selection(true).column_toggle_keys(true);
+        let msg = table.handle_event(key('2')).unwrap();
+        table.update(msg);
+        assert!(!table.is_column_visible(1));
+        assert_eq!(table.column_width(1), 0);
+        let view = crate::style::strip_ansi(&table.render());
+        assert!(!view.contains("Description") && view.contains("Owner"));
+        assert_eq!(table.content_width(), width(view.lines().next().unwrap()));
+
+        // The cursor skips hidden columns.
+        table.update(TableMsg::Right);
+        assert_eq!(table.cursor_col(), 2);
+        table.set_column_visible(2, false);
+        assert_eq!(table.cursor_col(), 3);
+
+        table.update(TableMsg::ToggleColumn(0));
+        table.update(TableMsg::ToggleColumn(3));
+        assert!(table.is_column_visible(3));
+        table.update(TableMsg::ToggleColumn(1));
+        assert!(table.is_column_visible(1));
+    }
+
+    #[test]
+    fn test_frozen_columns() {
+        let mut table = wide_table().frozen_columns(1).width(20);
+        table.update(TableMsg::Right);
+        table.update(TableMsg::Right);
+        let view = crate::style::strip_ansi(&table.render());
+        let header = view.lines().nth(1).unwrap();
+        assert!(header.starts_with("│ Id  │"));
+        assert!(!header.contains("Descr"));
+        assert_eq!(width(header), 20);
+
+        // Scrolling stops once the last column is in view.
+        for _ in 0..10 {
+            table.update(TableMsg::ScrollRight(H_SCROLL_STEP));
+        }
+        let view = crate::style::strip_ansi(&table.render());
+        assert!(view.lines().nth(1).unwrap().ends_with("│ State  │"));
+
+        // Moving the cell cursor scrolls its column into view.
+        let mut table = wide_table().frozen_columns(1).width(20).cell_selection(true);
+        table.update(TableMsg::Right);
+        table.update(TableMsg::Right);
+        let view = crate::style::strip_ansi(&table.render());
+        assert!(view.lines().nth(1).unwrap().contains("Owner"));
+    }
+
+    #[test]
+    fn test_resize_drag() {
+        let mut table = wide_table();
+        table.set_area(Rect::new(0, 0, 80, 24));
+        let mouse = |kind, column| Event::Mouse(MouseEvent::new(kind, column, 1));
+        let left = MouseButton::Left;
+
+        // "│ Id  │": the border after the first column is at x = 6.
+        let msg = table.handle_event(mouse(MouseEventKind::Down(left), 6)).unwrap();
+        assert!(matches!(msg, TableMsg::StartResize(0)));
+        table.update(msg);
+        let msg = table.handle_event(mouse(MouseEventKind::Drag(left), 10)).unwrap();
+        assert!(matches!(msg, TableMsg::SetColumnWidth { col: 0, width: 7 }));
+        table.update(msg);
+        assert_eq!(table.column_width(0), 7);
+        let msg = table.handle_event(mouse(MouseEventKind::Up(left), 10)).unwrap();
+        table.update(msg);
+        assert!(table.handle_event(mouse(MouseEventKind::Drag(left), 12)).is_none());
+    }
+
+    #[test]
+    fn test_export() {
+        let mut table = sortable_table().sort_by(1, SortOrder::Descending);
+        table.columns[1].align = Align::Right;
+        table.set_column_visible(2, false);
+        table.set_rows(vec![
+            vec!["file10", "2.5", "log"],
+            vec!["File2", "10", "txt"],
+            vec!["say \"hi\", |x|", "n/a", "log"],
+            vec!["\x1b[1mbold\x1b[0m\tit", "1", "log"],
+        ]);
+        table.set_filter("log");
+        let export = |table: &Table, format| {
+            let mut out = Vec::new();
+            table.export(format, &mut out).unwrap();
+            String::from_utf8(out).unwrap()
+        };
+
+        assert_eq!(
+            export(&table, ExportFormat::Csv),
+            "Name,Size\n\"say \"\"hi\"\", |x|\",n/a\nfile10,2.5\nbold\tit,1\n"
+        );
+        assert_eq!(
+            export(&table, ExportFormat::Tsv),
+            "Name\tSize\nsay \"hi\", |x|\tn/a\nfile10\t2.5\nbold it\t1\n"
+        );
+        assert_eq!(
+            export(&table, ExportFormat::Json),
+            "[\n  {\"Name\": \"say \\\"hi\\\", |x|\", \"Size\": \"n/a\"},\n  {\"Name\": \"file10\", \"Size\": \"2.5\"},\n  {\"Name\": \"bold\\tit\", \"Size\": \"1\"}\n]\n"
+        );
+        assert_eq!(
+            export(&table, ExportFormat::Markdown),
+            "| Name | Size |\n| --- | ---: |\n| say \"hi\", \\|x\\| | n/a |\n| file10 | 2.5 |\n| bold\tit | 1 |\n"
+        );
+
+        table.set_filter("nothing");
+        assert_eq!(export(&table, ExportFormat::Json), "[\n]\n");
+    }
+
+    #[test]
+    fn test_view_static() {
+        let mut table = Table::new()
+            .columns(vec![Column::new("n")])
+            .rows((0..20).map(|i| vec![i.to_string()]))
+            .height(3)
+            .width(4);
+        table.set_filter("1");
+        let view = table.view_static();
+        assert!(!view.contains("\x1b[7m"));
+        let view = crate::style::strip_ansi(&view);
+        assert!(!view.contains("/ 1") && !view.contains("more rows"));
+        assert_eq!(view.lines().count(), 11 + 4);
+        assert!(view.contains("│ 19  │"));
+    }
 }
@@ -62,11 +62,11 @@ impl TaskState {
     /// Get the color for this state.
     pub fn color(&self) -> Color {
         match self {
-            TaskState::Pending => Color::BrightBlack,
-            TaskState::Running => Color::Cyan,
-            TaskState::Success => Color::Green,
-            TaskState::Failure => Color::Red,
-            TaskState::Skipped => Color::Yellow,
+            TaskState::Pending => Color::MUTED,
+            TaskState::Running => Color::PRIMARY,
+            TaskState::Success => Color::SUCCESS,
+            TaskState::Failure => Color::ERROR,
+            TaskState::Skipped => Color::WARNING,
         }
     }
 
@@ -269,13 +269,13 @@ impl TaskList {
             if let Some(ref detail) = task.detail
                 && (task.state.is_terminal() || task.state == TaskState::Running)
             {
-                let dim = Color::BrightBlack.to_ansi_fg();
+                let dim = Color::MUTED.to_ansi_fg();
                 output.push_str(&format!("{}  {}{}\r\n", dim, detail, reset));
             }
 
             // Error line (if present)
             if let Some(ref error) = task.error {
-                let red = Color::Red.to_ansi_fg();
+                let red = Color::ERROR.to_ansi_fg();
                 output.push_str(&format!("{}  {}{}\r\n", red, error, reset));
             }
 
@@ -510,9 +510,9 @@ impl TaskProgressView {
 
             let modal = Modal::new(modal_width, modal_height)
                 .border(ModalBorder::Rounded)
-                .border_color(Color::Red)
+                .border_color(Color::ERROR)
                 .title("Error")
-                .title_color(Color::Red)
+                .title_color(Color::ERROR)
                 .content(format!("Failed: {}\n\n{}", task_name, error_msg))
                 .footer_hint("esc", "close");
 
@@ -695,7 +695,7 @@ impl Model for TaskProgressView {
         // Footer (hidden hints when modal is showing)
         let has_modal = self.phase == Phase::Confirming || self.error_modal.is_some();
         if has_modal {
-            let dim = Color::BrightBlack.to_ansi_fg();
+            let dim = Color::MUTED.to_ansi_fg();
             let reset = "\x1b[0m";
             output.push_str(&format!(
                 "{}{}{}\r\n{}",
@@ -15,10 +15,12 @@
 
 use std::process::Command;
 
+use super::WHEEL_LINES;
 use crate::{
+    layout::{HitArea, Rect},
     runtime::{Cmd, Model, accessible::Accessible},
-    style::Color,
-    terminal::{Event, KeyCode, KeyModifiers},
+    style::{Color, width},
+    terminal::{Event, KeyCode, KeyModifiers, MouseButton, MouseEvent, MouseEventKind},
 };
 
 /// Message type for text area.
@@ -48,6 +50,12 @@ pub enum TextAreaMsg {
     CursorStart,
     /// Move cursor to end of text.
     CursorEnd,
+    /// Move cursor to a position, clamped to the text.
+    CursorTo(CursorPos),
+    /// Scroll the view up by lines, keeping the cursor on screen.
+    ScrollUp(usize),
+    /// Scroll the view down by lines, keeping the cursor on screen.
+    ScrollDown(usize),
     /// Delete word before cursor.
     DeleteWord,
     /// Delete entire line.
@@ -110,6 +118,8 @@ pub struct TextArea {
     editor: Option<String>,
     /// File extension for temp file when using external editor.
     editor_extension: String,
+    /// Where the text area was last drawn.
+    area: HitArea,
 }
 
 impl Default for TextArea {
@@ -129,10 +139,10 @@ impl TextArea {
             focused: true,
             width: 80,
             height: 10,
-            cursor_color: Color::Cyan,
+            cursor_color: Color::PRIMARY,
             text_color: Color::Default,
-            placeholder_color: Color::BrightBlack,
-            line_number_color: Color::BrightBlack,
+            placeholder_color: Color::MUTED,
+            line_number_color: Color::MUTED,
             submitted: false,
             cancelled: false,
             show_line_numbers: false,
@@ -140,6 +150,7 @@ impl TextArea {
             validation_error: None,
             editor: None,
             editor_extension: "txt".to_string(),
+            area: HitArea::new(),
         }
     }
 
@@ -266,6 +277,18 @@ impl TextArea {
         self.focused = focused;
     }
 
+    /// Record where the text area is drawn, for mouse hit-testing.
+    ///
+    /// [`Model::view_in`] records this automatically.
+    pub fn set_area(&mut self, area: Rect) {
+        self.area.set(area);
+    }
+
+    /// Where the text area was last drawn.
+    pub fn area(&self) -> Option<Rect> {
+        self.area.get()
+    }
+
     /// Set a validation error.
     pub fn set_error(&mut self, error: impl Into<String>) {
         self.validation_error = Some(error.into());
@@ -499,6 +522,70 @@ impl TextArea {
         self.validation_error = None;
     }
 
+    /// Move the cursor to `pos`, clamped to the text.
+    fn cursor_to(&mut self, pos: CursorPos) {
+        self.cursor.row = pos.row.min(self.lines.len().saturating_sub(1));
+        self.cursor.col = pos.col;
+        self.clamp_cursor_col();
+        self.ensure_cursor_visible();
+    }
+
+    /// Scroll the view up.
+    fn scroll_up(&mut self, lines: usize) {
+        self.scroll_offset = self.scroll_offset.saturating_sub(lines);
+        self.keep_cursor_on_screen();
+    }
+
+    /// Scroll the view down.
+    fn scroll_down(&mut self, lines: usize) {
+        let max_offset = self.lines.len().saturating_sub(self.height);
+        self.scroll_offset = (self.scroll_offset + lines).min(max_offset);
+        self.keep_cursor_on_screen();
+    }
+
+    /// Drag the cursor along after the view scrolled.
+    fn keep_cursor_on_screen(&mut self) {
+        let last_visible = self.scroll_offset + self.height - 1;
+        self.cursor.row = self.cursor.row.clamp(self.scroll_offset, last_visible);
+        self.cursor.row = self.cursor.row.min(self.lines.len().saturating_sub(1));
+        self.clamp_cursor_col();
+    }
+
+    /// Width of the line number gutter.
+    fn gutter_width(&self) -> usize {
+        if self.show_line_numbers { self.lines.len().to_string().len().max(2) + 3 } else { 0 }
+    }
+
+    /// Text position drawn at (`x`, `y`), or `None` outside the lines.
+    fn pos_at(&self, x: u16, y: u16) -> Option<CursorPos> {
+        let top = usize::from(self.scroll_offset > 0);
+        let visible = self.height.min(self.lines.len().saturating_sub(self.scroll_offset));
+        let view_row = (y as usize).checked_sub(top).filter(|row| *row < visible)?;
+        let row = self.scroll_offset + view_row;
+
+        let x = (x as usize).saturating_sub(self.gutter_width());
+        let mut left = 0;
+        let mut col = 0;
+        for c in self.lines[row].chars() {
+            left += width(c.encode_utf8(&mut [0; 4]));
+            if x < left {
+                break;
+            }
+            col += 1;
+        }
+        Some(CursorPos { row, col })
+    }
+
+    fn handle_mouse(&self, mouse: MouseEvent) -> Option<TextAreaMsg> {
+        let (x, y) = self.area.hit(mouse.column, mouse.row)?;
+        match mouse.kind {
+            MouseEventKind::Down(MouseButton::Left) => self.pos_at(x, y).map(TextAreaMsg::CursorTo),
+            MouseEventKind::ScrollUp => Some(TextAreaMsg::ScrollUp(WHEEL_LINES)),
+            MouseEventKind::ScrollDown => Some(TextAreaMsg::ScrollDown(WHEEL_LINES)),
+            _ => None,
+        }
+    }
+
     /// Page up.
     fn page_up(&mut self) {
         let page_size = self.height.saturating_sub(1);
@@ -594,7 +681,7 @@ impl TextArea {
         // Line number
         if self.show_line_numbers {
             let line_num = line_idx + 1;
-            let num_width = self.lines.len().to_string().len().max(2);
+            let num_width = self.gutter_width() - 3;
             output.push_str(&format!(
                 "{}{:>width$} │{} ",
                 self.line_number_color.to_ansi_fg(),
@@ -661,6 +748,9 @@ impl Model for TextArea {
             TextAreaMsg::CursorLineEnd => self.cursor_line_end(),
             TextAreaMsg::CursorStart => self.cursor_start(),
             TextAreaMsg::CursorEnd => self.cursor_end(),
+            TextAreaMsg::CursorTo(pos) => self.cursor_to(pos),
+            TextAreaMsg::ScrollUp(lines) => self.scroll_up(lines),
+            TextAreaMsg::ScrollDown(lines) => self.scroll_down(lines),
             TextAreaMsg::DeleteWord => self.delete_word(),
             TextAreaMsg::DeleteLine => self.delete_line(),
             TextAreaMsg::Clear => {
@@ -723,7 +813,7 @@ impl Model for TextArea {
         if visible_start > 0 {
             output.push_str(&format!(
                 "{}↑ {} more lines{}",
-                Color::BrightBlack.to_ansi_fg(),
+                Color::MUTED.to_ansi_fg(),
                 visible_start,
                 "\x1b[0m\n"
             ));
@@ -744,7 +834,7 @@ impl Model for TextArea {
         if remaining > 0 {
             output.push_str(&format!(
                 "\n{}↓ {} more lines{}",
-                Color::BrightBlack.to_ansi_fg(),
+                Color::MUTED.to_ansi_fg(),
                 remaining,
                 "\x1b[0m"
             ));
@@ -752,12 +842,17 @@ impl Model for TextArea {
 
         // Show validation error if present
         if let Some(ref error) = self.validation_error {
-            output.push_str(&format!("\n{}✗ {}{}", Color::Red.to_ansi_fg(), error, "\x1b[0m"));
+            output.push_str(&format!("\n{}✗ {}{}", Color::ERROR.to_ansi_fg(), error, "\x1b[0m"));
         }
 
         output
     }
 
+    fn view_in(&self, area: Rect) -> String {
+        self.area.set(area);
+        self.view()
+    }
+
     fn handle_event(&self, event: Event) -> Option<Self::Message> {
         if !self.focused {
             return None;
@@ -802,6 +897,7 @@ impl Model for TextArea {
                 }
             },
             Event::Paste(text) => Some(TextAreaMsg::Paste(text)),
+            Event::Mouse(mouse) => self.handle_mouse(mouse),
             _ => None,
         }
     }
@@ -1056,4 +1152,25 @@ mod tests {
         assert!(value.contains("First line"));
         assert!(value.contains("Second line"));
     }
+
+    #[test]
+    fn test_mouse() {
+        let mut textarea = TextArea::new()
+            .height(2)
+            .value((0..6).map(|i| format!("line {}", i)).collect::<Vec<_>>().join("\n"));
+        textarea.update(TextAreaMsg::CursorStart);
+        textarea.set_area(Rect::new(10, 0, 20, 4));
+        let mouse = |kind, column, row| Event::Mouse(MouseEvent::new(kind, column, row));
+
+        let msg = textarea.handle_event(mouse(MouseEventKind::ScrollDown, 12, 1)).unwrap();
+        textarea.update(msg);
+        assert_eq!(textarea.cursor_position().row, 3);
+
+        // Row 0 is the "↑ more lines" indicator.
+        let click = mouse(MouseEventKind::Down(MouseButton::Left), 13, 2);
+        let msg = textarea.handle_event(click).unwrap();
+        textarea.update(msg);
+        assert_eq!(textarea.cursor_position(), CursorPos { row: 4, col: 3 });
+        assert!(textarea.handle_event(mouse(MouseEventKind::ScrollDown, 0, 1)).is_none());
+    }
 }
@@ -13,12 +13,13 @@
 //! ```
 
 use crate::{
+    layout::{HitArea, Rect},
     runtime::{
         Cmd, Model,
         accessible::{Accessible, AccessibleInput},
     },
-    style::Color,
-    terminal::{Event, KeyCode, KeyModifiers},
+    style::{Color, width},
+    terminal::{Event, KeyCode, KeyModifiers, MouseButton, MouseEventKind},
 };
 
 /// Message type for text input.
@@ -38,6 +39,8 @@ pub enum TextInputMsg {
     CursorStart,
     /// Move cursor to end.
     CursorEnd,
+    /// Move cursor before the character at this index.
+    CursorTo(usize),
     /// Delete word before cursor.
     DeleteWord,
     /// Clear all text.
@@ -67,8 +70,11 @@ pub struct TextInput {
     cursor_color: Color,
     text_color: Color,
     placeholder_color: Color,
+    error_color: Color,
     submitted: bool,
     validation_error: Option<String>,
+    /// Where the input was last drawn.
+    area: HitArea,
 }
 
 impl Default for TextInput {
@@ -80,11 +86,13 @@ impl Default for TextInput {
             prompt: String::new(),
             focused: true,
             hidden: false,
-            cursor_color: Color::Cyan,
+            cursor_color: Color::PRIMARY,
             text_color: Color::Default,
-            placeholder_color: Color::BrightBlack,
+            placeholder_color: Color::MUTED,
+            error_color: Color::ERROR,
             submitted: false,
             validation_error: None,
+            area: HitArea::new(),
         }
     }
 }
@@ -148,6 +156,12 @@ impl TextInput {
         self
     }
 
+    /// Set the validation error color.
+    pub fn error_color(mut self, color: Color) -> Self {
+        self.error_color = color;
+        self
+    }
+
     /// Get the current value.
     pub fn get_value(&self) -> &str {
         &self.value
@@ -158,6 +172,16 @@ impl TextInput {
         self.submitted
     }
 
+    /// Clear the submitted state so the value can be edited again.
+    pub fn reopen(&mut self) {
+        self.submitted = false;
+    }
+
+    /// Check if the input masks its value.
+    pub fn is_hidden(&self) -> bool {
+        self.hidden
+    }
+
     /// Check if the input is focused.
     pub fn is_focused(&self) -> bool {
         self.focused
@@ -168,6 +192,31 @@ impl TextInput {
         self.focused = focused;
     }
 
+    /// Record where the input is drawn, for mouse hit-testing.
+    ///
+    /// [`Model::view_in`] records this automatically.
+    pub fn set_area(&mut self, area: Rect) {
+        self.area.set(area);
+    }
+
+    /// Where the input was last drawn.
+    pub fn area(&self) -> Option<Rect> {
+        self.area.get()
+    }
+
+    /// Character index drawn at column `x`, counting the prompt.
+    fn char_at(&self, x: u16) -> usize {
+        let mut left = width(&self.prompt);
+        for (i, c) in self.value.chars().enumerate() {
+            let right = left + if self.hidden { 1 } else { width(c.encode_utf8(&mut [0; 4])) };
+            if (x as usize) < right {
+                return i;
+            }
+            left = right;
+        }
+        self.value.chars().count()
+    }
+
     /// Set a validation error.
     pub fn set_error(&mut self, error: impl Into<String>) {
         self.validation_error = Some(error.into());
@@ -232,6 +281,11 @@ impl TextInput {
         self.cursor = self.value.len();
     }
 
+    /// Move cursor before the character at `index`.
+    fn cursor_to(&mut self, index: usize) {
+        self.cursor = self.value.char_indices().nth(index).map_or(self.value.len(), |(i, _)| i);
+    }
+
     /// Delete word before cursor.
     fn delete_word(&mut self) {
         // Find start of current/previous word
@@ -262,6 +316,7 @@ impl Model for TextInput {
             TextInputMsg::CursorRight => self.cursor_right(),
             TextInputMsg::CursorStart => self.cursor_start(),
             TextInputMsg::CursorEnd => self.cursor_end(),
+            TextInputMsg::CursorTo(index) => self.cursor_to(index),
             TextInputMsg::DeleteWord => self.delete_word(),
             TextInputMsg::Clear => {
                 self.value.clear();
@@ -354,12 +409,22 @@ impl Model for TextInput {
 
         // Show validation error if present
         if let Some(ref error) = self.validation_error {
-            output.push_str(&format!("\n{}✗ {}{}", Color::Red.to_ansi_fg(), error, "\x1b[0m"));
+            output.push_str(&format!(
+                "\n{}✗ {}{}",
+                self.error_color.to_ansi_fg(),
+                error,
+                "\x1b[0m"
+            ));
         }
 
         output
     }
 
+    fn view_in(&self, area: Rect) -> String {
+        self.area.set(area);
+        self.view()
+    }
+
     fn handle_event(&self, event: Event) -> Option<Self::Message> {
         if !self.focused {
             return None;
@@ -390,6 +455,12 @@ impl Model for TextInput {
                 _ => None,
             },
             Event::Paste(text) => Some(TextInputMsg::Paste(text)),
+            Event::Mouse(mouse) if mouse.kind == MouseEventKind::Down(MouseButton::Left) => {
+                match self.area.hit(mouse.column, mouse.row)? {
+                    (x, 0) => Some(TextInputMsg::CursorTo(self.char_at(x))),
+                    _ => None,
+                }
+            },
             _ => None,
         }
     }
@@ -440,6 +511,7 @@ impl Accessible for TextInput {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::terminal::MouseEvent;
 
     #[test]
     fn test_text_input_creation() {
@@ -516,4 +588,23 @@ mod tests {
         let view = input.view();
         assert!(view.contains("•")); // Should show bullets, not the actual text
     }
+
+    #[test]
+    fn test_click_places_cursor() {
+        let mut input = TextInput::new().prompt("> ").value("héllo");
+        input.set_area(Rect::new(0, 0, 80, 1));
+        let click = |column| {
+            Event::Mouse(MouseEvent::new(MouseEventKind::Down(MouseButton::Left), column, 0))
+        };
+
+        let msg = input.handle_event(click(4)).unwrap();
+        input.update(msg);
+        input.update(TextInputMsg::InsertChar('x'));
+        assert_eq!(input.get_value(), "héxllo");
+
+        let msg = input.handle_event(click(40)).unwrap();
+        input.update(msg);
+        input.update(TextInputMsg::InsertChar('!'));
+        assert_eq!(input.get_value(), "héxllo!");
+    }
 }
@@ -61,7 +61,7 @@ impl Default for TitleBar {
             width: 80,
             title_color: Color::Default,
             subtitle_color: Color::Default,
-            separator_color: Color::BrightBlack,
+            separator_color: Color::BORDER,
         }
     }
 }
@@ -1,6 +1,8 @@
 //! Viewport component for scrollable content.
 //!
-//! A viewport displays content that can be scrolled vertically.
+//! A viewport displays content that can be scrolled vertically and
+//! horizontally, keeping ANSI styles intact. Press `/` to search: matches are
+//! highlighted as you type, and `n`/`N` jump between them.
 //!
 //! # Example
 //!
@@ -8,12 +10,21 @@
 //! use teapot::components::Viewport;
 //!
 //! let viewport = Viewport::new(80, 20)
-//!     .content("Long content here...");
+//!     .content("Long content here...")
+//!     .line_numbers(true)
+//!     .follow(true);
 //! ```
 
+use std::{borrow::Cow, collections::VecDeque, ops::Range};
+
+use unicode_width::UnicodeWidthChar;
+
+use super::{H_SCROLL_STEP, WHEEL_LINES};
 use crate::{
+    layout::{HitArea, Rect},
     runtime::{Cmd, Model},
-    terminal::{Event, KeyCode, KeyModifiers},
+    style::{Color, slice, strip_ansi, truncate, width},
+    terminal::{Event, KeyCode, KeyModifiers, MouseEventKind},
 };
 
 /// Message type for viewport.
@@ -31,9 +42,29 @@ pub enum ViewportMsg {
     PageUp,
     /// Page down.
     PageDown,
+    /// Scroll left by columns.
+    ScrollLeft(usize),
+    /// Scroll right by columns.
+    ScrollRight(usize),
     /// Set content.
     SetContent(String),
-    /// Resize viewport.
+    /// Add lines to the end of the content.
+    Append(String),
+    /// Open the search bar.
+    StartSearch,
+    /// Insert character into the search query.
+    InsertSearchChar(char),
+    /// Delete character from the search query.
+    DeleteSearchChar,
+    /// Close the search bar, keeping the matches highlighted.
+    ConfirmSearch,
+    /// Close the search bar and clear the query.
+    ClearSearch,
+    /// Jump to the next match.
+    NextMatch,
+    /// Jump to the previous match.
+    PrevMatch,
+    /// Resize viewport. Only used before it is drawn into an area.
     Resize { width: usize, height: usize },
 }
 
@@ -41,12 +72,33 @@ pub enum ViewportMsg {
 #[derive(Debug, Clone)]
 #[must_use = "components do nothing unless used in a view or run with Program"]
 pub struct Viewport {
-    content: String,
-    lines: Vec<String>,
+    lines: VecDeque<String>,
     offset: usize,
+    /// Columns scrolled off the left edge.
+    h_offset: usize,
+    /// Display width of the widest line.
+    max_line_width: usize,
     width: usize,
     height: usize,
     focused: bool,
+    line_numbers: bool,
+    /// Stay at the bottom as content arrives while scrolled to the end.
+    follow: bool,
+    query: String,
+    /// Whether the search bar is open.
+    searching: bool,
+    /// Every match as a line index and the display columns it covers, in order.
+    matches: Vec<(usize, Range<usize>)>,
+    current_match: usize,
+    line_number_color: Color,
+    search_color: Color,
+    match_color: Color,
+    current_match_color: Color,
+    /// Where the viewport was last drawn.
+    area: HitArea,
+    /// Rows the offset was last clamped to, so following survives the
+    /// drawn area changing size.
+    rows: usize,
 }
 
 impl Default for Viewport {
@@ -58,7 +110,27 @@ impl Default for Viewport {
 impl Viewport {
     /// Create a new viewport with dimensions.
     pub fn new(width: usize, height: usize) -> Self {
-        Self { content: String::new(), lines: Vec::new(), offset: 0, width, height, focused: true }
+        Self {
+            lines: VecDeque::new(),
+            offset: 0,
+            h_offset: 0,
+            max_line_width: 0,
+            width,
+            height,
+            focused: true,
+            line_numbers: false,
+            follow: false,
+            query: String::new(),
+            searching: false,
+            matches: Vec::new(),
+            current_match: 0,
+            line_number_color: Color::MUTED,
+            search_color: Color::ACCENT,
+            match_color: Color::WARNING,
+            current_match_color: Color::ACCENT,
+            area: HitArea::new(),
+            rows: height,
+        }
     }
 
     /// Set the content.
@@ -67,12 +139,180 @@ impl Viewport {
         self
     }
 
+    /// Show line numbers in a gutter on the left.
+    pub fn line_numbers(mut self, show: bool) -> Self {
+        self.line_numbers = show;
+        self
+    }
+
+    /// Keep scrolling to the bottom as content is set or appended, like
+    /// `tail -f`, as long as the view is already at the bottom.
+    pub fn follow(mut self, follow: bool) -> Self {
+        self.follow = follow;
+        self
+    }
+
+    /// Set the line number color.
+    pub fn line_number_color(mut self, color: Color) -> Self {
+        self.line_number_color = color;
+        self
+    }
+
+    /// Set the search bar color.
+    pub fn search_color(mut self, color: Color) -> Self {
+        self.search_color = color;
+        self
+    }
+
+    /// Set the background color of search matches.
+    pub fn match_color(mut self, color: Color) -> Self {
+        self.match_color = color;
+        self
+    }
+
+    /// Set the background color of the current search match.
+    pub fn current_match_color(mut self, color: Color) -> Self {
+        self.current_match_color = color;
+        self
+    }
+
     /// Set content and recompute lines.
     pub fn set_content(&mut self, content: String) {
-        self.content = content;
-        self.lines = self.content.lines().map(String::from).collect();
-        // Ensure offset is valid
+        self.sync_rows();
+        let stick = self.is_following();
+        self.lines = content.lines().map(String::from).collect();
+        self.max_line_width = self.lines.iter().map(|line| width(line)).max().unwrap_or(0);
+        self.matches.clear();
+        self.find_matches(0);
+        self.current_match = self.current_match.min(self.matches.len().saturating_sub(1));
+        if stick {
+            self.scroll_to_bottom();
+        }
+        // Ensure offsets are valid
+        self.offset = self.offset.min(self.max_offset());
+        self.h_offset = self.h_offset.min(self.max_h_offset());
+    }
+
+    /// Add lines to the end of the content.
+    ///
+    /// An empty string adds one empty line.
+    pub fn append(&mut self, text: &str) {
+        self.sync_rows();
+        let stick = self.is_following();
+        let first = self.lines.len();
+        let lines = if text.is_empty() { vec![""] } else { text.lines().collect() };
+        for line in lines {
+            self.max_line_width = self.max_line_width.max(width(line));
+            self.lines.push_back(line.to_string());
+        }
+        self.find_matches(first);
+        if stick {
+            self.scroll_to_bottom();
+        }
+    }
+
+    /// Remove the first `count` lines, keeping the same lines in view.
+    pub fn remove_front(&mut self, count: usize) {
+        self.sync_rows();
+        let count = count.min(self.lines.len());
+        let removed_widest =
+            self.lines.drain(..count).any(|line| width(&line) >= self.max_line_width);
+        if removed_widest {
+            self.max_line_width = self.lines.iter().map(|line| width(line)).max().unwrap_or(0);
+            self.h_offset = self.h_offset.min(self.max_h_offset());
+        }
+        self.offset = self.offset.saturating_sub(count).min(self.max_offset());
+
+        let gone = self.matches.partition_point(|(line, _)| *line < count);
+        self.matches.drain(..gone);
+        for (line, _) in &mut self.matches {
+            *line -= count;
+        }
+        self.current_match = self.current_match.saturating_sub(gone);
+    }
+
+    /// Search for `query`, highlighting every match and jumping to the first
+    /// one at or below the top of the view.
+    ///
+    /// Matching is smart-case: case is ignored unless the query contains an
+    /// uppercase letter. An empty query clears the search.
+    pub fn set_search(&mut self, query: impl Into<String>) {
+        self.sync_rows();
+        self.query = query.into();
+        self.matches.clear();
+        self.find_matches(0);
+        self.current_match = self.matches.partition_point(|(line, _)| *line < self.offset);
+        if self.current_match == self.matches.len() {
+            self.current_match = 0;
+        }
+        self.scroll_to_match();
+    }
+
+    /// Get the search query.
+    pub fn search_query(&self) -> &str {
+        &self.query
+    }
+
+    /// Get the number of search matches.
+    pub fn match_count(&self) -> usize {
+        self.matches.len()
+    }
+
+    /// Get the line of the current search match.
+    pub fn current_match_line(&self) -> Option<usize> {
+        self.matches.get(self.current_match).map(|(line, _)| *line)
+    }
+
+    /// Jump to the next search match, wrapping around.
+    pub fn next_match(&mut self) {
+        self.sync_rows();
+        if !self.matches.is_empty() {
+            self.current_match = (self.current_match + 1) % self.matches.len();
+            self.scroll_to_match();
+        }
+    }
+
+    /// Jump to the previous search match, wrapping around.
+    pub fn prev_match(&mut self) {
+        self.sync_rows();
+        if !self.matches.is_empty() {
+            self.current_match =
+                self.current_match.checked_sub(1).unwrap_or(self.matches.len() - 1);
+            self.scroll_to_match();
+        }
+    }
+
+    /// Check if the search bar is open.
+    pub fn is_searching(&self) -> bool {
+        self.searching
+    }
+
+    /// Check if new content will keep the view at the bottom.
+    pub fn is_following(&self) -> bool {
+        self.follow && self.at_bottom()
+    }
+
+    /// Set follow mode in place.
+    pub fn set_follow(&mut self, follow: bool) {
+        self.follow = follow;
+    }
+
+    /// Set the size in place.
+    pub fn set_size(&mut self, width: usize, height: usize) {
+        self.width = width;
+        self.height = height;
+        self.sync_rows();
         self.offset = self.offset.min(self.max_offset());
+        self.h_offset = self.h_offset.min(self.max_h_offset());
+    }
+
+    /// Render Markdown at the viewport's width and use it as the content.
+    ///
+    /// Requires the `markdown` feature.
+    #[cfg(feature = "markdown")]
+    pub fn set_markdown(&mut self, markdown: &str) {
+        let rendered = super::Markdown::new().width(self.width).render(markdown);
+        self.set_content(rendered);
     }
 
     /// Get the current scroll offset.
@@ -80,6 +320,11 @@ impl Viewport {
         self.offset
     }
 
+    /// Get the number of columns scrolled off the left edge.
+    pub fn h_offset(&self) -> usize {
+        self.h_offset
+    }
+
     /// Get total number of lines.
     pub fn total_lines(&self) -> usize {
         self.lines.len()
@@ -87,7 +332,7 @@ impl Viewport {
 
     /// Get maximum scroll offset.
     pub fn max_offset(&self) -> usize {
-        self.lines.len().saturating_sub(self.height)
+        self.lines.len().saturating_sub(self.rows)
     }
 
     /// Check if at top.
@@ -103,7 +348,7 @@ impl Viewport {
     /// Get visible line range.
     pub fn visible_range(&self) -> (usize, usize) {
         let start = self.offset;
-        let end = (self.offset + self.height).min(self.lines.len());
+        let end = (self.offset + self.rows).min(self.lines.len());
         (start, end)
     }
 
@@ -117,6 +362,19 @@ impl Viewport {
         self.offset = (self.offset + lines).min(self.max_offset());
     }
 
+    /// Get the maximum horizontal scroll offset.
+    fn max_h_offset(&self) -> usize {
+        self.max_line_width.saturating_sub(self.page_width().saturating_sub(self.gutter_width()))
+    }
+
+    fn scroll_left(&mut self, columns: usize) {
+        self.h_offset = self.h_offset.saturating_sub(columns);
+    }
+
+    fn scroll_right(&mut self, columns: usize) {
+        self.h_offset = (self.h_offset + columns).min(self.max_h_offset());
+    }
+
     /// Scroll to top.
     fn scroll_to_top(&mut self) {
         self.offset = 0;
@@ -129,18 +387,184 @@ impl Viewport {
 
     /// Page up.
     fn page_up(&mut self) {
-        self.scroll_up(self.height.saturating_sub(1));
+        self.scroll_up(self.rows.saturating_sub(1));
     }
 
     /// Page down.
     fn page_down(&mut self) {
-        self.scroll_down(self.height.saturating_sub(1));
+        self.scroll_down(self.rows.saturating_sub(1));
+    }
+
+    /// Record the matches of the query in lines `first..`.
+    fn find_matches(&mut self, first: usize) {
+        if self.query.is_empty() {
+            return;
+        }
+        // Smart case: an uppercase letter makes the search case-sensitive.
+        let fold = !self.query.chars().any(char::is_uppercase);
+        let norm = |c: char| if fold { c.to_lowercase().next().unwrap_or(c) } else { c };
+        let query: Vec<char> = self.query.chars().map(norm).collect();
+
+        for (index, line) in self.lines.iter().enumerate().skip(first) {
+            let text: Vec<char> = strip_ansi(line).chars().map(norm).collect();
+            // Display column where each character starts.
+            let columns: Vec<usize> = std::iter::once(0)
+                .chain(text.iter().scan(0, |column, c| {
+                    *column += c.width().unwrap_or(0);
+                    Some(*column)
+                }))
+                .collect();
+            let mut start = 0;
+            while start + query.len() <= text.len() {
+                if text[start..start + query.len()] == query[..] {
+                    self.matches.push((index, columns[start]..columns[start + query.len()]));
+                    start += query.len();
+                } else {
+                    start += 1;
+                }
+            }
+        }
+    }
+
+    /// Scroll the current match into view.
+    fn scroll_to_match(&mut self) {
+        let Some((line, columns)) = self.matches.get(self.current_match).cloned() else {
+            return;
+        };
+        // The search bar covers the last line while open.
+        let height = self.rows.saturating_sub(usize::from(self.searching)).max(1);
+        if line < self.offset {
+            self.offset = line;
+        } else if line >= self.offset + height {
+            self.offset = line + 1 - height;
+        }
+        let text_width = self.page_width().saturating_sub(self.gutter_width()).max(1);
+        if columns.start < self.h_offset {
+            self.h_offset = columns.start;
+        } else if columns.end > self.h_offset + text_width {
+            self.h_offset = (columns.end - text_width).min(columns.start);
+        }
+    }
+
+    /// Size of the area last drawn into, or the configured size before that.
+    fn page_width(&self) -> usize {
+        self.area.get().map_or(self.width, |area| area.width as usize)
+    }
+
+    fn page_height(&self) -> usize {
+        self.area.get().map_or(self.height, |area| area.height as usize)
+    }
+
+    /// Clamp the offset to the drawn height, staying at the bottom if
+    /// following.
+    fn sync_rows(&mut self) {
+        let rows = self.page_height();
+        if rows == self.rows {
+            return;
+        }
+        let stick = self.is_following();
+        self.rows = rows;
+        self.offset = if stick { self.max_offset() } else { self.offset.min(self.max_offset()) };
+        self.h_offset = self.h_offset.min(self.max_h_offset());
+    }
+
+    /// Width of the line number gutter, including the space after it.
+    fn gutter_width(&self) -> usize {
+        if self.line_numbers { self.lines.len().max(1).to_string().len() + 1 } else { 0 }
+    }
+
+    /// A line with its search matches highlighted.
+    fn highlighted(&self, index: usize) -> Cow<'_, str> {
+        let line = &self.lines[index];
+        let first = self.matches.partition_point(|(l, _)| *l < index);
+        let hits = self.matches[first..].iter().take_while(|(l, _)| *l == index);
+
+        let mut out = String::new();
+        let mut column = 0;
+        for (i, (_, range)) in hits.enumerate() {
+            let color = if first + i == self.current_match {
+                &self.current_match_color
+            } else {
+                &self.match_color
+            };
+            out.push_str(&slice(line, column, range.start));
+            out.push_str(&color.to_ansi_bg());
+            out.push_str(&strip_ansi(&slice(line, range.start, range.end)));
+            out.push_str("\x1b[0m");
+            column = range.end;
+        }
+        if column == 0 {
+            return Cow::Borrowed(line);
+        }
+        out.push_str(&slice(line, column, usize::MAX));
+        Cow::Owned(out)
     }
 
     /// Set focus state.
     pub fn set_focused(&mut self, focused: bool) {
         self.focused = focused;
     }
+
+    /// Record where the viewport is drawn, for mouse hit-testing.
+    ///
+    /// [`Model::view_in`] records this automatically.
+    pub fn set_area(&mut self, area: Rect) {
+        self.area.set(area);
+    }
+
+    /// Where the viewport was last drawn.
+    pub fn area(&self) -> Option<Rect> {
+        self.area.get()
+    }
+
+    /// Render the visible lines for a `width` x `height` area.
+    fn render_sized(&self, width: usize, height: usize) -> String {
+        // The search bar takes the last line while open.
+        let rows = height.saturating_sub(usize::from(self.searching));
+        // Show the tail if following, even before the offset is synced to a
+        // new area.
+        let last = self.lines.len().saturating_sub(rows);
+        let start = if self.is_following() { last } else { self.offset.min(last) };
+        let end = (start + rows).min(self.lines.len());
+        let gutter = self.gutter_width();
+        let text_width = width.saturating_sub(gutter);
+
+        let mut lines: Vec<String> = (start..end)
+            .map(|index| {
+                let line = self.highlighted(index);
+                let line = match self.h_offset {
+                    0 => truncate(&line, text_width),
+                    h_offset => truncate(&slice(&line, h_offset, usize::MAX), text_width),
+                };
+                if gutter == 0 {
+                    return line;
+                }
+                format!(
+                    "{}{:>w$}\x1b[0m {}",
+                    self.line_number_color.to_ansi_fg(),
+                    index + 1,
+                    line,
+                    w = gutter - 1
+                )
+            })
+            .collect();
+
+        if self.searching {
+            let count = match self.matches.len() {
+                _ if self.query.is_empty() => String::new(),
+                0 => " no matches".to_string(),
+                n => format!(" {}/{}", self.current_match + 1, n),
+            };
+            lines.push(format!(
+                "{}/{}{}{}\x1b[0m",
+                self.search_color.to_ansi_fg(),
+                self.query,
+                Color::MUTED.to_ansi_fg(),
+                count
+            ));
+        }
+        lines.join("\n")
+    }
 }
 
 impl Model for Viewport {
@@ -151,6 +575,7 @@ impl Model for Viewport {
     }
 
     fn update(&mut self, msg: Self::Message) -> Option<Cmd<Self::Message>> {
+        self.sync_rows();
         match msg {
             ViewportMsg::ScrollUp(lines) => self.scroll_up(lines),
             ViewportMsg::ScrollDown(lines) => self.scroll_down(lines),
@@ -158,36 +583,40 @@ impl Model for Viewport {
             ViewportMsg::ScrollToBottom => self.scroll_to_bottom(),
             ViewportMsg::PageUp => self.page_up(),
             ViewportMsg::PageDown => self.page_down(),
+            ViewportMsg::ScrollLeft(columns) => self.scroll_left(columns),
+            ViewportMsg::ScrollRight(columns) => self.scroll_right(columns),
             ViewportMsg::SetContent(content) => self.set_content(content),
-            ViewportMsg::Resize { width, height } => {
-                self.width = width;
-                self.height = height;
-                self.offset = self.offset.min(self.max_offset());
+            ViewportMsg::Append(text) => self.append(&text),
+            ViewportMsg::StartSearch => self.searching = true,
+            ViewportMsg::InsertSearchChar(c) => {
+                let mut query = std::mem::take(&mut self.query);
+                query.push(c);
+                self.set_search(query);
+            },
+            ViewportMsg::DeleteSearchChar => {
+                let mut query = std::mem::take(&mut self.query);
+                query.pop();
+                self.set_search(query);
+            },
+            ViewportMsg::ConfirmSearch => self.searching = false,
+            ViewportMsg::ClearSearch => {
+                self.searching = false;
+                self.set_search(String::new());
             },
+            ViewportMsg::NextMatch => self.next_match(),
+            ViewportMsg::PrevMatch => self.prev_match(),
+            ViewportMsg::Resize { width, height } => self.set_size(width, height),
         }
         None
     }
 
     fn view(&self) -> String {
-        let (start, end) = self.visible_range();
-        let mut output = String::new();
-
-        for (i, line) in self.lines[start..end].iter().enumerate() {
-            // Truncate line to width
-            let display = if line.len() > self.width {
-                format!("{}...", &line[..self.width.saturating_sub(3)])
-            } else {
-                line.clone()
-            };
-
-            output.push_str(&display);
-
-            if start + i < end - 1 {
-                output.push('\n');
-            }
-        }
+        self.render_sized(self.width, self.height)
+    }
 
-        output
+    fn view_in(&self, area: Rect) -> String {
+        self.area.set(area);
+        self.render_sized(area.width as usize, area.height as usize)
     }
 
     fn handle_event(&self, event: Event) -> Option<Self::Message> {
@@ -196,7 +625,24 @@ impl Model for Viewport {
         }
 
         match event {
+            Event::Key(key) if self.searching => match key.code {
+                KeyCode::Enter => Some(ViewportMsg::ConfirmSearch),
+                KeyCode::Esc => Some(ViewportMsg::ClearSearch),
+                KeyCode::Backspace => Some(ViewportMsg::DeleteSearchChar),
+                KeyCode::Char(c) if !key.modifiers.contains(KeyModifiers::CONTROL) => {
+                    Some(ViewportMsg::InsertSearchChar(c))
+                },
+                _ => None,
+            },
             Event::Key(key) => match key.code {
+                KeyCode::Left | KeyCode::Char('h') => Some(ViewportMsg::ScrollLeft(H_SCROLL_STEP)),
+                KeyCode::Right | KeyCode::Char('l') => {
+                    Some(ViewportMsg::ScrollRight(H_SCROLL_STEP))
+                },
+                KeyCode::Char('/') => Some(ViewportMsg::StartSearch),
+                KeyCode::Char('n') => Some(ViewportMsg::NextMatch),
+                KeyCode::Char('N') => Some(ViewportMsg::PrevMatch),
+                KeyCode::Esc if !self.query.is_empty() => Some(ViewportMsg::ClearSearch),
                 KeyCode::Up | KeyCode::Char('k') => Some(ViewportMsg::ScrollUp(1)),
                 KeyCode::Down | KeyCode::Char('j') => Some(ViewportMsg::ScrollDown(1)),
                 KeyCode::PageUp => Some(ViewportMsg::PageUp),
@@ -213,9 +659,20 @@ impl Model for Viewport {
                 },
                 _ => None,
             },
-            Event::Resize { width, height } => {
+            // Once drawn with `view_in`, the area decides the size.
+            Event::Resize { width, height } if self.area.get().is_none() => {
                 Some(ViewportMsg::Resize { width: width as usize, height: height as usize })
             },
+            Event::Mouse(mouse) => {
+                self.area.hit(mouse.column, mouse.row)?;
+                match mouse.kind {
+                    MouseEventKind::ScrollUp => Some(ViewportMsg::ScrollUp(WHEEL_LINES)),
+                    MouseEventKind::ScrollDown => Some(ViewportMsg::ScrollDown(WHEEL_LINES)),
+                    MouseEventKind::ScrollLeft => Some(ViewportMsg::ScrollLeft(H_SCROLL_STEP)),
+                    MouseEventKind::ScrollRight => Some(ViewportMsg::ScrollRight(H_SCROLL_STEP)),
+                    _ => None,
+                }
+            },
             _ => None,
         }
     }
@@ -252,4 +709,108 @@ mod tests {
         viewport.page_up();
         assert_eq!(viewport.offset(), 0);
     }
+
+    #[test]
+    fn test_view_in_area() {
+        let mut viewport = Viewport::new(80, 2).content("first line\nsecond\nthird\nfourth");
+        viewport.scroll_down(2);
+        assert_eq!(viewport.view(), "third\nfourth");
+
+        // A taller area shows more lines without scrolling past the end.
+        assert_eq!(viewport.view_in(Rect::from_size(5, 3)), "se...\nthird\nfo...");
+    }
+
+    #[test]
+    fn test_view_in_shorter_area() {
+        let content = (1..=100).map(|n| n.to_string()).collect::<Vec<_>>().join("\n");
+        let mut viewport = Viewport::new(40, 24).follow(true).content(content);
+        let area = Rect::from_size(40, 5);
+        assert_eq!(viewport.view_in(area), "96\n97\n98\n99\n100");
+
+        viewport.update(ViewportMsg::Append("101".to_string()));
+        assert_eq!(viewport.view_in(area), "97\n98\n99\n100\n101");
+        assert_eq!(viewport.max_offset(), 96);
+
+        viewport.update(ViewportMsg::ScrollUp(1));
+        assert_eq!(viewport.view_in(area), "96\n97\n98\n99\n100");
+        viewport.update(ViewportMsg::ScrollToBottom);
+        assert!(viewport.is_following());
+        assert_eq!(viewport.visible_range(), (96, 101));
+
+        // The area decides the size, so terminal resizes are ignored.
+        assert!(viewport.handle_event(Event::Resize { width: 80, height: 50 }).is_none());
+    }
+
+    #[test]
+    fn test_horizontal_scroll() {
+        let mut viewport =
+            Viewport::new(6, 2).content("\x1b[31mhéllo wörld\x1b[0m\n日本語のテキスト");
+        assert_eq!(viewport.max_h_offset(), 10);
+        viewport.update(ViewportMsg::ScrollRight(2));
+        let view = viewport.view();
+        assert!(view.starts_with("\x1b[31m"));
+        assert_eq!(strip_ansi(&view), "llo...\n本 ...");
+
+        viewport.update(ViewportMsg::ScrollRight(100));
+        assert_eq!(viewport.h_offset(), 10);
+        viewport.update(ViewportMsg::ScrollLeft(100));
+        assert_eq!(viewport.h_offset(), 0);
+    }
+
+    #[test]
+    fn test_search() {
+        let content = (1..=20).map(|i| format!("line {i}")).collect::<Vec<_>>().join("\n");
+        let mut viewport = Viewport::new(20, 4).content(content);
+        let key =
+            |code| Event::Key(crate::terminal::KeyEvent { code, modifiers: KeyModifiers::NONE });
+
+        for code in [KeyCode::Char('/'), KeyCode::Char('1'), KeyCode::Char('5')] {
+            let msg = viewport.handle_event(key(code)).unwrap();
+            viewport.update(msg);
+        }
+        assert_eq!(viewport.match_count(), 1);
+        assert_eq!(viewport.current_match_line(), Some(14));
+        let view = viewport.view();
+        assert!(
+            view.contains(&format!("line {}15\x1b[0m", viewport.current_match_color.to_ansi_bg()))
+        );
+        assert!(strip_ansi(&view).ends_with("/15 1/1"));
+
+        let msg = viewport.handle_event(key(KeyCode::Enter)).unwrap();
+        viewport.update(msg);
+        assert!(!viewport.is_searching());
+
+        // Smart case, wrapping n/N.
+        viewport.set_search("LINE");
+        assert_eq!(viewport.match_count(), 0);
+        viewport.update(ViewportMsg::ScrollToTop);
+        viewport.set_search("line 1");
+        assert_eq!(viewport.match_count(), 11);
+        viewport.update(ViewportMsg::PrevMatch);
+        assert_eq!(viewport.current_match_line(), Some(18));
+        viewport.update(ViewportMsg::NextMatch);
+        assert_eq!(viewport.current_match_line(), Some(0));
+        assert_eq!(viewport.offset(), 0);
+
+        let msg = viewport.handle_event(key(KeyCode::Esc)).unwrap();
+        viewport.update(msg);
+        assert_eq!(viewport.match_count(), 0);
+    }
+
+    #[test]
+    fn test_line_numbers_and_follow() {
+        let mut viewport = Viewport::new(10, 2).content("a\nb\nc").line_numbers(true).follow(true);
+        viewport.update(ViewportMsg::ScrollToBottom);
+        viewport.update(ViewportMsg::Append((0..8).map(|i| format!("{i}\n")).collect()));
+        assert_eq!(viewport.offset(), 9);
+        assert_eq!(strip_ansi(&viewport.view()), "10 6\n11 7");
+
+        // Scrolling up stops following until the bottom is reached again.
+        viewport.update(ViewportMsg::ScrollUp(1));
+        viewport.update(ViewportMsg::Append("more".into()));
+        assert_eq!(viewport.offset(), 8);
+        viewport.update(ViewportMsg::ScrollToBottom);
+        viewport.update(ViewportMsg::SetContent("x\ny\nz".into()));
+        assert_eq!(viewport.offset(), 1);
+    }
 }
@@ -43,6 +43,24 @@ pub enum Error {
     /// An external process failed.
     #[error("external process failed: {0}")]
     ProcessFailed(String),
+
+    /// A declarative form spec could not be parsed or is invalid.
+    #[error("invalid form spec at '{path}': {message}")]
+    Spec {
+        /// Location of the problem within the spec (e.g. `groups[0].fields[1].options`).
+        path: String,
+        /// Description of the problem.
+        message: String,
+    },
+
+    /// A headless answers source could not be parsed.
+    #[error("invalid answers at '{path}': {message}")]
+    Answers {
+        /// Location of the problem within the answers (usually a field key).
+        path: String,
+        /// Description of the problem.
+        message: String,
+    },
 }
 
 /// A specialized Result type for Teapot operations.
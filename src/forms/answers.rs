@@ -0,0 +1,520 @@
+//! Headless form answering.
+//!
+//! [`Answers`] supplies field values without a terminal, so the same form can
+//! be driven interactively or from automation. Run it with
+//! [`Form::run_with_answers`](super::Form::run_with_answers).
+//!
+//! # Example
+//!
+//! ```
+//! use teapot::forms::{Answers, Field, Form, Group};
+//!
+//! let mut form = Form::new().group(
+//!     Group::new()
+//!         .field(Field::input().key("name").title("Name").required(true).build())
+//!         .field(Field::confirm().key("agree").title("Agree?").build()),
+//! );
+//!
+//! // Explicit values take precedence over `MYAPP_NAME` / `MYAPP_AGREE`.
+//! let answers = Answers::from_env("MYAPP").set("name", "teapot").set("agree", true);
+//! let results = form.run_with_answers(&answers).unwrap();
+//!
+//! assert_eq!(results.get_string("name"), Some("teapot"));
+//! assert_eq!(results.get_bool("agree"), Some(true));
+//! ```
+
+use std::collections::HashMap;
+
+use super::field::{Field, FieldInner};
+use crate::{
+    components::{
+        confirm::ConfirmMsg, multi_select::MultiSelectMsg, select::SelectMsg,
+        text_input::TextInputMsg,
+    },
+    error::{Error, Result},
+    runtime::Model,
+};
+
+/// A single answer for a form field.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(untagged))]
+pub enum Answer {
+    /// A boolean (confirm).
+    Bool(bool),
+    /// An integer, treated as text for input and select fields.
+    Int(i64),
+    /// Text (input, select, file picker). Multi-select fields split it on commas.
+    Text(String),
+    /// A list of option labels (multi-select).
+    List(Vec<String>),
+}
+
+impl From<bool> for Answer {
+    fn from(value: bool) -> Self {
+        Answer::Bool(value)
+    }
+}
+
+impl From<i64> for Answer {
+    fn from(value: i64) -> Self {
+        Answer::Int(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_string())
+    }
+}
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<Vec<String>> for Answer {
+    fn from(value: Vec<String>) -> Self {
+        Answer::List(value)
+    }
+}
+
+impl From<Vec<&str>> for Answer {
+    fn from(value: Vec<&str>) -> Self {
+        Answer::List(value.into_iter().map(String::from).collect())
+    }
+}
+
+/// A source of answers for headless form runs.
+///
+/// Values come from an explicit map, `PREFIX_KEY` environment variables, or a
+/// JSON/TOML answers file. Explicit values always win over the environment.
+#[derive(Debug, Clone, Default)]
+pub struct Answers {
+    values: HashMap<String, Answer>,
+    env_prefix: Option<String>,
+}
+
+impl Answers {
+    /// Create an empty answer set.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create answers from key/value pairs.
+    pub fn from_map<I, K, V>(values: I) -> Self
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: Into<String>,
+        V: Into<Answer>,
+    {
+        Self {
+            values: values.into_iter().map(|(k, v)| (k.into(), v.into())).collect(),
+            env_prefix: None,
+        }
+    }
+
+    /// Read answers from environment variables named `PREFIX_KEY`.
+    ///
+    /// The key is upper-cased and any character that isn't alphanumeric is
+    /// replaced with `_`, so the field `project-name` with prefix `MYAPP` is
+    /// read from `MYAPP_PROJECT_NAME`. Variables are looked up lazily when the
+    /// form runs.
+    pub fn from_env(prefix: impl Into<String>) -> Self {
+        Self { values: HashMap::new(), env_prefix: Some(prefix.into()) }
+    }
+
+    /// Parse answers from a JSON object keyed by field key.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Answers`] if the JSON is malformed or a value isn't a
+    /// string, boolean, integer or list of strings.
+    #[cfg(feature = "json")]
+    pub fn from_json(source: &str) -> Result<Self> {
+        let mut de = serde_json::Deserializer::from_str(source);
+        let values = serde_path_to_error::deserialize(&mut de).map_err(parse_error)?;
+        Ok(Self { values, env_prefix: None })
+    }
+
+    /// Parse answers from a TOML table keyed by field key.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Answers`] if the TOML is malformed or a value isn't a
+    /// string, boolean, integer or list of strings.
+    #[cfg(feature = "toml")]
+    pub fn from_toml(source: &str) -> Result<Self> {
+        let de = toml::de::Deserializer::parse(source)
+            .map_err(|err| answers_error("", err.to_string().trim_end()))?;
+        let values = serde_path_to_error::deserialize(de).map_err(parse_error)?;
+        Ok(Self { values, env_prefix: None })
+    }
+
+    /// Load answers from a file, choosing the format by extension.
+    ///
+    /// Supports `.json` and `.toml`, subject to the matching crate feature
+    /// being enabled.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Io`] if the file can't be read, or [`Error::Answers`]
+    /// if the format is unsupported or the contents are invalid.
+    #[cfg(feature = "serde")]
+    pub fn from_file(path: impl AsRef<std::path::Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let extension =
+            path.extension().and_then(|e| e.to_str()).unwrap_or_default().to_lowercase();
+
+        match extension.as_str() {
+            #[cfg(feature = "json")]
+            "json" => Self::from_json(&std::fs::read_to_string(path)?),
+            #[cfg(feature = "toml")]
+            "toml" => Self::from_toml(&std::fs::read_to_string(path)?),
+            _ => Err(answers_error(
+                "",
+                format!("unsupported answers format '{}' for {}", extension, path.display()),
+            )),
+        }
+    }
+
+    /// Set an answer, overriding any environment variable for the same key.
+    pub fn set(mut self, key: impl Into<String>, value: impl Into<Answer>) -> Self {
+        self.values.insert(key.into(), value.into());
+        self
+    }
+
+    /// Look up the answer for a field key.
+    pub fn get(&self, key: &str) -> Option<Answer> {
+        if let Some(answer) = self.values.get(key) {
+            return Some(answer.clone());
+        }
+        let prefix = self.env_prefix.as_deref()?;
+        std::env::var(env_var_name(prefix, key)).ok().map(Answer::Text)
+    }
+}
+
+/// Build the environment variable name for a field key.
+fn env_var_name(prefix: &str, key: &str) -> String {
+    let key: String = key
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c.to_ascii_uppercase() } else { '_' })
+        .collect();
+    if prefix.is_empty() { key } else { format!("{}_{}", prefix, key) }
+}
+
+#[cfg(feature = "serde")]
+fn answers_error(path: impl Into<String>, message: impl Into<String>) -> Error {
+    Error::Answers { path: path.into(), message: message.into() }
+}
+
+#[cfg(any(feature = "json", feature = "toml"))]
+pub(super) fn parse_error<E: std::fmt::Display>(err: serde_path_to_error::Error<E>) -> Error {
+    let path = err.path().to_string();
+    answers_error(path, err.into_inner().to_string())
+}
+
+fn invalid(key: &str, message: impl Into<String>) -> Error {
+    Error::Validation { field: key.to_string(), message: message.into() }
+}
+
+/// Resolve an answer against option labels, matching case-insensitively as a
+/// fallback.
+fn find_option(labels: &[&str], answer: &str) -> Option<usize> {
+    let answer = answer.trim();
+    labels
+        .iter()
+        .position(|label| *label == answer)
+        .or_else(|| labels.iter().position(|label| label.eq_ignore_ascii_case(answer)))
+}
+
+fn parse_bool(answer: &str) -> Option<bool> {
+    match answer.trim().to_lowercase().as_str() {
+        "y" | "yes" | "true" | "on" | "1" => Some(true),
+        "n" | "no" | "false" | "off" | "0" => Some(false),
+        _ => None,
+    }
+}
+
+impl Field {
+    /// Apply a headless answer to this field and submit it.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Validation`] if the answer has the wrong type for the
+    /// field or doesn't match its options or filters.
+    pub fn apply_answer(&mut self, answer: &Answer) -> Result<()> {
+        let key = self.key.as_str();
+        match (&mut self.inner, answer) {
+            (FieldInner::Input(input), Answer::Text(text)) => {
+                input.update(TextInputMsg::SetValue(text.clone()));
+            },
+            (FieldInner::Input(input), Answer::Int(n)) => {
+                input.update(TextInputMsg::SetValue(n.to_string()));
+            },
+            (FieldInner::Select(select), Answer::Text(_) | Answer::Int(_)) => {
+                let text = answer_text(answer);
+                let labels = select.labels();
+                let Some(index) = find_option(&labels, &text) else {
+                    let options = labels.join(", ");
+                    return Err(invalid(key, format!("'{}' is not one of: {}", text, options)));
+                };
+                select.set_cursor(index);
+            },
+            (FieldInner::MultiSelect(select), Answer::Text(_) | Answer::List(_)) => {
+                let wanted: Vec<String> = match answer {
+                    Answer::List(items) => items.clone(),
+                    _ => answer_text(answer)
+                        .split(',')
+                        .map(|s| s.trim().to_string())
+                        .filter(|s| !s.is_empty())
+                        .collect(),
+                };
+                let labels = select.labels();
+                let mut indices = Vec::new();
+                for item in &wanted {
+                    let Some(index) = find_option(&labels, item) else {
+                        let options = labels.join(", ");
+                        return Err(invalid(key, format!("'{}' is not one of: {}", item, options)));
+                    };
+                    if !indices.contains(&index) {
+                        indices.push(index);
+                    }
+                }
+                if let Some(max) = select.max_selections()
+                    && indices.len() > max
+                {
+                    return Err(invalid(key, format!("select at most {} options", max)));
+                }
+                select.update(MultiSelectMsg::DeselectAll);
+                for index in indices {
+                    select.toggle_at(index);
+                }
+            },
+            (FieldInner::Confirm(confirm), _) => {
+                let value = match answer {
+                    Answer::Bool(value) => Some(*value),
+                    Answer::Int(n) => match n {
+                        0 => Some(false),
+                        1 => Some(true),
+                        _ => None,
+                    },
+                    Answer::Text(text) => parse_bool(text),
+                    Answer::List(_) => None,
+                };
+                let Some(value) = value else {
+                    return Err(invalid(key, "expected yes or no"));
+                };
+                confirm.update(if value { ConfirmMsg::Yes } else { ConfirmMsg::No });
+            },
+            (FieldInner::FilePicker(picker), Answer::Text(path)) => {
+                picker.select_path(path).map_err(|message| invalid(key, message))?;
+            },
+            // Notes carry no value; ignore anything addressed to them.
+            (FieldInner::Note(_), _) => {},
+            (FieldInner::MultiSelect(_), _) => {
+                return Err(invalid(key, "expected a list of options"));
+            },
+            (FieldInner::Input(_) | FieldInner::Select(_) | FieldInner::FilePicker(_), _) => {
+                return Err(invalid(key, "expected a single value"));
+            },
+        }
+        Ok(())
+    }
+
+    /// Check the field's current value against its constraints.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::RequiredField`] if a required field is empty, or
+    /// [`Error::Validation`] if a multi-select is outside its `min`/`max`.
+    pub fn validate(&self) -> Result<()> {
+        let empty = match &self.inner {
+            FieldInner::Input(input) => input.get_value().trim().is_empty(),
+            FieldInner::Select(select) => select.current().is_none(),
+            FieldInner::MultiSelect(select) => select.selected_count() == 0,
+            FieldInner::FilePicker(picker) => picker.selected().is_none(),
+            FieldInner::Confirm(_) | FieldInner::Note(_) => false,
+        };
+        if self.required && empty {
+            return Err(Error::RequiredField { field: self.key.clone() });
+        }
+
+        if let FieldInner::MultiSelect(select) = &self.inner {
+            let count = select.selected_count();
+            if let Some(min) = select.min_selections()
+                && count < min
+            {
+                return Err(invalid(&self.key, format!("select at least {} options", min)));
+            }
+            if let Some(max) = select.max_selections()
+                && count > max
+            {
+                return Err(invalid(&self.key, format!("select at most {} options", max)));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Mark the field as answered with its current value.
+    pub(super) fn submit(&mut self) {
+        match &mut self.inner {
+            FieldInner::Input(input) => {
+                input.update(TextInputMsg::Submit);
+            },
+            FieldInner::Select(select) => {
+                select.update(SelectMsg::Submit);
+            },
+            FieldInner::MultiSelect(select) => {
+                select.update(MultiSelectMsg::Submit);
+            },
+            FieldInner::Confirm(confirm) => {
+                confirm.update(ConfirmMsg::Submit);
+            },
+            FieldInner::Note(note) => note.acknowledge(),
+            // A picker is submitted by `select_path`; an unanswered optional
+            // picker stays empty.
+            FieldInner::FilePicker(_) => {},
+        }
+    }
+}
+
+fn answer_text(answer: &Answer) -> String {
+    match answer {
+        Answer::Bool(value) => value.to_string(),
+        Answer::Int(n) => n.to_string(),
+        Answer::Text(text) => text.clone(),
+        Answer::List(items) => items.join(","),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::forms::{Form, Group};
+
+    fn form() -> Form {
+        Form::new()
+            .group(
+                Group::new()
+                    .field(Field::input().key("name").title("Name").required(true).build())
+                    .field(
+                        Field::select()
+                            .key("license")
+                            .options(vec!["MIT".to_string(), "Apache-2.0".to_string()])
+                            .build(),
+                    ),
+            )
+            .group(
+                Group::new()
+                    .field(
+                        Field::multi_select()
+                            .key("langs")
+                            .options(vec!["Rust".to_string(), "Go".to_string(), "Zig".to_string()])
+                            .max(2)
+                            .build(),
+                    )
+                    .field(Field::confirm().key("ci").default(true).build()),
+            )
+    }
+
+    #[test]
+    fn test_run_with_map() {
+        let answers = Answers::from_map([
+            ("name", Answer::from("teapot")),
+            ("license", "apache-2.0".into()),
+            ("langs", vec!["Rust", "Zig"].into()),
+            ("ci", false.into()),
+        ]);
+        let mut form = form();
+        let results = form.run_with_answers(&answers).unwrap();
+
+        assert!(form.is_submitted());
+        assert_eq!(results.get_string("name"), Some("teapot"));
+        assert_eq!(results.get_string("license"), Some("Apache-2.0"));
+        assert_eq!(
+            results.get_string_list("langs"),
+            Some(&["Rust".to_string(), "Zig".to_string()][..])
+        );
+        assert_eq!(results.get_bool("ci"), Some(false));
+    }
+
+    #[test]
+    fn test_missing_answers_use_defaults() {
+        let answers = Answers::new().set("name", "teapot");
+        let results = form().run_with_answers(&answers).unwrap();
+
+        assert_eq!(results.get_string("license"), Some("MIT"));
+        assert_eq!(results.get_string_list("langs"), Some(&[][..]));
+        assert_eq!(results.get_bool("ci"), Some(true));
+    }
+
+    #[test]
+    fn test_required_field() {
+        let err = form().run_with_answers(&Answers::new().set("name", "  ")).unwrap_err();
+        assert!(matches!(err, Error::RequiredField { ref field } if field == "name"));
+    }
+
+    #[test]
+    fn test_validation_errors() {
+        let cases = [
+            ("license", Answer::from("GPL")),
+            ("langs", Answer::from("Rust, Go, Zig")),
+            ("langs", Answer::from("Cobol")),
+            ("ci", Answer::from("maybe")),
+            ("name", Answer::from(true)),
+        ];
+        for (key, answer) in cases {
+            let answers = Answers::new().set("name", "teapot").set(key, answer);
+            let err = form().run_with_answers(&answers).unwrap_err();
+            assert!(
+                matches!(err, Error::Validation { ref field, .. } if field == key),
+                "{key}: {err}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_env_var_name() {
+        assert_eq!(env_var_name("MYAPP", "project-name"), "MYAPP_PROJECT_NAME");
+        assert_eq!(env_var_name("", "name"), "NAME");
+    }
+
+    #[test]
+    fn test_file_picker_answer() {
+        let dir = std::env::temp_dir();
+        let mut field =
+            Field::file_picker().key("dir").directory(dir.clone()).dirs_only(true).build();
+        field.apply_answer(&Answer::from(".")).unwrap();
+        assert!(field.value().as_path().is_some());
+
+        let mut field = Field::file_picker().key("dir").directory(dir).build();
+        let err = field.apply_answer(&Answer::from("does-not-exist.teapot")).unwrap_err();
+        assert!(matches!(err, Error::Validation { .. }));
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn test_from_json() {
+        let answers =
+            Answers::from_json(r#"{"name": "teapot", "langs": ["Go"], "ci": "no"}"#).unwrap();
+        let results = form().run_with_answers(&answers).unwrap();
+        assert_eq!(results.get_string_list("langs"), Some(&["Go".to_string()][..]));
+        assert_eq!(results.get_bool("ci"), Some(false));
+
+        let err = Answers::from_json(r#"{"name": {"first": "a"}}"#).unwrap_err();
+        assert!(matches!(err, Error::Answers { ref path, .. } if path == "name"));
+    }
+
+    #[cfg(feature = "toml")]
+    #[test]
+    fn test_from_toml() {
+        let answers =
+            Answers::from_toml("name = \"teapot\"\nlicense = \"MIT\"\nci = true").unwrap();
+        let results = form().run_with_answers(&answers).unwrap();
+        assert_eq!(results.get_string("name"), Some("teapot"));
+        assert_eq!(results.get_bool("ci"), Some(true));
+    }
+}
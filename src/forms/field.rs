@@ -11,6 +11,7 @@ use std::{
 /// Counter for generating unique note field keys.
 static NOTE_COUNTER: AtomicU64 = AtomicU64::new(0);
 
+use super::theme::FormTheme;
 use crate::{
     components::{Confirm, FilePicker, MultiSelect, Select, TextInput},
     runtime::{Cmd, Model, accessible::Accessible},
@@ -21,7 +22,7 @@ use crate::{
 pub type DynamicString = Arc<dyn Fn() -> String + Send + Sync>;
 
 /// A field value that can be stored in form results.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum FieldValue {
     /// A string value.
@@ -73,20 +74,27 @@ impl FieldValue {
 }
 
 /// The kind of field.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum FieldKind {
     /// Text input field.
+    #[cfg_attr(feature = "serde", serde(alias = "Input"))]
     Input,
     /// Select field.
+    #[cfg_attr(feature = "serde", serde(alias = "Select"))]
     Select,
     /// Multi-select field.
+    #[cfg_attr(feature = "serde", serde(alias = "MultiSelect"))]
     MultiSelect,
     /// Confirmation field.
+    #[cfg_attr(feature = "serde", serde(alias = "Confirm"))]
     Confirm,
     /// Display-only note.
+    #[cfg_attr(feature = "serde", serde(alias = "Note"))]
     Note,
     /// File picker field.
+    #[cfg_attr(feature = "serde", serde(alias = "FilePicker"))]
     FilePicker,
 }
 
@@ -123,6 +131,10 @@ pub struct Field {
     pub required: bool,
     /// The field kind and state.
     pub inner: FieldInner,
+    /// Colors used to render the field.
+    pub theme: FormTheme,
+    /// Whether the answer may be written to disk by `Form::autosave`.
+    pub autosave: bool,
 }
 
 impl Clone for Field {
@@ -135,6 +147,8 @@ impl Clone for Field {
             description_fn: self.description_fn.clone(),
             required: self.required,
             inner: self.inner.clone(),
+            theme: self.theme.clone(),
+            autosave: self.autosave,
         }
     }
 }
@@ -149,6 +163,8 @@ impl std::fmt::Debug for Field {
             .field("description_fn", &self.description_fn.as_ref().map(|_| "<fn>"))
             .field("required", &self.required)
             .field("inner", &self.inner)
+            .field("theme", &self.theme)
+            .field("autosave", &self.autosave)
             .finish()
     }
 }
@@ -194,6 +210,9 @@ impl Field {
         #[builder(default = String::new())] default_value: String,
         #[builder(default)] required: bool,
         #[builder(default)] hidden: bool,
+        // Hidden inputs hold passwords and tokens, so keep them off disk
+        // unless asked otherwise.
+        #[builder(default = !hidden)] autosave: bool,
         title_fn: Option<DynamicString>,
         description_fn: Option<DynamicString>,
     ) -> Self {
@@ -207,6 +226,8 @@ impl Field {
             description_fn,
             required,
             inner: FieldInner::Input(input),
+            theme: FormTheme::default(),
+            autosave,
         }
     }
 
@@ -229,11 +250,16 @@ impl Field {
         #[builder(default)] title: String,
         description: Option<String>,
         #[builder(default)] options: Vec<String>,
-        _default_index: Option<usize>,
+        default_index: Option<usize>,
+        #[builder(default = true)] autosave: bool,
         title_fn: Option<DynamicString>,
         description_fn: Option<DynamicString>,
     ) -> Self {
-        let select = Select::new(&title).options(options);
+        let mut select = Select::new(&title).options(options);
+
+        if let Some(index) = default_index {
+            select.set_cursor(index);
+        }
 
         Field {
             key,
@@ -243,6 +269,8 @@ impl Field {
             description_fn,
             required: false,
             inner: FieldInner::Select(select),
+            theme: FormTheme::default(),
+            autosave,
         }
     }
 
@@ -267,12 +295,21 @@ impl Field {
         #[builder(default)] title: String,
         description: Option<String>,
         #[builder(default)] options: Vec<String>,
+        #[builder(default)] default: Vec<String>,
         min: Option<usize>,
         max: Option<usize>,
+        #[builder(default = true)] autosave: bool,
         title_fn: Option<DynamicString>,
         description_fn: Option<DynamicString>,
     ) -> Self {
-        let mut select = MultiSelect::new(&title).options(options);
+        let options = options
+            .into_iter()
+            .map(|option| {
+                let checked = default.contains(&option);
+                (option.clone(), option, checked)
+            })
+            .collect();
+        let mut select = MultiSelect::new(&title).options_with_selection(options);
 
         if let Some(min_val) = min {
             select = select.min(min_val);
@@ -289,6 +326,8 @@ impl Field {
             description_fn,
             required: min.is_some_and(|m| m > 0),
             inner: FieldInner::MultiSelect(select),
+            theme: FormTheme::default(),
+            autosave,
         }
     }
 
@@ -310,6 +349,7 @@ impl Field {
         #[builder(default)] title: String,
         description: Option<String>,
         #[builder(default)] default: bool,
+        #[builder(default = true)] autosave: bool,
         title_fn: Option<DynamicString>,
         description_fn: Option<DynamicString>,
     ) -> Self {
@@ -323,6 +363,8 @@ impl Field {
             description_fn,
             required: false,
             inner: FieldInner::Confirm(confirm),
+            theme: FormTheme::default(),
+            autosave,
         }
     }
 
@@ -361,6 +403,8 @@ impl Field {
             description_fn,
             required: false,
             inner: FieldInner::Note(Note::new(content)),
+            theme: FormTheme::default(),
+            autosave: true,
         }
     }
 
@@ -388,6 +432,7 @@ impl Field {
         #[builder(default)] extensions: Vec<String>,
         #[builder(default = 10)] height: usize,
         #[builder(default)] required: bool,
+        #[builder(default = true)] autosave: bool,
         title_fn: Option<DynamicString>,
         description_fn: Option<DynamicString>,
     ) -> Self {
@@ -421,6 +466,8 @@ impl Field {
             description_fn,
             required,
             inner: FieldInner::FilePicker(picker),
+            theme: FormTheme::default(),
+            autosave,
         }
     }
 }
@@ -444,6 +491,11 @@ impl Note {
         self.acknowledged
     }
 
+    /// Clear the acknowledgement so the note is shown again.
+    pub fn reopen(&mut self) {
+        self.acknowledged = false;
+    }
+
     /// Acknowledge the note.
     pub fn acknowledge(&mut self) {
         self.acknowledged = true;
@@ -454,6 +506,11 @@ impl Note {
         self.focused = focused;
     }
 
+    /// Check if the note is focused.
+    pub fn is_focused(&self) -> bool {
+        self.focused
+    }
+
     /// Get the content.
     pub fn content(&self) -> &str {
         &self.content
@@ -529,6 +586,74 @@ impl Field {
         }
     }
 
+    /// Apply a theme to the field and its component.
+    pub fn set_theme(&mut self, theme: &FormTheme) {
+        let t = theme.clone();
+        match &mut self.inner {
+            FieldInner::Input(input) => {
+                *input = input
+                    .clone()
+                    .cursor_color(t.cursor)
+                    .text_color(t.focused)
+                    .placeholder_color(t.blurred)
+                    .error_color(t.error);
+            },
+            FieldInner::Select(select) => {
+                *select = select.clone().selected_color(t.selected).unselected_color(t.blurred);
+            },
+            FieldInner::MultiSelect(select) => {
+                *select = select
+                    .clone()
+                    .selected_color(t.cursor)
+                    .checked_color(t.selected)
+                    .unchecked_color(t.blurred)
+                    .error_color(t.error)
+                    .help_color(t.help);
+            },
+            FieldInner::Confirm(confirm) => {
+                *confirm = confirm
+                    .clone()
+                    .selected_color(t.selected)
+                    .unselected_color(t.blurred)
+                    .help_color(t.help);
+            },
+            FieldInner::FilePicker(picker) => {
+                *picker = picker
+                    .clone()
+                    .title_color(t.title)
+                    .cursor_color(t.cursor)
+                    .directory_color(t.selected)
+                    .help_color(t.help);
+            },
+            FieldInner::Note(_) => {},
+        }
+        self.theme = theme.clone();
+    }
+
+    /// Clear the submitted state so the field can be answered again.
+    pub fn reopen(&mut self) {
+        match &mut self.inner {
+            FieldInner::Input(input) => input.reopen(),
+            FieldInner::Select(select) => select.reopen(),
+            FieldInner::MultiSelect(select) => select.reopen(),
+            FieldInner::Confirm(confirm) => confirm.reopen(),
+            FieldInner::Note(note) => note.reopen(),
+            FieldInner::FilePicker(picker) => picker.reopen(),
+        }
+    }
+
+    /// Check if the field is focused.
+    pub fn is_focused(&self) -> bool {
+        match &self.inner {
+            FieldInner::Input(input) => input.is_focused(),
+            FieldInner::Select(select) => select.is_focused(),
+            FieldInner::MultiSelect(select) => select.is_focused(),
+            FieldInner::Confirm(confirm) => confirm.is_focused(),
+            FieldInner::Note(note) => note.is_focused(),
+            FieldInner::FilePicker(picker) => picker.is_focused(),
+        }
+    }
+
     /// Set focus state.
     pub fn set_focused(&mut self, focused: bool) {
         match &mut self.inner {
@@ -587,18 +712,20 @@ impl Model for Field {
         // Title (dynamic or static)
         let title = self.get_title();
         if !title.is_empty() {
+            let color = if self.is_focused() { &self.theme.focused } else { &self.theme.blurred };
+            output.push_str(&color.to_ansi_fg());
             output.push_str(&title);
             if self.required {
                 output.push_str(" *");
             }
-            output.push('\n');
+            output.push_str("\x1b[0m\n");
         }
 
         // Description (dynamic or static)
         if let Some(desc) = self.get_description() {
             output.push_str(&format!(
                 "{}{}{}\n",
-                crate::style::Color::BrightBlack.to_ansi_fg(),
+                self.theme.description.to_ansi_fg(),
                 desc,
                 "\x1b[0m"
             ));
@@ -614,10 +741,10 @@ impl Model for Field {
             FieldInner::Note(note) => {
                 output.push_str(&format!(
                     "{}{}{}\n\n{}Press Enter to continue{}",
-                    crate::style::Color::BrightBlack.to_ansi_fg(),
+                    self.theme.description.to_ansi_fg(),
                     note.content(),
                     "\x1b[0m",
-                    crate::style::Color::BrightBlack.to_ansi_fg(),
+                    self.theme.help.to_ansi_fg(),
                     "\x1b[0m"
                 ));
             },
@@ -737,3 +864,23 @@ impl Field {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_title_follows_focus() {
+        let mut field = Field::input().key("name").title("Name").build();
+        let theme = FormTheme::charm();
+        field.set_theme(&theme);
+
+        let focused = format!("{}Name", theme.focused.to_ansi_fg());
+        let blurred = format!("{}Name", theme.blurred.to_ansi_fg());
+        assert!(field.view().starts_with(&focused));
+
+        field.set_focused(false);
+        assert!(!field.is_focused());
+        assert!(field.view().starts_with(&blurred));
+    }
+}
@@ -3,26 +3,32 @@
 use std::collections::HashMap;
 
 use super::{
-    field::FieldValue,
+    answers::Answers,
+    field::{FieldInner, FieldValue},
     group::{Group, GroupMsg},
+    theme::FormTheme,
 };
 use crate::{
     error::Error,
     runtime::{Cmd, Model, accessible::Accessible},
-    style::{Color, Position, join_horizontal_with},
+    style::{Position, join_horizontal_with},
     terminal::{Event, KeyCode},
 };
 
 /// Form layout options.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum FormLayout {
     /// Show one group at a time (default).
     #[default]
+    #[cfg_attr(feature = "serde", serde(alias = "Default"))]
     Default,
     /// Show all groups stacked vertically.
+    #[cfg_attr(feature = "serde", serde(alias = "Stack"))]
     Stack,
     /// Show groups in columns.
+    #[cfg_attr(feature = "serde", serde(alias = "Columns"))]
     Columns(usize),
 }
 
@@ -83,6 +89,12 @@ pub enum FormMsg {
     Submit,
     /// Cancel the form.
     Cancel,
+    /// Move the review cursor up.
+    ReviewUp,
+    /// Move the review cursor down.
+    ReviewDown,
+    /// Edit the field at this row of the review page.
+    Edit(usize),
 }
 
 /// A form with multiple groups of fields.
@@ -97,6 +109,15 @@ pub struct Form {
     cancelled: bool,
     accessible: bool,
     layout: FormLayout,
+    review: bool,
+    reviewing: bool,
+    review_cursor: usize,
+    editing: Option<(usize, usize)>,
+    theme: Option<FormTheme>,
+    #[cfg(feature = "json")]
+    autosave: Option<std::path::PathBuf>,
+    #[cfg(feature = "json")]
+    last_saved: Option<SavedProgress>,
 }
 
 impl Default for Form {
@@ -117,6 +138,15 @@ impl Form {
             cancelled: false,
             accessible: std::env::var("ACCESSIBLE").is_ok(),
             layout: FormLayout::Default,
+            review: false,
+            reviewing: false,
+            review_cursor: 0,
+            editing: None,
+            theme: None,
+            #[cfg(feature = "json")]
+            autosave: None,
+            #[cfg(feature = "json")]
+            last_saved: None,
         }
     }
 
@@ -133,11 +163,30 @@ impl Form {
     }
 
     /// Add a group to the form.
-    pub fn group(mut self, group: Group) -> Self {
+    pub fn group(mut self, mut group: Group) -> Self {
+        if let Some(theme) = &self.theme {
+            group.set_theme(theme);
+        }
         self.groups.push(group);
         self
     }
 
+    /// Set the theme for the form and every field in it.
+    ///
+    /// Applies to groups added before or after this call.
+    pub fn theme(mut self, theme: FormTheme) -> Self {
+        for group in &mut self.groups {
+            group.set_theme(&theme);
+        }
+        self.theme = Some(theme);
+        self
+    }
+
+    /// Get the form's colors, falling back to the default theme.
+    fn colors(&self) -> FormTheme {
+        self.theme.clone().unwrap_or_default()
+    }
+
     /// Enable accessible mode.
     pub fn accessible(mut self, accessible: bool) -> Self {
         self.accessible = accessible;
@@ -154,6 +203,20 @@ impl Form {
         self
     }
 
+    /// Show a review page listing every answer before submission.
+    ///
+    /// From the review page the user can jump back to any field, edit it,
+    /// and return to the review. The form is only submitted from this page.
+    pub fn review(mut self, review: bool) -> Self {
+        self.review = review;
+        self
+    }
+
+    /// Check if the review page is showing.
+    pub fn is_reviewing(&self) -> bool {
+        self.reviewing
+    }
+
     /// Get the current layout.
     pub fn get_layout(&self) -> FormLayout {
         self.layout
@@ -218,6 +281,67 @@ impl Form {
         }
     }
 
+    /// Finish the last group: show the review page or submit.
+    fn finish(&mut self) {
+        if self.review {
+            self.reviewing = true;
+            self.review_cursor = 0;
+        } else {
+            self.submitted = true;
+        }
+    }
+
+    /// Fields listed on the review page, as `(group, field)` indices.
+    ///
+    /// Notes carry no value and are skipped.
+    fn review_fields(&self) -> Vec<(usize, usize)> {
+        let mut rows = Vec::new();
+        for (g, group) in self.groups.iter().enumerate() {
+            for (f, field) in group.fields().iter().enumerate() {
+                if !matches!(field.inner, FieldInner::Note(_)) {
+                    rows.push((g, f));
+                }
+            }
+        }
+        rows
+    }
+
+    /// Leave the review page to edit the field at `row`.
+    fn edit(&mut self, row: usize) {
+        let Some(&(g, f)) = self.review_fields().get(row) else {
+            return;
+        };
+        if let Some(group) = self.groups.get_mut(g) {
+            if let Some(field) = group.fields_mut().get_mut(f) {
+                field.reopen();
+            }
+            group.focus_field(f);
+        }
+        self.current_group = g;
+        self.editing = Some((g, f));
+        self.reviewing = false;
+        self.review_cursor = row;
+    }
+
+    /// Return to the review page once the field being edited is submitted.
+    ///
+    /// Returns true if an edit was in progress.
+    fn finish_edit(&mut self) -> bool {
+        let Some((g, f)) = self.editing else {
+            return false;
+        };
+        let done = self
+            .groups
+            .get(g)
+            .and_then(|group| group.fields().get(f))
+            .is_some_and(|field| field.is_submitted());
+        if done {
+            self.editing = None;
+            self.reviewing = true;
+        }
+        true
+    }
+
     /// Check if on last group.
     fn is_last_group(&self) -> bool {
         self.current_group >= self.groups.len().saturating_sub(1)
@@ -225,26 +349,15 @@ impl Form {
 
     /// Initialize the form.
     pub fn init_form(&mut self) {
-        if let Some(group) = self.groups.get_mut(0) {
-            group.focus_first();
+        if let Some(group) = self.groups.get_mut(self.current_group) {
+            group.focus_field(group.current_field());
         }
     }
 }
 
-impl Model for Form {
-    type Message = FormMsg;
-
-    fn init(&self) -> Option<Cmd<Self::Message>> {
-        // Initialize current group
-        if let Some(group) = self.groups.get(self.current_group) {
-            let idx = self.current_group;
-            group.init().map(|c| c.map(move |m| FormMsg::Group(idx, m)))
-        } else {
-            None
-        }
-    }
-
-    fn update(&mut self, msg: Self::Message) -> Option<Cmd<Self::Message>> {
+impl Form {
+    /// Apply a message to the form state.
+    fn update_form(&mut self, msg: FormMsg) -> Option<Cmd<FormMsg>> {
         match msg {
             FormMsg::Group(idx, group_msg) => {
                 // Update the group and capture state before releasing borrow
@@ -259,10 +372,12 @@ impl Model for Form {
                 };
 
                 // Now handle state changes without borrow conflicts
-                if is_complete && !self.is_last_group() {
+                if self.finish_edit() {
+                    // Editing from the review page; don't advance.
+                } else if is_complete && !self.is_last_group() {
                     self.next_group();
                 } else if is_complete && self.is_last_group() {
-                    self.submitted = true;
+                    self.finish();
                 }
 
                 if is_cancelled {
@@ -273,7 +388,7 @@ impl Model for Form {
             },
             FormMsg::NextGroup => {
                 if self.is_last_group() {
-                    self.submitted = true;
+                    self.finish();
                 } else {
                     self.next_group();
                 }
@@ -284,6 +399,12 @@ impl Model for Form {
                 None
             },
             FormMsg::Submit => {
+                if self.review && !self.reviewing {
+                    self.editing = None;
+                    self.finish();
+                    return None;
+                }
+                self.reviewing = false;
                 self.submitted = true;
                 Some(Cmd::quit())
             },
@@ -291,24 +412,65 @@ impl Model for Form {
                 self.cancelled = true;
                 Some(Cmd::quit())
             },
+            FormMsg::ReviewUp => {
+                self.review_cursor = self.review_cursor.saturating_sub(1);
+                None
+            },
+            FormMsg::ReviewDown => {
+                // The last row is the submit button.
+                let last = self.review_fields().len();
+                self.review_cursor = (self.review_cursor + 1).min(last);
+                None
+            },
+            FormMsg::Edit(row) => {
+                self.edit(row);
+                None
+            },
         }
     }
+}
+
+impl Model for Form {
+    type Message = FormMsg;
+
+    fn init(&self) -> Option<Cmd<Self::Message>> {
+        // Initialize current group
+        if let Some(group) = self.groups.get(self.current_group) {
+            let idx = self.current_group;
+            group.init().map(|c| c.map(move |m| FormMsg::Group(idx, m)))
+        } else {
+            None
+        }
+    }
+
+    fn update(&mut self, msg: Self::Message) -> Option<Cmd<Self::Message>> {
+        let cmd = self.update_form(msg);
+        #[cfg(feature = "json")]
+        self.autosave_progress();
+        cmd
+    }
 
     fn view(&self) -> String {
+        let theme = self.colors();
+
         if self.submitted {
             return self.view_results();
         }
 
+        if self.reviewing {
+            return self.view_review();
+        }
+
         let mut output = String::new();
 
         // Form title
         if let Some(title) = &self.title {
-            output.push_str(&format!("{}\x1b[1m{}\x1b[0m\n", Color::Cyan.to_ansi_fg(), title));
+            output.push_str(&format!("{}\x1b[1m{}\x1b[0m\n", theme.title.to_ansi_fg(), title));
         }
 
         // Form description
         if let Some(desc) = &self.description {
-            output.push_str(&format!("{}{}{}\n", Color::BrightBlack.to_ansi_fg(), desc, "\x1b[0m"));
+            output.push_str(&format!("{}{}{}\n", theme.description.to_ansi_fg(), desc, "\x1b[0m"));
         }
 
         if self.title.is_some() || self.description.is_some() {
@@ -327,7 +489,7 @@ impl Model for Form {
                 if self.groups.len() > 1 {
                     output.push_str(&format!(
                         "\n{}Page {}/{}{}",
-                        Color::BrightBlack.to_ansi_fg(),
+                        theme.help.to_ansi_fg(),
                         self.current_group + 1,
                         self.groups.len(),
                         "\x1b[0m"
@@ -374,6 +536,22 @@ impl Model for Form {
             return Some(FormMsg::Cancel);
         }
 
+        if self.reviewing {
+            let Event::Key(key) = event else {
+                return None;
+            };
+            return match key.code {
+                KeyCode::Up | KeyCode::Char('k') => Some(FormMsg::ReviewUp),
+                KeyCode::Down | KeyCode::Char('j') => Some(FormMsg::ReviewDown),
+                KeyCode::Char('s') => Some(FormMsg::Submit),
+                KeyCode::Enter if self.review_cursor >= self.review_fields().len() => {
+                    Some(FormMsg::Submit)
+                },
+                KeyCode::Enter => Some(FormMsg::Edit(self.review_cursor)),
+                _ => None,
+            };
+        }
+
         // Pass to current group
         if let Some(group) = self.groups.get(self.current_group) {
             let idx = self.current_group;
@@ -387,39 +565,89 @@ impl Model for Form {
 impl Form {
     /// View the results summary.
     fn view_results(&self) -> String {
+        let theme = self.colors();
         let mut output = String::new();
 
-        output.push_str(&format!("{}✓ Form completed{}\n\n", Color::Green.to_ansi_fg(), "\x1b[0m"));
+        output.push_str(&format!(
+            "{}✓ Form completed{}\n\n",
+            theme.success.to_ansi_fg(),
+            "\x1b[0m"
+        ));
 
         for group in &self.groups {
             for field in group.fields() {
-                let value_str = match field.value() {
-                    FieldValue::String(s) => s,
-                    FieldValue::Bool(b) => {
-                        if b {
-                            "Yes".to_string()
-                        } else {
-                            "No".to_string()
-                        }
-                    },
-                    FieldValue::StringList(list) => list.join(", "),
-                    FieldValue::Int(n) => n.to_string(),
-                    FieldValue::Path(p) => p.display().to_string(),
-                    FieldValue::None => "(empty)".to_string(),
-                };
-
                 output.push_str(&format!(
                     "  {}{}{}: {}\n",
-                    Color::Cyan.to_ansi_fg(),
+                    theme.selected.to_ansi_fg(),
                     field.key(),
                     "\x1b[0m",
-                    value_str
+                    display_value(field.value())
                 ));
             }
         }
 
         output
     }
+
+    /// View the review page.
+    fn view_review(&self) -> String {
+        let theme = self.colors();
+        let mut output = String::new();
+
+        if let Some(title) = &self.title {
+            output.push_str(&format!("{}\x1b[1m{}\x1b[0m\n", theme.title.to_ansi_fg(), title));
+        }
+        output.push_str("Review your answers\n\n");
+
+        let rows = self.review_fields();
+        for (row, &(g, f)) in rows.iter().enumerate() {
+            let field = &self.groups[g].fields()[f];
+            let value = display_value(field.value());
+            if row == self.review_cursor {
+                output.push_str(&format!(
+                    "{}❯ {}: {}\x1b[0m\n",
+                    theme.selected.to_ansi_fg(),
+                    field.key(),
+                    value
+                ));
+            } else {
+                output.push_str(&format!("  {}: {}\n", field.key(), value));
+            }
+        }
+
+        output.push('\n');
+        if self.review_cursor >= rows.len() {
+            output.push_str(&format!("{}❯ \x1b[1mSubmit\x1b[0m\n", theme.selected.to_ansi_fg()));
+        } else {
+            output.push_str("  Submit\n");
+        }
+
+        output.push_str(&format!(
+            "\n{}↑/↓ navigate • enter edit • s submit • esc cancel{}",
+            theme.help.to_ansi_fg(),
+            "\x1b[0m"
+        ));
+
+        output
+    }
+}
+
+/// Format a field value for summaries.
+fn display_value(value: FieldValue) -> String {
+    match value {
+        FieldValue::String(s) => s,
+        FieldValue::Bool(b) => {
+            if b {
+                "Yes".to_string()
+            } else {
+                "No".to_string()
+            }
+        },
+        FieldValue::StringList(list) => list.join(", "),
+        FieldValue::Int(n) => n.to_string(),
+        FieldValue::Path(p) => p.display().to_string(),
+        FieldValue::None => "(empty)".to_string(),
+    }
 }
 
 impl Accessible for Form {
@@ -428,6 +656,21 @@ impl Accessible for Form {
     fn accessible_prompt(&self) -> String {
         let mut prompt = String::new();
 
+        if self.reviewing {
+            prompt.push_str("Review your answers:\n");
+            for (row, &(g, f)) in self.review_fields().iter().enumerate() {
+                let field = &self.groups[g].fields()[f];
+                prompt.push_str(&format!(
+                    "  {}) {}: {}\n",
+                    row + 1,
+                    field.key(),
+                    display_value(field.value())
+                ));
+            }
+            prompt.push_str("Enter a number to edit, or press Enter to submit (q to cancel): ");
+            return prompt;
+        }
+
         // Form title
         if let Some(title) = &self.title {
             prompt.push_str(&format!("=== {} ===\n", title));
@@ -454,6 +697,18 @@ impl Accessible for Form {
     }
 
     fn parse_accessible_input(&self, input: &str) -> Option<Self::Message> {
+        if self.reviewing {
+            let trimmed = input.trim().to_lowercase();
+            return match trimmed.as_str() {
+                "" | "s" | "submit" => Some(FormMsg::Submit),
+                "q" | "quit" | "cancel" => Some(FormMsg::Cancel),
+                _ => match trimmed.parse::<usize>() {
+                    Ok(n) if n > 0 && n <= self.review_fields().len() => Some(FormMsg::Edit(n - 1)),
+                    _ => None,
+                },
+            };
+        }
+
         if let Some(group) = self.groups.get(self.current_group) {
             let idx = self.current_group;
             group.parse_accessible_input(input).map(|m| FormMsg::Group(idx, m))
@@ -479,11 +734,22 @@ impl Form {
     ///
     /// Returns an error if terminal I/O fails.
     pub fn run_accessible(&mut self) -> Result<Option<FormResults>, Error> {
+        let result = self.accessible_loop();
+        #[cfg(feature = "json")]
+        self.autosave_progress();
+        result
+    }
+
+    /// Prompt for fields until the form is submitted or cancelled.
+    fn accessible_loop(&mut self) -> Result<Option<FormResults>, Error> {
         use std::io::{self, BufRead, Write};
 
         self.init_form();
 
         loop {
+            #[cfg(feature = "json")]
+            self.autosave_progress();
+
             // Print the accessible prompt
             print!("{}", self.accessible_prompt());
             io::stdout().flush()?;
@@ -492,26 +758,46 @@ impl Form {
             let mut input = String::new();
             io::stdin().lock().read_line(&mut input)?;
 
+            if self.reviewing {
+                if let Some(msg) = self.parse_accessible_input(&input) {
+                    self.update(msg);
+                }
+                if self.submitted {
+                    println!("\nForm completed!");
+                    return Ok(Some(self.results()));
+                }
+                if self.cancelled {
+                    return Ok(None);
+                }
+                continue;
+            }
+
             // Apply input to current field
             if let Some(group) = self.groups.get_mut(self.current_group) {
                 let complete = group.apply_accessible_input(&input);
+                let group_complete = group.is_complete();
 
-                if complete {
-                    // Check if group is cancelled
-                    if group.is_cancelled() {
-                        self.cancelled = true;
-                        return Ok(None);
-                    }
+                // Check if group is cancelled
+                if complete && group.is_cancelled() {
+                    self.cancelled = true;
+                    return Ok(None);
+                }
 
-                    // Move to next field or group
-                    if group.is_complete() {
-                        if self.is_last_group() {
-                            self.submitted = true;
-                            println!("\nForm completed!");
-                            return Ok(Some(self.results()));
-                        } else {
-                            self.next_group();
-                        }
+                // Editing from the review page returns there instead of advancing
+                if self.finish_edit() {
+                    continue;
+                }
+
+                // Move to next field or group
+                if complete && group_complete {
+                    if !self.is_last_group() {
+                        self.next_group();
+                    } else if self.review {
+                        self.finish();
+                    } else {
+                        self.submitted = true;
+                        println!("\nForm completed!");
+                        return Ok(Some(self.results()));
                     }
                 }
             }
@@ -527,10 +813,202 @@ impl Form {
     }
 }
 
+impl Form {
+    /// Run the form headlessly, taking every value from `answers`.
+    ///
+    /// Fields without an answer keep their default. Each field is validated
+    /// in order, and the first failure is returned. This lets the same form
+    /// serve both interactive use and automation (CI, scripts, tests).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::RequiredField`] if a required field ends up empty, or
+    /// [`Error::Validation`] if an answer doesn't fit its field.
+    pub fn run_with_answers(&mut self, answers: &Answers) -> Result<FormResults, Error> {
+        self.init_form();
+
+        for group in &mut self.groups {
+            for field in group.fields_mut() {
+                if let Some(answer) = answers.get(field.key()) {
+                    field.apply_answer(&answer)?;
+                }
+                field.validate()?;
+                field.submit();
+            }
+        }
+
+        self.current_group = self.groups.len().saturating_sub(1);
+        self.submitted = true;
+        Ok(self.results())
+    }
+}
+
+// Persistence
+// ============================================================================
+
+/// Snapshot of a partially completed form, written by [`Form::autosave`].
+#[cfg(feature = "json")]
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+struct SavedProgress {
+    current_group: usize,
+    current_field: usize,
+    values: HashMap<String, FieldValue>,
+    answered: Vec<String>,
+}
+
+#[cfg(feature = "json")]
+impl Form {
+    /// Save progress to `path` whenever it changes.
+    ///
+    /// Partial answers, the current group and the focused field are written
+    /// as JSON so an interrupted form can be picked up again with
+    /// [`Form::resume`]. Fields built with `.autosave(false)` are never
+    /// written; hidden inputs (passwords, tokens) opt out by default. The
+    /// file is removed once the form is submitted.
+    pub fn autosave(mut self, path: impl Into<std::path::PathBuf>) -> Self {
+        self.autosave = Some(path.into());
+        self
+    }
+
+    /// Restore progress saved by [`Form::autosave`].
+    ///
+    /// A missing file is not an error; the form simply starts fresh. Saved
+    /// answers that no longer fit a field (for example a removed option) are
+    /// dropped, and fields excluded from autosave must be answered again.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Io`] if the file can't be read, or [`Error::Answers`]
+    /// with the path of the offending value if it isn't valid progress.
+    pub fn resume(mut self, path: impl AsRef<std::path::Path>) -> Result<Self, Error> {
+        let json = match std::fs::read_to_string(path) {
+            Ok(json) => json,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(self),
+            Err(err) => return Err(err.into()),
+        };
+        let mut de = serde_json::Deserializer::from_str(&json);
+        let progress: SavedProgress =
+            serde_path_to_error::deserialize(&mut de).map_err(super::answers::parse_error)?;
+
+        let saved = (progress.current_group, progress.current_field);
+        let mut first_open = None;
+
+        for (g, group) in self.groups.iter_mut().enumerate() {
+            for (f, field) in group.fields_mut().iter_mut().enumerate() {
+                if !field.autosave {
+                    first_open.get_or_insert((g, f));
+                    continue;
+                }
+
+                let restored = match progress.values.get(field.key()).and_then(saved_answer) {
+                    Some(answer) => field.apply_answer(&answer).is_ok(),
+                    None => true,
+                };
+
+                // Note keys aren't stable between runs, so go by position.
+                let answered = if matches!(field.inner, FieldInner::Note(_)) {
+                    (g, f) < saved
+                } else {
+                    restored && progress.answered.iter().any(|key| key == field.key())
+                };
+
+                if answered {
+                    field.submit();
+                } else {
+                    field.reopen();
+                    first_open.get_or_insert((g, f));
+                }
+            }
+        }
+
+        // Never resume past a field that still needs an answer.
+        let (g, f) = match first_open {
+            Some(open) if open < saved => open,
+            _ => saved,
+        };
+        if let Some(group) = self.groups.get_mut(g) {
+            self.current_group = g;
+            group.focus_field(f);
+        }
+
+        Ok(self)
+    }
+
+    /// Capture the current answers and position.
+    fn progress(&self) -> SavedProgress {
+        let mut values = HashMap::new();
+        let mut answered = Vec::new();
+
+        for group in &self.groups {
+            for field in group.fields() {
+                if !field.autosave || matches!(field.inner, FieldInner::Note(_)) {
+                    continue;
+                }
+                let value = field.value();
+                if !matches!(value, FieldValue::None) {
+                    values.insert(field.key().to_string(), value);
+                }
+                if field.is_submitted() {
+                    answered.push(field.key().to_string());
+                }
+            }
+        }
+
+        SavedProgress {
+            current_group: self.current_group,
+            current_field: self.current_group().map_or(0, Group::current_field),
+            values,
+            answered,
+        }
+    }
+
+    /// Write progress to the autosave file if it changed since the last save.
+    fn autosave_progress(&mut self) {
+        let Some(path) = &self.autosave else {
+            return;
+        };
+
+        // Saving is best effort: a failed write must not interrupt the form.
+        if self.submitted {
+            if self.last_saved.take().is_some() {
+                let _ = std::fs::remove_file(path);
+            }
+            return;
+        }
+
+        let progress = self.progress();
+        if self.last_saved.as_ref() == Some(&progress) {
+            return;
+        }
+        if let Ok(json) = serde_json::to_string_pretty(&progress) {
+            let _ = std::fs::write(path, json);
+            self.last_saved = Some(progress);
+        }
+    }
+}
+
+/// Convert a saved value back into an answer.
+#[cfg(feature = "json")]
+fn saved_answer(value: &FieldValue) -> Option<super::Answer> {
+    use super::Answer;
+
+    match value {
+        FieldValue::String(s) => Some(Answer::Text(s.clone())),
+        FieldValue::Bool(b) => Some(Answer::Bool(*b)),
+        FieldValue::StringList(list) => Some(Answer::List(list.clone())),
+        FieldValue::Int(n) => Some(Answer::Int(*n)),
+        FieldValue::Path(path) => Some(Answer::Text(path.display().to_string())),
+        FieldValue::None => None,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::forms::Field;
+    use crate::{
+        components::text_input::TextInputMsg,
+        forms::{Field, field::FieldMsg},
+    };
 
     #[test]
     fn test_form_creation() {
@@ -650,4 +1128,181 @@ mod tests {
         show_hint.store(true, Ordering::SeqCst);
         assert_eq!(field.get_description(), Some("Hint: It's your birthday!".to_string()));
     }
+
+    #[test]
+    fn test_theme_propagates() {
+        let theme = FormTheme::dracula();
+        let purple = theme.title.to_ansi_fg();
+        let green = theme.selected.to_ansi_fg();
+
+        // Groups added before and after the theme both pick it up.
+        let form = Form::new()
+            .title("Setup")
+            .group(Group::new().field(Field::input().key("name").build()))
+            .theme(theme.clone())
+            .group(
+                Group::new().field(
+                    Field::select()
+                        .key("color")
+                        .options(vec!["Red".to_string(), "Blue".to_string()])
+                        .build(),
+                ),
+            );
+
+        assert!(form.view().contains(&purple));
+        assert!(form.groups.iter().all(|g| g.fields().iter().all(|f| f.theme == theme)));
+        assert!(form.groups[1].view().contains(&green));
+        assert!(!Form::new().title("Setup").view().contains(&purple));
+    }
+
+    fn input_msg(group: usize, field: usize, msg: TextInputMsg) -> FormMsg {
+        FormMsg::Group(group, GroupMsg::Field(field, FieldMsg::Input(msg)))
+    }
+
+    fn review_form() -> Form {
+        let mut form = Form::new()
+            .review(true)
+            .group(Group::new().field(Field::input().key("name").build()))
+            .group(Group::new().field(Field::input().key("city").build()));
+        form.init_form();
+        for g in 0..2 {
+            form.update(input_msg(g, 0, TextInputMsg::SetValue(format!("v{}", g))));
+            form.update(input_msg(g, 0, TextInputMsg::Submit));
+        }
+        form
+    }
+
+    #[test]
+    fn test_review_before_submit() {
+        let mut form = review_form();
+        assert!(form.is_reviewing());
+        assert!(!form.is_submitted());
+        assert!(form.view().contains("Review your answers"));
+
+        // Jump back to the first field, edit it, and land on the review page again
+        form.update(FormMsg::Edit(0));
+        assert!(!form.is_reviewing());
+        assert_eq!(form.current_group().map(|g| g.current_field()), Some(0));
+        form.update(input_msg(0, 0, TextInputMsg::SetValue("edited".to_string())));
+        form.update(input_msg(0, 0, TextInputMsg::Submit));
+        assert!(form.is_reviewing());
+        assert_eq!(form.results().get_string("name"), Some("edited"));
+
+        form.update(FormMsg::Submit);
+        assert!(form.is_submitted());
+    }
+
+    #[test]
+    fn test_review_keys() {
+        let mut form = review_form();
+        let key =
+            |code| Event::Key(crate::terminal::KeyEvent { code, modifiers: Default::default() });
+
+        // Enter on a field row edits it
+        assert!(matches!(form.handle_event(key(KeyCode::Enter)), Some(FormMsg::Edit(0))));
+
+        // The row after the last field is the submit button
+        form.update(FormMsg::ReviewDown);
+        form.update(FormMsg::ReviewDown);
+        form.update(FormMsg::ReviewDown);
+        assert!(matches!(form.handle_event(key(KeyCode::Enter)), Some(FormMsg::Submit)));
+    }
+
+    #[test]
+    fn test_review_accessible_input() {
+        let mut form = review_form();
+        assert!(form.accessible_prompt().contains("2) city: v1"));
+        assert!(matches!(form.parse_accessible_input("2"), Some(FormMsg::Edit(1))));
+        assert!(matches!(form.parse_accessible_input(""), Some(FormMsg::Submit)));
+        assert!(form.parse_accessible_input("9").is_none());
+
+        form.update(FormMsg::Edit(1));
+        form.current_group_mut().unwrap().apply_accessible_input("new city");
+        assert!(form.finish_edit());
+        assert!(form.is_reviewing());
+        assert_eq!(form.results().get_string("city"), Some("new city"));
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn test_autosave_and_resume() {
+        let path =
+            std::env::temp_dir().join(format!("teapot-autosave-{}.json", std::process::id()));
+        let build = || {
+            Form::new()
+                .group(
+                    Group::new()
+                        .field(Field::input().key("name").build())
+                        .field(Field::input().key("token").hidden(true).build())
+                        .field(Field::input().key("email").build()),
+                )
+                .group(Group::new().field(Field::input().key("city").build()))
+        };
+
+        let mut form = build().autosave(&path);
+        form.init_form();
+        for (field, value) in [(0, "alice"), (1, "s3cret"), (2, "a@example.com")] {
+            form.update(input_msg(0, field, TextInputMsg::SetValue(value.to_string())));
+            form.update(input_msg(0, field, TextInputMsg::Submit));
+        }
+        assert_eq!(form.current_group, 1);
+
+        let saved = std::fs::read_to_string(&path).unwrap();
+        assert!(saved.contains("alice"));
+        assert!(!saved.contains("s3cret"));
+
+        // The hidden input must be answered again, so focus returns to it.
+        let resumed = build().resume(&path).unwrap();
+        let results = resumed.results();
+        assert_eq!(results.get_string("name"), Some("alice"));
+        assert_eq!(results.get_string("email"), Some("a@example.com"));
+        assert_eq!(results.get_string("token"), Some(""));
+        assert_eq!(resumed.current_group, 0);
+        assert_eq!(resumed.current_group().map(Group::current_field), Some(1));
+
+        // Submitting removes the autosave file.
+        form.update(FormMsg::Submit);
+        assert!(!path.exists());
+
+        // A missing file just starts fresh.
+        let fresh = build().resume(&path).unwrap();
+        assert_eq!(fresh.results().get_string("name"), Some(""));
+
+        // A corrupt file is reported as invalid answers, not an I/O error.
+        std::fs::write(&path, r#"{"current_group": "two"}"#).unwrap();
+        let err = build().resume(&path).unwrap_err();
+        assert!(matches!(err, Error::Answers { ref path, .. } if path == "current_group"));
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn test_autosave_only_writes_changes() {
+        let path =
+            std::env::temp_dir().join(format!("teapot-autosave-diff-{}.json", std::process::id()));
+        let mut form = Form::new()
+            .group(
+                Group::new()
+                    .field(Field::input().key("name").build())
+                    .field(Field::input().key("pin").autosave(false).build()),
+            )
+            .autosave(&path);
+        form.init_form();
+
+        form.update(input_msg(0, 0, TextInputMsg::SetValue("alice".to_string())));
+        assert!(std::fs::read_to_string(&path).unwrap().contains("alice"));
+
+        // Messages that leave the answers alone don't touch the file.
+        std::fs::remove_file(&path).unwrap();
+        form.update(input_msg(0, 0, TextInputMsg::CursorStart));
+        assert!(!path.exists());
+
+        // Opted-out fields are never written.
+        form.update(input_msg(0, 0, TextInputMsg::Submit));
+        form.update(input_msg(0, 1, TextInputMsg::SetValue("1234".to_string())));
+        let saved = std::fs::read_to_string(&path).unwrap();
+        assert!(!saved.contains("1234"));
+
+        let _ = std::fs::remove_file(&path);
+    }
 }
@@ -1,6 +1,9 @@
 //! Form groups (pages).
 
-use super::field::{Field, FieldMsg};
+use super::{
+    field::{Field, FieldMsg},
+    theme::FormTheme,
+};
 use crate::{
     runtime::{Cmd, Model, accessible::Accessible},
     terminal::Event,
@@ -13,6 +16,7 @@ pub struct Group {
     description: Option<String>,
     fields: Vec<Field>,
     current_field: usize,
+    theme: FormTheme,
 }
 
 impl Default for Group {
@@ -24,7 +28,13 @@ impl Default for Group {
 impl Group {
     /// Create a new empty group.
     pub fn new() -> Self {
-        Self { title: None, description: None, fields: Vec::new(), current_field: 0 }
+        Self {
+            title: None,
+            description: None,
+            fields: Vec::new(),
+            current_field: 0,
+            theme: FormTheme::default(),
+        }
     }
 
     /// Set the group title.
@@ -120,9 +130,28 @@ impl Group {
     /// Initialize focus on first field.
     pub fn focus_first(&mut self) {
         self.current_field = 0;
-        if let Some(field) = self.fields.get_mut(0) {
-            field.set_focused(true);
+        for (i, field) in self.fields.iter_mut().enumerate() {
+            field.set_focused(i == 0);
+        }
+    }
+
+    /// Apply a theme to the group and all of its fields.
+    pub fn set_theme(&mut self, theme: &FormTheme) {
+        for field in &mut self.fields {
+            field.set_theme(theme);
         }
+        self.theme = theme.clone();
+    }
+
+    /// Move focus to the field at `index`.
+    pub fn focus_field(&mut self, index: usize) {
+        if index >= self.fields.len() {
+            return;
+        }
+        for (i, field) in self.fields.iter_mut().enumerate() {
+            field.set_focused(i == index);
+        }
+        self.current_field = index;
     }
 }
 
@@ -194,18 +223,14 @@ impl Model for Group {
 
         // Group title
         if let Some(title) = &self.title {
-            output.push_str(&format!(
-                "{}\x1b[1m{}\x1b[0m\n",
-                crate::style::Color::Cyan.to_ansi_fg(),
-                title
-            ));
+            output.push_str(&format!("{}\x1b[1m{}\x1b[0m\n", self.theme.title.to_ansi_fg(), title));
         }
 
         // Group description
         if let Some(desc) = &self.description {
             output.push_str(&format!(
                 "{}{}{}\n",
-                crate::style::Color::BrightBlack.to_ansi_fg(),
+                self.theme.description.to_ansi_fg(),
                 desc,
                 "\x1b[0m"
             ));
@@ -225,7 +250,7 @@ impl Model for Group {
         if total > 1 {
             output.push_str(&format!(
                 "\n\n{}({}/{}){}\n",
-                crate::style::Color::BrightBlack.to_ansi_fg(),
+                self.theme.help.to_ansi_fg(),
                 self.current_field + 1,
                 total,
                 "\x1b[0m"
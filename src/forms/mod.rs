@@ -31,11 +31,17 @@
 //! Program::new(form).run();
 //! ```
 
+mod answers;
 mod field;
 mod form;
 mod group;
+mod spec;
+mod theme;
 
 // Re-export field types
+pub use answers::{Answer, Answers};
 pub use field::{Field, FieldKind, FieldValue, Note};
 pub use form::{Form, FormLayout, FormMsg, FormResults};
 pub use group::Group;
+pub use spec::{DefaultValue, FieldSpec, FormSpec, GroupSpec};
+pub use theme::FormTheme;
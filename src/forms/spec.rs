@@ -0,0 +1,744 @@
+//! Declarative form specs.
+//!
+//! A [`FormSpec`] describes a form as plain data so that questions can live in
+//! TOML, JSON or YAML files instead of Rust code. Build a runnable [`Form`] with
+//! [`Form::from_spec`].
+//!
+//! # Example
+//!
+//! ```toml
+//! title = "Project Setup"
+//!
+//! [[groups]]
+//! title = "Basics"
+//!
+//! [[groups.fields]]
+//! kind = "input"
+//! key = "name"
+//! title = "Project name"
+//! required = true
+//!
+//! [[groups.fields]]
+//! kind = "select"
+//! key = "license"
+//! title = "License"
+//! options = ["MIT", "Apache-2.0"]
+//! default = "MIT"
+//! ```
+//!
+//! Loading requires the `json`, `toml` or `yaml` feature:
+//!
+//! ```ignore
+//! use teapot::forms::{Form, FormSpec};
+//!
+//! let form = Form::from_spec(FormSpec::from_file("setup.toml")?)?;
+//! ```
+
+use std::{collections::HashSet, path::PathBuf};
+
+use super::{
+    field::{Field, FieldKind},
+    form::{Form, FormLayout},
+    group::Group,
+};
+use crate::error::{Error, Result};
+
+/// A declarative description of a [`Form`].
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default, deny_unknown_fields))]
+pub struct FormSpec {
+    /// Form title.
+    pub title: Option<String>,
+    /// Form description.
+    pub description: Option<String>,
+    /// Form layout.
+    pub layout: Option<FormLayout>,
+    /// Groups (pages) of the form.
+    pub groups: Vec<GroupSpec>,
+}
+
+/// A declarative description of a [`Group`].
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default, deny_unknown_fields))]
+pub struct GroupSpec {
+    /// Group title.
+    pub title: Option<String>,
+    /// Group description.
+    pub description: Option<String>,
+    /// Fields in the group.
+    pub fields: Vec<FieldSpec>,
+}
+
+/// A declarative description of a [`Field`].
+///
+/// Only the options that apply to `kind` may be set; anything else is
+/// reported as an error by [`Form::from_spec`].
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(deny_unknown_fields))]
+pub struct FieldSpec {
+    /// The kind of field.
+    pub kind: FieldKind,
+    /// Result key (optional for notes).
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub key: Option<String>,
+    /// Field title.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub title: Option<String>,
+    /// Field description.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub description: Option<String>,
+    /// Whether a value is required (input, multi-select, file picker).
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub required: Option<bool>,
+    /// Initial value (text for input/select, list for multi-select, bool for confirm).
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub default: Option<DefaultValue>,
+    /// Placeholder text (input).
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub placeholder: Option<String>,
+    /// Mask the typed value (input).
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub hidden: Option<bool>,
+    /// Choices (select, multi-select).
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub options: Option<Vec<String>>,
+    /// Minimum number of selections (multi-select).
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub min: Option<usize>,
+    /// Maximum number of selections (multi-select).
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub max: Option<usize>,
+    /// Body text (note).
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub content: Option<String>,
+    /// Starting directory (file picker).
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub directory: Option<PathBuf>,
+    /// Show dotfiles (file picker).
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub show_hidden: Option<bool>,
+    /// Only allow directories (file picker).
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub dirs_only: Option<bool>,
+    /// Only allow files (file picker).
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub files_only: Option<bool>,
+    /// Allowed file extensions (file picker).
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub extensions: Option<Vec<String>>,
+    /// Visible rows (file picker).
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub height: Option<usize>,
+}
+
+impl FieldSpec {
+    /// Create an empty spec of the given kind.
+    pub fn new(kind: FieldKind) -> Self {
+        Self {
+            kind,
+            key: None,
+            title: None,
+            description: None,
+            required: None,
+            default: None,
+            placeholder: None,
+            hidden: None,
+            options: None,
+            min: None,
+            max: None,
+            content: None,
+            directory: None,
+            show_hidden: None,
+            dirs_only: None,
+            files_only: None,
+            extensions: None,
+            height: None,
+        }
+    }
+}
+
+/// The initial value of a [`FieldSpec`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(untagged))]
+pub enum DefaultValue {
+    /// A boolean (confirm).
+    Bool(bool),
+    /// A single string (input, select).
+    Text(String),
+    /// A list of strings (multi-select).
+    List(Vec<String>),
+}
+
+impl DefaultValue {
+    fn type_name(&self) -> &'static str {
+        match self {
+            DefaultValue::Bool(_) => "a boolean",
+            DefaultValue::Text(_) => "a string",
+            DefaultValue::List(_) => "a list",
+        }
+    }
+}
+
+fn spec_error(path: impl Into<String>, message: impl Into<String>) -> Error {
+    Error::Spec { path: path.into(), message: message.into() }
+}
+
+impl FieldKind {
+    /// The name used for this kind in specs.
+    fn spec_name(self) -> &'static str {
+        match self {
+            FieldKind::Input => "input",
+            FieldKind::Select => "select",
+            FieldKind::MultiSelect => "multi_select",
+            FieldKind::Confirm => "confirm",
+            FieldKind::Note => "note",
+            FieldKind::FilePicker => "file_picker",
+        }
+    }
+
+    /// Spec options that apply to this kind, besides `key`, `title` and `description`.
+    fn spec_options(self) -> &'static [&'static str] {
+        match self {
+            FieldKind::Input => &["required", "default", "placeholder", "hidden"],
+            FieldKind::Select => &["default", "options"],
+            FieldKind::MultiSelect => &["required", "default", "options", "min", "max"],
+            FieldKind::Confirm => &["default"],
+            FieldKind::Note => &["content"],
+            FieldKind::FilePicker => &[
+                "required",
+                "directory",
+                "show_hidden",
+                "dirs_only",
+                "files_only",
+                "extensions",
+                "height",
+            ],
+        }
+    }
+}
+
+impl Form {
+    /// Build a form from a declarative spec.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Spec`] naming the offending location (for example
+    /// `groups[0].fields[2].options`) if the spec is incomplete, uses options
+    /// that don't apply to a field's kind, or is otherwise inconsistent.
+    pub fn from_spec(spec: FormSpec) -> Result<Self> {
+        if spec.groups.is_empty() {
+            return Err(spec_error("groups", "a form needs at least one group"));
+        }
+
+        let mut form = Form::new();
+        if let Some(title) = spec.title {
+            form = form.title(title);
+        }
+        if let Some(description) = spec.description {
+            form = form.description(description);
+        }
+        if let Some(layout) = spec.layout {
+            form = form.layout(layout);
+        }
+
+        let mut keys = HashSet::new();
+        for (g, group_spec) in spec.groups.into_iter().enumerate() {
+            let group_path = format!("groups[{}]", g);
+            if group_spec.fields.is_empty() {
+                return Err(spec_error(
+                    format!("{}.fields", group_path),
+                    "a group needs at least one field",
+                ));
+            }
+
+            let mut group = Group::new();
+            if let Some(title) = group_spec.title {
+                group = group.title(title);
+            }
+            if let Some(description) = group_spec.description {
+                group = group.description(description);
+            }
+
+            for (f, field_spec) in group_spec.fields.into_iter().enumerate() {
+                let path = format!("{}.fields[{}]", group_path, f);
+                let field = build_field(&path, field_spec)?;
+                if !keys.insert(field.key().to_string()) {
+                    return Err(spec_error(
+                        format!("{}.key", path),
+                        format!("duplicate key '{}'", field.key()),
+                    ));
+                }
+                group = group.field(field);
+            }
+
+            form = form.group(group);
+        }
+
+        Ok(form)
+    }
+}
+
+/// Build a single field, validating it against its kind.
+fn build_field(path: &str, spec: FieldSpec) -> Result<Field> {
+    let kind = spec.kind;
+    let at = |option: &str| format!("{}.{}", path, option);
+
+    let set = [
+        ("required", spec.required.is_some()),
+        ("default", spec.default.is_some()),
+        ("placeholder", spec.placeholder.is_some()),
+        ("hidden", spec.hidden.is_some()),
+        ("options", spec.options.is_some()),
+        ("min", spec.min.is_some()),
+        ("max", spec.max.is_some()),
+        ("content", spec.content.is_some()),
+        ("directory", spec.directory.is_some()),
+        ("show_hidden", spec.show_hidden.is_some()),
+        ("dirs_only", spec.dirs_only.is_some()),
+        ("files_only", spec.files_only.is_some()),
+        ("extensions", spec.extensions.is_some()),
+        ("height", spec.height.is_some()),
+    ];
+    if let Some((option, _)) =
+        set.iter().find(|(option, is_set)| *is_set && !kind.spec_options().contains(option))
+    {
+        return Err(spec_error(
+            at(option),
+            format!("'{}' does not apply to {} fields", option, kind.spec_name()),
+        ));
+    }
+
+    let key = match (kind, spec.key) {
+        (_, Some(key)) if key.trim().is_empty() => {
+            return Err(spec_error(at("key"), "key must not be empty"));
+        },
+        (_, Some(key)) => Some(key),
+        (FieldKind::Note, None) => None,
+        (_, None) => {
+            return Err(spec_error(at("key"), format!("{} fields need a key", kind.spec_name())));
+        },
+    };
+    let title = spec.title.unwrap_or_default();
+    let description = spec.description;
+
+    let wrong_default = |expected: &str, found: &DefaultValue| {
+        spec_error(
+            at("default"),
+            format!(
+                "{} fields take {} default, found {}",
+                kind.spec_name(),
+                expected,
+                found.type_name()
+            ),
+        )
+    };
+
+    let field = match kind {
+        FieldKind::Input => {
+            let default_value = match spec.default {
+                None => String::new(),
+                Some(DefaultValue::Text(text)) => text,
+                Some(other) => return Err(wrong_default("a string", &other)),
+            };
+            Field::input()
+                .key(key.unwrap_or_default())
+                .title(title)
+                .maybe_description(description)
+                .placeholder(spec.placeholder.unwrap_or_default())
+                .default_value(default_value)
+                .required(spec.required.unwrap_or(false))
+                .hidden(spec.hidden.unwrap_or(false))
+                .build()
+        },
+        FieldKind::Select => {
+            let options = choice_options(&at("options"), kind, spec.options)?;
+            let default_index = match spec.default {
+                None => None,
+                Some(DefaultValue::Text(text)) => {
+                    Some(options.iter().position(|o| *o == text).ok_or_else(|| {
+                        spec_error(at("default"), format!("'{}' is not one of the options", text))
+                    })?)
+                },
+                Some(other) => return Err(wrong_default("a string", &other)),
+            };
+            Field::select()
+                .key(key.unwrap_or_default())
+                .title(title)
+                .maybe_description(description)
+                .options(options)
+                .maybe_default_index(default_index)
+                .build()
+        },
+        FieldKind::MultiSelect => {
+            let options = choice_options(&at("options"), kind, spec.options)?;
+            let default = match spec.default {
+                None => Vec::new(),
+                Some(DefaultValue::List(list)) => list,
+                Some(other) => return Err(wrong_default("a list", &other)),
+            };
+            if let Some(missing) = default.iter().find(|d| !options.contains(d)) {
+                return Err(spec_error(
+                    at("default"),
+                    format!("'{}' is not one of the options", missing),
+                ));
+            }
+
+            let min = match (spec.min, spec.required) {
+                (Some(min), _) => Some(min),
+                (None, Some(true)) => Some(1),
+                (None, _) => None,
+            };
+            if let Some(min) = min
+                && min > options.len()
+            {
+                return Err(spec_error(
+                    at("min"),
+                    format!("min is {} but there are only {} options", min, options.len()),
+                ));
+            }
+            if let (Some(min), Some(max)) = (min, spec.max)
+                && min > max
+            {
+                return Err(spec_error(
+                    at("max"),
+                    format!("max ({}) is smaller than min ({})", max, min),
+                ));
+            }
+            if let Some(max) = spec.max
+                && default.len() > max
+            {
+                return Err(spec_error(
+                    at("default"),
+                    format!("{} options are selected by default but max is {}", default.len(), max),
+                ));
+            }
+
+            Field::multi_select()
+                .key(key.unwrap_or_default())
+                .title(title)
+                .maybe_description(description)
+                .options(options)
+                .default(default)
+                .maybe_min(min)
+                .maybe_max(spec.max)
+                .build()
+        },
+        FieldKind::Confirm => {
+            let default = match spec.default {
+                None => false,
+                Some(DefaultValue::Bool(value)) => value,
+                Some(other) => return Err(wrong_default("a boolean", &other)),
+            };
+            Field::confirm()
+                .key(key.unwrap_or_default())
+                .title(title)
+                .maybe_description(description)
+                .default(default)
+                .build()
+        },
+        FieldKind::Note => {
+            let content = spec
+                .content
+                .ok_or_else(|| spec_error(at("content"), "note fields need content"))?;
+            Field::note()
+                .content(content)
+                .title(title)
+                .maybe_description(description)
+                .maybe_key(key)
+                .build()
+        },
+        FieldKind::FilePicker => {
+            let dirs_only = spec.dirs_only.unwrap_or(false);
+            let files_only = spec.files_only.unwrap_or(false);
+            if dirs_only && files_only {
+                return Err(spec_error(
+                    at("files_only"),
+                    "'dirs_only' and 'files_only' cannot both be set",
+                ));
+            }
+            if spec.height == Some(0) {
+                return Err(spec_error(at("height"), "height must be at least 1"));
+            }
+            let extensions: Vec<String> = spec
+                .extensions
+                .unwrap_or_default()
+                .into_iter()
+                .map(|ext| ext.trim_start_matches('.').to_string())
+                .collect();
+            if dirs_only && !extensions.is_empty() {
+                return Err(spec_error(
+                    at("extensions"),
+                    "'extensions' cannot be combined with 'dirs_only'",
+                ));
+            }
+
+            Field::file_picker()
+                .key(key.unwrap_or_default())
+                .title(title)
+                .maybe_description(description)
+                .maybe_directory(spec.directory)
+                .show_hidden(spec.show_hidden.unwrap_or(false))
+                .dirs_only(dirs_only)
+                .files_only(files_only)
+                .extensions(extensions)
+                .maybe_height(spec.height)
+                .required(spec.required.unwrap_or(false))
+                .build()
+        },
+    };
+
+    Ok(field)
+}
+
+/// Validate the options of a select or multi-select field.
+fn choice_options(
+    path: &str,
+    kind: FieldKind,
+    options: Option<Vec<String>>,
+) -> Result<Vec<String>> {
+    let options = options.unwrap_or_default();
+    if options.is_empty() {
+        return Err(spec_error(
+            path,
+            format!("{} fields need at least one option", kind.spec_name()),
+        ));
+    }
+
+    let mut seen = HashSet::new();
+    if let Some(duplicate) = options.iter().find(|o| !seen.insert(o.as_str())) {
+        return Err(spec_error(path, format!("duplicate option '{}'", duplicate)));
+    }
+
+    Ok(options)
+}
+
+// ============================================================================
+// Loading
+// ============================================================================
+
+#[cfg(any(feature = "json", feature = "toml", feature = "yaml"))]
+fn parse_error<E: std::fmt::Display>(err: serde_path_to_error::Error<E>) -> Error {
+    let path = err.path().to_string();
+    spec_error(path, err.into_inner().to_string())
+}
+
+impl FormSpec {
+    /// Parse a spec from a JSON string.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Spec`] with the path of the offending value if the
+    /// JSON is malformed or doesn't match the spec schema.
+    #[cfg(feature = "json")]
+    pub fn from_json(source: &str) -> Result<Self> {
+        let mut de = serde_json::Deserializer::from_str(source);
+        serde_path_to_error::deserialize(&mut de).map_err(parse_error)
+    }
+
+    /// Parse a spec from a TOML string.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Spec`] with the path of the offending value if the
+    /// TOML is malformed or doesn't match the spec schema.
+    #[cfg(feature = "toml")]
+    pub fn from_toml(source: &str) -> Result<Self> {
+        let de = toml::de::Deserializer::parse(source)
+            .map_err(|err| spec_error("", err.to_string().trim_end()))?;
+        serde_path_to_error::deserialize(de).map_err(parse_error)
+    }
+
+    /// Parse a spec from a YAML string.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Spec`] with the path of the offending value if the
+    /// YAML is malformed or doesn't match the spec schema.
+    #[cfg(feature = "yaml")]
+    pub fn from_yaml(source: &str) -> Result<Self> {
+        let de = serde_norway::Deserializer::from_str(source);
+        serde_path_to_error::deserialize(de).map_err(parse_error)
+    }
+
+    /// Load a spec from a file, choosing the format by extension.
+    ///
+    /// Supports `.json`, `.toml`, `.yaml` and `.yml`, subject to the matching
+    /// crate feature being enabled.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Io`] if the file can't be read, or [`Error::Spec`] if
+    /// the format is unsupported or the contents are invalid.
+    #[cfg(feature = "serde")]
+    pub fn from_file(path: impl AsRef<std::path::Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let extension =
+            path.extension().and_then(|e| e.to_str()).unwrap_or_default().to_lowercase();
+
+        match extension.as_str() {
+            #[cfg(feature = "json")]
+            "json" => Self::from_json(&std::fs::read_to_string(path)?),
+            #[cfg(feature = "toml")]
+            "toml" => Self::from_toml(&std::fs::read_to_string(path)?),
+            #[cfg(feature = "yaml")]
+            "yaml" | "yml" => Self::from_yaml(&std::fs::read_to_string(path)?),
+            _ => Err(spec_error(
+                "",
+                format!("unsupported spec format '{}' for {}", extension, path.display()),
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::forms::FieldValue;
+
+    fn field(kind: FieldKind, key: &str) -> FieldSpec {
+        FieldSpec { key: Some(key.to_string()), ..FieldSpec::new(kind) }
+    }
+
+    fn form(fields: Vec<FieldSpec>) -> FormSpec {
+        FormSpec { groups: vec![GroupSpec { fields, ..Default::default() }], ..Default::default() }
+    }
+
+    fn error_path(result: Result<Form>) -> String {
+        match result {
+            Err(Error::Spec { path, .. }) => path,
+            other => panic!("expected spec error, got {:?}", other.map(|_| ())),
+        }
+    }
+
+    #[test]
+    fn test_from_spec_all_kinds() {
+        let spec = form(vec![
+            FieldSpec {
+                default: Some(DefaultValue::Text("Alice".into())),
+                ..field(FieldKind::Input, "name")
+            },
+            FieldSpec {
+                options: Some(vec!["Red".into(), "Green".into()]),
+                default: Some(DefaultValue::Text("Green".into())),
+                ..field(FieldKind::Select, "color")
+            },
+            FieldSpec {
+                options: Some(vec!["a".into(), "b".into(), "c".into()]),
+                default: Some(DefaultValue::List(vec!["b".into()])),
+                min: Some(1),
+                ..field(FieldKind::MultiSelect, "letters")
+            },
+            FieldSpec {
+                default: Some(DefaultValue::Bool(true)),
+                ..field(FieldKind::Confirm, "ok")
+            },
+            FieldSpec { content: Some("Hello".into()), ..FieldSpec::new(FieldKind::Note) },
+            FieldSpec {
+                extensions: Some(vec![".toml".into()]),
+                files_only: Some(true),
+                ..field(FieldKind::FilePicker, "config")
+            },
+        ]);
+
+        let results = Form::from_spec(spec).unwrap().results();
+        assert_eq!(results.get_string("name"), Some("Alice"));
+        assert_eq!(results.get_string("color"), Some("Green"));
+        assert_eq!(results.get_string_list("letters"), Some(&["b".to_string()][..]));
+        assert_eq!(results.get_bool("ok"), Some(true));
+        assert!(matches!(results.get("config"), Some(FieldValue::None)));
+    }
+
+    #[test]
+    fn test_from_spec_errors_report_path() {
+        assert_eq!(error_path(Form::from_spec(FormSpec::default())), "groups");
+        assert_eq!(
+            error_path(Form::from_spec(form(vec![FieldSpec::new(FieldKind::Input)]))),
+            "groups[0].fields[0].key"
+        );
+        assert_eq!(
+            error_path(Form::from_spec(form(vec![field(FieldKind::Select, "color")]))),
+            "groups[0].fields[0].options"
+        );
+        assert_eq!(
+            error_path(Form::from_spec(form(vec![
+                field(FieldKind::Confirm, "a"),
+                FieldSpec { placeholder: Some("x".into()), ..field(FieldKind::Confirm, "b") },
+            ]))),
+            "groups[0].fields[1].placeholder"
+        );
+        assert_eq!(
+            error_path(Form::from_spec(form(vec![
+                field(FieldKind::Confirm, "a"),
+                field(FieldKind::Confirm, "a"),
+            ]))),
+            "groups[0].fields[1].key"
+        );
+        assert_eq!(
+            error_path(Form::from_spec(form(vec![FieldSpec {
+                options: Some(vec!["a".into()]),
+                min: Some(2),
+                ..field(FieldKind::MultiSelect, "m")
+            }]))),
+            "groups[0].fields[0].min"
+        );
+    }
+
+    #[cfg(feature = "toml")]
+    #[test]
+    fn test_from_toml() {
+        let spec = FormSpec::from_toml(
+            r#"
+            title = "Setup"
+            layout = "stack"
+
+            [[groups]]
+            [[groups.fields]]
+            kind = "multi_select"
+            key = "langs"
+            options = ["Rust", "Go"]
+            default = ["Rust"]
+            "#,
+        )
+        .unwrap();
+        assert_eq!(spec.layout, Some(FormLayout::Stack));
+        let results = Form::from_spec(spec).unwrap().results();
+        assert_eq!(results.get_string_list("langs"), Some(&["Rust".to_string()][..]));
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn test_from_json_reports_path() {
+        let err = FormSpec::from_json(
+            r#"{"groups": [{"fields": [{"kind": "input", "key": "a", "colour": "red"}]}]}"#,
+        )
+        .unwrap_err();
+        assert!(
+            matches!(err, Error::Spec { ref path, .. } if path == "groups[0].fields[0].colour")
+        );
+
+        let spec = FormSpec::from_json(r#"{"layout": {"columns": 2}, "groups": []}"#).unwrap();
+        assert_eq!(spec.layout, Some(FormLayout::Columns(2)));
+
+        // Names from before the switch to snake_case still load.
+        let spec = FormSpec::from_json(
+            r#"{"layout": {"Columns": 3}, "groups": [{"fields": [{"kind": "MultiSelect", "key": "a"}]}]}"#,
+        )
+        .unwrap();
+        assert_eq!(spec.layout, Some(FormLayout::Columns(3)));
+        assert_eq!(spec.groups[0].fields[0].kind, FieldKind::MultiSelect);
+    }
+
+    #[cfg(feature = "yaml")]
+    #[test]
+    fn test_from_yaml() {
+        let spec = FormSpec::from_yaml(
+            "groups:\n  - fields:\n      - kind: confirm\n        key: agree\n        default: true\n",
+        )
+        .unwrap();
+        assert_eq!(Form::from_spec(spec).unwrap().results().get_bool("agree"), Some(true));
+    }
+}
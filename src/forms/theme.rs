@@ -0,0 +1,130 @@
+//! Form themes.
+//!
+//! A [`FormTheme`] sets the colors a form and all of its fields render with.
+//! Apply one with [`Form::theme`](super::Form::theme).
+//!
+//! # Example
+//!
+//! ```
+//! use teapot::forms::{Field, Form, FormTheme, Group};
+//!
+//! let form = Form::new()
+//!     .theme(FormTheme::dracula())
+//!     .group(Group::new().field(Field::input().key("name").title("Name").build()));
+//! ```
+
+use crate::style::Color;
+
+/// Colors used to render a form and its fields.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FormTheme {
+    /// Form and group titles.
+    pub title: Color,
+    /// Form, group and field descriptions, and note content.
+    pub description: Color,
+    /// The focused field's title and input text.
+    pub focused: Color,
+    /// Inactive options and labels.
+    pub blurred: Color,
+    /// Text cursor and selection indicators.
+    pub cursor: Color,
+    /// Highlighted and checked options.
+    pub selected: Color,
+    /// Validation errors and warnings.
+    pub error: Color,
+    /// Hints, progress and key help.
+    pub help: Color,
+    /// The completion message.
+    pub success: Color,
+}
+
+impl Default for FormTheme {
+    fn default() -> Self {
+        Self {
+            title: Color::PRIMARY,
+            description: Color::MUTED,
+            focused: Color::Default,
+            blurred: Color::MUTED,
+            cursor: Color::PRIMARY,
+            selected: Color::SELECTION,
+            error: Color::ERROR,
+            help: Color::MUTED,
+            success: Color::SUCCESS,
+        }
+    }
+}
+
+impl FormTheme {
+    /// The default theme, following the application-wide [`Theme`](crate::style::Theme).
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Indigo and fuchsia, after Charm's house style.
+    pub fn charm() -> Self {
+        let indigo = Color::adaptive(Color::Rgb(0x5A, 0x56, 0xE0), Color::Rgb(0x75, 0x71, 0xF9));
+        let fuchsia = Color::Rgb(0xF7, 0x80, 0xE2);
+        let green = Color::adaptive(Color::Rgb(0x02, 0xBA, 0x84), Color::Rgb(0x02, 0xBF, 0x87));
+        let red = Color::adaptive(Color::Rgb(0xFF, 0x46, 0x72), Color::Rgb(0xED, 0x56, 0x7A));
+        let gray = Color::adaptive(Color::Ansi256(243), Color::Ansi256(243));
+
+        Self {
+            title: indigo,
+            description: gray.clone(),
+            focused: Color::adaptive(Color::Ansi256(235), Color::Ansi256(252)),
+            blurred: gray.clone(),
+            cursor: fuchsia,
+            selected: green.clone(),
+            error: red,
+            help: gray,
+            success: green,
+        }
+    }
+
+    /// The Dracula palette.
+    pub fn dracula() -> Self {
+        let comment = Color::Rgb(0x62, 0x72, 0xA4);
+        let green = Color::Rgb(0x50, 0xFA, 0x7B);
+
+        Self {
+            title: Color::Rgb(0xBD, 0x93, 0xF9),
+            description: comment.clone(),
+            focused: Color::Rgb(0xF8, 0xF8, 0xF2),
+            blurred: comment.clone(),
+            cursor: Color::Rgb(0xF1, 0xFA, 0x8C),
+            selected: green.clone(),
+            error: Color::Rgb(0xFF, 0x55, 0x55),
+            help: comment,
+            success: green,
+        }
+    }
+
+    /// ANSI colors only, so the terminal's own base16 scheme shows through.
+    pub fn base16() -> Self {
+        Self {
+            title: Color::Cyan,
+            description: Color::BrightBlack,
+            focused: Color::Default,
+            blurred: Color::BrightBlack,
+            cursor: Color::Yellow,
+            selected: Color::Green,
+            error: Color::BrightRed,
+            help: Color::BrightBlack,
+            success: Color::Green,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_presets_differ() {
+        assert_eq!(FormTheme::new(), FormTheme::default());
+        assert_ne!(FormTheme::charm(), FormTheme::default());
+        assert_ne!(FormTheme::dracula(), FormTheme::charm());
+        assert_ne!(FormTheme::base16(), FormTheme::default());
+    }
+}
@@ -0,0 +1,354 @@
+//! Z-ordered layer compositing.
+//!
+//! A [`Compositor`] stacks rendered views at absolute positions: a base view,
+//! then dropdowns, tooltips, toasts and modals on top. Layers are drawn on a
+//! cell grid, so escape sequences are carried per cell and wide characters cut
+//! by a layer edge or the screen edge are replaced with spaces instead of
+//! leaving half a glyph behind.
+
+use super::Rect;
+use crate::style::{SgrState, glyphs};
+
+/// A view placed at a position in a [`Compositor`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Layer {
+    content: String,
+    x: i32,
+    y: i32,
+    z: i32,
+    centered: bool,
+    dim_below: bool,
+}
+
+impl Layer {
+    /// Create a layer at the top-left corner.
+    pub fn new(content: impl Into<String>) -> Self {
+        Self { content: content.into(), ..Default::default() }
+    }
+
+    /// Place the layer's top-left corner. Negative values crop from the left or top.
+    pub fn position(mut self, x: i32, y: i32) -> Self {
+        self.x = x;
+        self.y = y;
+        self.centered = false;
+        self
+    }
+
+    /// Place the layer at the top-left corner of an area.
+    pub fn area(self, area: Rect) -> Self {
+        self.position(area.x as i32, area.y as i32)
+    }
+
+    /// Center the layer on the screen.
+    pub fn centered(mut self) -> Self {
+        self.centered = true;
+        self
+    }
+
+    /// Set the stacking order. Higher values are drawn on top; equal values
+    /// keep the order they were added in.
+    pub fn z(mut self, z: i32) -> Self {
+        self.z = z;
+        self
+    }
+
+    /// Dim everything drawn below this layer.
+    pub fn dim_below(mut self, dim: bool) -> Self {
+        self.dim_below = dim;
+        self
+    }
+
+    fn origin(&self, width: u16, height: u16) -> (i32, i32) {
+        if !self.centered {
+            return (self.x, self.y);
+        }
+        let (layer_width, layer_height) = crate::style::size(&self.content);
+        ((width as i32 - layer_width as i32) / 2, (height as i32 - layer_height as i32) / 2)
+    }
+}
+
+/// Stacks [`Layer`]s into a single screen.
+///
+/// # Example
+///
+/// ```rust
+/// use teapot::layout::{Compositor, Layer};
+///
+/// let screen = Compositor::new(20, 5)
+///     .layer(Layer::new("background text"))
+///     .layer(Layer::new("┌──┐\n│hi│\n└──┘").centered().z(10).dim_below(true))
+///     .render();
+///
+/// assert_eq!(screen.lines().count(), 5);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Compositor {
+    width: u16,
+    height: u16,
+    layers: Vec<Layer>,
+}
+
+impl Compositor {
+    /// Create a compositor for a `width` x `height` screen.
+    pub fn new(width: u16, height: u16) -> Self {
+        Self { width, height, layers: Vec::new() }
+    }
+
+    /// Add a layer.
+    pub fn layer(mut self, layer: Layer) -> Self {
+        self.layers.push(layer);
+        self
+    }
+
+    /// Add a layer in place.
+    pub fn push(&mut self, layer: Layer) {
+        self.layers.push(layer);
+    }
+
+    /// Draw all layers, bottom to top, into a string of exactly `height`
+    /// lines of `width` columns.
+    pub fn render(&self) -> String {
+        let mut grid = Grid::new(self.width as usize, self.height as usize);
+
+        let mut layers: Vec<&Layer> = self.layers.iter().collect();
+        layers.sort_by_key(|layer| layer.z);
+
+        for layer in layers {
+            if layer.dim_below {
+                grid.dim();
+            }
+            let (x, y) = layer.origin(self.width, self.height);
+            for (row, line) in layer.content.lines().enumerate() {
+                grid.draw_line(x, y + row as i32, line);
+            }
+        }
+
+        grid.render()
+    }
+}
+
+/// One terminal cell.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Cell {
+    /// The grapheme drawn here; empty for the second half of a wide character.
+    symbol: String,
+    /// Display width: 1, 2, or 0 for a wide character's continuation.
+    width: usize,
+    /// SGR sequences active for this cell.
+    style: String,
+    /// OSC 8 hyperlink opener active for this cell.
+    link: String,
+}
+
+impl Cell {
+    fn blank(style: String, link: String) -> Self {
+        Self { symbol: " ".to_string(), width: 1, style, link }
+    }
+}
+
+struct Grid {
+    width: usize,
+    rows: Vec<Vec<Cell>>,
+}
+
+impl Grid {
+    fn new(width: usize, height: usize) -> Self {
+        let blank = Cell::blank(String::new(), String::new());
+        Self { width, rows: vec![vec![blank; width]; height] }
+    }
+
+    fn dim(&mut self) {
+        for cell in self.rows.iter_mut().flatten() {
+            cell.style.push_str("\x1b[2m");
+        }
+    }
+
+    fn draw_line(&mut self, x: i32, y: i32, line: &str) {
+        let Ok(y) = usize::try_from(y) else {
+            return;
+        };
+        if y >= self.rows.len() {
+            return;
+        }
+
+        let mut column = x;
+        for cell in parse_cells(line) {
+            let cell_width = cell.width as i32;
+            let fits = column >= 0 && column + cell_width <= self.width as i32;
+
+            if fits {
+                self.put(y, column as usize, cell);
+            } else {
+                // A wide character cut by an edge leaves a space in each
+                // visible column it would have covered.
+                for offset in 0..cell_width {
+                    let col = column + offset;
+                    if col >= 0 && (col as usize) < self.width {
+                        self.put(
+                            y,
+                            col as usize,
+                            Cell::blank(cell.style.clone(), cell.link.clone()),
+                        );
+                    }
+                }
+            }
+            column += cell_width;
+        }
+    }
+
+    fn put(&mut self, y: usize, x: usize, cell: Cell) {
+        let row = &mut self.rows[y];
+        let end = x + cell.width;
+
+        // Clear what's left of wide characters we partially cover.
+        if row[x].width == 0 && x > 0 {
+            let covered = &row[x - 1];
+            row[x - 1] = Cell::blank(covered.style.clone(), covered.link.clone());
+        }
+        if let Some(last) = row.get(end - 1)
+            && last.width == 2
+            && let Some(next) = row.get(end)
+        {
+            row[end] = Cell::blank(next.style.clone(), next.link.clone());
+        }
+
+        let wide = cell.width == 2;
+        let continuation = Cell {
+            symbol: String::new(),
+            width: 0,
+            style: cell.style.clone(),
+            link: cell.link.clone(),
+        };
+        row[x] = cell;
+        if wide {
+            row[x + 1] = continuation;
+        }
+    }
+
+    fn render(&self) -> String {
+        let lines: Vec<String> = self
+            .rows
+            .iter()
+            .map(|row| {
+                let mut line = String::new();
+                let mut style = "";
+                let mut link = "";
+
+                for cell in row.iter().filter(|cell| cell.width > 0) {
+                    if cell.style != style {
+                        if !style.is_empty() {
+                            line.push_str("\x1b[0m");
+                        }
+                        line.push_str(&cell.style);
+                        style = &cell.style;
+                    }
+                    if cell.link != link {
+                        if !link.is_empty() {
+                            line.push_str("\x1b]8;;\x07");
+                        }
+                        line.push_str(&cell.link);
+                        link = &cell.link;
+                    }
+                    line.push_str(&cell.symbol);
+                }
+
+                if !link.is_empty() {
+                    line.push_str("\x1b]8;;\x07");
+                }
+                if !style.is_empty() {
+                    line.push_str("\x1b[0m");
+                }
+                line
+            })
+            .collect();
+
+        lines.join("\n")
+    }
+}
+
+/// Split a line into cells, tracking SGR and OSC 8 state per cell.
+fn parse_cells(line: &str) -> Vec<Cell> {
+    let mut state = SgrState::default();
+    glyphs(line)
+        .0
+        .into_iter()
+        .filter(|glyph| glyph.width > 0)
+        .map(|glyph| {
+            for escape in &glyph.escapes {
+                state.apply(escape);
+            }
+            Cell {
+                symbol: glyph.text,
+                width: glyph.width,
+                style: state.style.clone(),
+                link: state.link.clone(),
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::style::{strip_ansi, width};
+
+    #[test]
+    fn test_layers_stack_by_z() {
+        let screen = Compositor::new(6, 2)
+            .layer(Layer::new("top").z(5))
+            .layer(Layer::new("aaaaaa\nbbbbbb"))
+            .layer(Layer::new("X").position(4, 1).z(1))
+            .render();
+
+        assert_eq!(screen, "topaaa\nbbbbXb");
+    }
+
+    #[test]
+    fn test_centered_and_cropped() {
+        let screen = Compositor::new(5, 3).layer(Layer::new("#").centered()).render();
+        assert_eq!(screen, "     \n  #  \n     ");
+
+        let screen = Compositor::new(4, 1).layer(Layer::new("abcdef").position(-2, 0)).render();
+        assert_eq!(screen, "cdef");
+    }
+
+    #[test]
+    fn test_wide_characters_at_edges() {
+        // A wide character cut by the right edge becomes a space.
+        let screen = Compositor::new(4, 1).layer(Layer::new("a日本")).render();
+        assert_eq!(screen, "a日 ");
+
+        // A layer covering half of a wide character blanks the other half.
+        let screen = Compositor::new(6, 1)
+            .layer(Layer::new("日本語"))
+            .layer(Layer::new("x").position(1, 0))
+            .render();
+        assert_eq!(screen, " x本語");
+        assert_eq!(width(&screen), 6);
+
+        // A wide character starting off-screen on the left.
+        let screen = Compositor::new(3, 1).layer(Layer::new("日ab").position(-1, 0)).render();
+        assert_eq!(screen, " ab");
+    }
+
+    #[test]
+    fn test_styles_are_kept_per_cell() {
+        let screen = Compositor::new(6, 1)
+            .layer(Layer::new("\x1b[31mredred\x1b[0m"))
+            .layer(Layer::new("\x1b[1mB\x1b[0m").position(2, 0))
+            .render();
+
+        assert_eq!(strip_ansi(&screen), "reBred");
+        assert_eq!(screen, "\x1b[31mre\x1b[0m\x1b[1mB\x1b[0m\x1b[31mred\x1b[0m");
+    }
+
+    #[test]
+    fn test_dim_below() {
+        let screen = Compositor::new(3, 1)
+            .layer(Layer::new("abc"))
+            .layer(Layer::new("X").position(1, 0).dim_below(true))
+            .render();
+
+        assert_eq!(screen, "\x1b[2ma\x1b[0mX\x1b[2mc\x1b[0m");
+    }
+}
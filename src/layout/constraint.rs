@@ -0,0 +1,139 @@
+//! Size constraints and the solver that resolves them.
+
+/// How much of its parent's main axis a child takes.
+///
+/// Fixed and percentage sizes are allocated first; whatever space remains is
+/// shared among [`Min`](Constraint::Min), [`Max`](Constraint::Max) and
+/// [`Flex`](Constraint::Flex) children in proportion to their weight.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Constraint {
+    /// Exactly this many cells.
+    Length(u16),
+    /// A percentage of the available space.
+    Percentage(u16),
+    /// At least this many cells, growing like `Flex(1)`.
+    Min(u16),
+    /// At most this many cells, growing like `Flex(1)`.
+    Max(u16),
+    /// A share of the remaining space, weighted by the value.
+    Flex(u16),
+}
+
+impl Default for Constraint {
+    fn default() -> Self {
+        Constraint::Flex(1)
+    }
+}
+
+impl Constraint {
+    fn base(&self, total: u16) -> u16 {
+        match *self {
+            Constraint::Length(n) | Constraint::Min(n) => n,
+            Constraint::Percentage(p) => (total as u32 * p.min(100) as u32 / 100) as u16,
+            Constraint::Max(_) | Constraint::Flex(_) => 0,
+        }
+    }
+
+    fn weight(&self) -> u16 {
+        match *self {
+            Constraint::Length(_) | Constraint::Percentage(_) => 0,
+            Constraint::Min(_) | Constraint::Max(_) => 1,
+            Constraint::Flex(weight) => weight,
+        }
+    }
+
+    fn cap(&self) -> u16 {
+        match *self {
+            Constraint::Max(n) => n,
+            _ => u16::MAX,
+        }
+    }
+}
+
+/// Resolve constraints to sizes along an axis of `total` cells.
+///
+/// Base sizes that don't fit are cut from the end. The sizes never add up to
+/// more than `total`.
+pub fn solve(constraints: &[Constraint], total: u16) -> Vec<u16> {
+    let mut remaining = total;
+    let mut sizes: Vec<u16> = constraints
+        .iter()
+        .map(|c| {
+            let size = c.base(total).min(remaining);
+            remaining -= size;
+            size
+        })
+        .collect();
+
+    // Share the leftover space by weight, re-sharing whatever capped
+    // children can't take.
+    let mut growing: Vec<usize> = (0..constraints.len())
+        .filter(|&i| constraints[i].weight() > 0 && sizes[i] < constraints[i].cap())
+        .collect();
+
+    while remaining > 0 && !growing.is_empty() {
+        let weights: u32 = growing.iter().map(|&i| constraints[i].weight() as u32).sum();
+        let pool = remaining;
+
+        for &i in &growing {
+            let share = (pool as u32 * constraints[i].weight() as u32 / weights) as u16;
+            let grow = share.min(constraints[i].cap() - sizes[i]);
+            sizes[i] += grow;
+            remaining -= grow;
+        }
+
+        // Rounding leftovers go one cell at a time, first come first served.
+        for &i in &growing {
+            if remaining == 0 {
+                break;
+            }
+            if sizes[i] < constraints[i].cap() {
+                sizes[i] += 1;
+                remaining -= 1;
+            }
+        }
+
+        let before = growing.len();
+        growing.retain(|&i| sizes[i] < constraints[i].cap());
+        if growing.len() == before && remaining == pool {
+            break;
+        }
+    }
+
+    sizes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fixed_and_flex() {
+        let sizes = solve(&[Constraint::Length(10), Constraint::Flex(1), Constraint::Flex(3)], 50);
+        assert_eq!(sizes, vec![10, 10, 30]);
+    }
+
+    #[test]
+    fn test_percentage_and_rounding() {
+        let sizes =
+            solve(&[Constraint::Percentage(25), Constraint::Flex(1), Constraint::Flex(1)], 11);
+        assert_eq!(sizes, vec![2, 5, 4]);
+        assert_eq!(sizes.iter().sum::<u16>(), 11);
+    }
+
+    #[test]
+    fn test_min_max() {
+        let sizes = solve(&[Constraint::Max(5), Constraint::Min(3)], 20);
+        assert_eq!(sizes, vec![5, 15]);
+
+        let sizes = solve(&[Constraint::Min(8), Constraint::Min(8)], 10);
+        assert_eq!(sizes, vec![8, 2]);
+    }
+
+    #[test]
+    fn test_overflow_cuts_from_end() {
+        let sizes = solve(&[Constraint::Length(6), Constraint::Length(6), Constraint::Flex(1)], 8);
+        assert_eq!(sizes, vec![6, 2, 0]);
+    }
+}
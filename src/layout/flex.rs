@@ -0,0 +1,308 @@
+//! Row and column layouts.
+
+use super::{Constraint, Rect, constraint::solve};
+use crate::style::{Spacing, crop, slice};
+
+/// The main axis of a [`Layout`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Direction {
+    /// Children side by side, left to right.
+    Row,
+    /// Children stacked, top to bottom.
+    #[default]
+    Column,
+}
+
+/// Something that can be drawn into a [`Rect`].
+///
+/// Implemented for strings, nested [`Layout`]s and closures taking the area,
+/// so a child can adapt its content to the space it gets.
+pub trait Render {
+    /// Render content for `area`.
+    ///
+    /// The output doesn't need to fit exactly; the layout crops and pads it.
+    fn render(&self, area: Rect) -> String;
+}
+
+impl Render for String {
+    fn render(&self, _area: Rect) -> String {
+        self.clone()
+    }
+}
+
+impl<F: Fn(Rect) -> String> Render for F {
+    fn render(&self, area: Rect) -> String {
+        self(area)
+    }
+}
+
+impl Render for &str {
+    fn render(&self, _area: Rect) -> String {
+        self.to_string()
+    }
+}
+
+struct Child<'a> {
+    constraint: Constraint,
+    content: Box<dyn Render + 'a>,
+}
+
+/// A row or column of children sized by [`Constraint`]s.
+///
+/// # Example
+///
+/// ```rust
+/// use teapot::layout::{Constraint, Layout, Rect};
+///
+/// let layout = Layout::column()
+///     .child(Constraint::Length(1), "Header")
+///     .child(
+///         Constraint::Flex(1),
+///         Layout::row()
+///             .gap(1)
+///             .child(Constraint::Percentage(30), "Sidebar")
+///             .child(Constraint::Flex(1), |area: Rect| format!("{}x{}", area.width, area.height)),
+///     )
+///     .child(Constraint::Length(1), "Footer");
+///
+/// let areas = layout.split(Rect::from_size(80, 24));
+/// assert_eq!(areas[1], Rect::new(0, 1, 80, 22));
+///
+/// let screen = layout.render(80, 24);
+/// assert_eq!(screen.lines().count(), 24);
+/// ```
+pub struct Layout<'a> {
+    direction: Direction,
+    gap: u16,
+    padding: Spacing,
+    children: Vec<Child<'a>>,
+}
+
+impl std::fmt::Debug for Layout<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Layout")
+            .field("direction", &self.direction)
+            .field("gap", &self.gap)
+            .field("padding", &self.padding)
+            .field("constraints", &self.constraints())
+            .finish()
+    }
+}
+
+impl Default for Layout<'_> {
+    fn default() -> Self {
+        Self::new(Direction::default())
+    }
+}
+
+impl<'a> Layout<'a> {
+    /// Create an empty layout along `direction`.
+    pub fn new(direction: Direction) -> Self {
+        Self { direction, gap: 0, padding: Spacing::default(), children: Vec::new() }
+    }
+
+    /// Create a layout that places children side by side.
+    pub fn row() -> Self {
+        Self::new(Direction::Row)
+    }
+
+    /// Create a layout that stacks children.
+    pub fn column() -> Self {
+        Self::new(Direction::Column)
+    }
+
+    /// Set the number of blank cells between children.
+    pub fn gap(mut self, gap: u16) -> Self {
+        self.gap = gap;
+        self
+    }
+
+    /// Set padding with CSS-style shorthand.
+    pub fn padding(mut self, values: &[usize]) -> Self {
+        self.padding = Spacing::from_values(values);
+        self
+    }
+
+    /// Add a child.
+    pub fn child(mut self, constraint: Constraint, content: impl Render + 'a) -> Self {
+        self.children.push(Child { constraint, content: Box::new(content) });
+        self
+    }
+
+    /// Get the direction.
+    pub fn direction(&self) -> Direction {
+        self.direction
+    }
+
+    /// Get the child constraints.
+    pub fn constraints(&self) -> Vec<Constraint> {
+        self.children.iter().map(|child| child.constraint).collect()
+    }
+
+    /// Resolve each child's area within `area`.
+    pub fn split(&self, area: Rect) -> Vec<Rect> {
+        let inner = area.inner(self.padding);
+        let (main, cross) = match self.direction {
+            Direction::Row => (inner.width, inner.height),
+            Direction::Column => (inner.height, inner.width),
+        };
+
+        let gaps = self.gap.saturating_mul(self.children.len().saturating_sub(1) as u16);
+        let sizes = solve(&self.constraints(), main.saturating_sub(gaps));
+
+        let mut offset = 0u16;
+        sizes
+            .into_iter()
+            .map(|size| {
+                let rect = match self.direction {
+                    Direction::Row => Rect::new(inner.x + offset, inner.y, size, cross),
+                    Direction::Column => Rect::new(inner.x, inner.y + offset, cross, size),
+                };
+                offset = offset.saturating_add(size).saturating_add(self.gap).min(main);
+                rect
+            })
+            .collect()
+    }
+
+    /// Render the layout to fill a `width` x `height` screen.
+    pub fn render(&self, width: u16, height: u16) -> String {
+        Render::render(self, Rect::from_size(width, height))
+    }
+}
+
+impl Render for Layout<'_> {
+    fn render(&self, area: Rect) -> String {
+        let mut canvas = vec![" ".repeat(area.width as usize); area.height as usize];
+
+        for (child, rect) in self.children.iter().zip(self.split(area)) {
+            if rect.is_empty() {
+                continue;
+            }
+            let content = fit(&child.content.render(rect), rect);
+            let column = (rect.x - area.x) as usize;
+
+            for (row, line) in (rect.y..rect.bottom()).zip(content.lines()) {
+                let target = &mut canvas[(row - area.y) as usize];
+                *target = splice(target, column, rect.width as usize, line);
+            }
+        }
+
+        canvas.join("\n")
+    }
+}
+
+/// Crop or pad content to exactly fill `area`, keeping escape sequences.
+///
+/// Useful in [`Model::view_in`](crate::Model::view_in) implementations that
+/// must not spill outside the area they were given.
+pub fn fit(content: &str, area: Rect) -> String {
+    let mut lines = content.lines();
+    (0..area.height)
+        .map(|_| fit_line(lines.next().unwrap_or(""), area.width as usize))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Crop content to fit inside `area` without padding it, keeping escape
+/// sequences.
+///
+/// Unlike [`fit`], short content stays short, so an inline program only
+/// draws the lines it has.
+pub fn clip(content: &str, area: Rect) -> String {
+    content
+        .lines()
+        .take(area.height as usize)
+        .map(|line| crop(line, area.width as usize))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Crop or pad a line to exactly `width` columns, keeping escape sequences.
+fn fit_line(line: &str, width: usize) -> String {
+    let cropped = crop(line, width);
+    let used = crate::style::width(&cropped);
+    format!("{}{}", cropped, " ".repeat(width.saturating_sub(used)))
+}
+
+/// Replace `width` columns of a canvas line starting at `column`.
+///
+/// Styles and links open on either side are closed and reopened around the
+/// content, so children can't bleed into each other.
+fn splice(target: &str, column: usize, width: usize, content: &str) -> String {
+    format!("{}{}{}", slice(target, 0, column), content, slice(target, column + width, usize::MAX))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::style::width;
+
+    #[test]
+    fn test_split_with_gap_and_padding() {
+        let layout = Layout::row()
+            .gap(2)
+            .padding(&[1])
+            .child(Constraint::Length(10), "a")
+            .child(Constraint::Flex(1), "b");
+
+        let areas = layout.split(Rect::from_size(40, 10));
+        assert_eq!(areas, vec![Rect::new(1, 1, 10, 8), Rect::new(13, 1, 26, 8)]);
+    }
+
+    #[test]
+    fn test_render_fills_screen() {
+        let layout = Layout::row()
+            .child(Constraint::Length(3), "\x1b[31mred text\x1b[0m")
+            .child(Constraint::Flex(1), |area: Rect| "x".repeat(area.width as usize + 5));
+
+        let screen = layout.render(10, 2);
+        let lines: Vec<&str> = screen.lines().collect();
+        assert_eq!(lines.len(), 2);
+        assert!(lines.iter().all(|line| width(line) == 10));
+        assert_eq!(crate::style::strip_ansi(lines[0]), "redxxxxxxx");
+        assert_eq!(lines[1], "          ");
+    }
+
+    #[test]
+    fn test_fit() {
+        assert_eq!(fit("abcdef\nxy", Rect::from_size(4, 3)), "abcd\nxy  \n    ");
+        assert_eq!(fit("日本語", Rect::from_size(5, 1)), "日本 ");
+        assert_eq!(fit("\x1b[1mbold\x1b[0m", Rect::from_size(2, 1)), "\x1b[1mbo\x1b[0m");
+    }
+
+    #[test]
+    fn test_clip() {
+        assert_eq!(clip("abcdef\nxy\nz", Rect::from_size(4, 2)), "abcd\nxy");
+        assert_eq!(clip("a\nb", Rect::from_size(4, 5)), "a\nb");
+    }
+
+    #[test]
+    fn test_nested_reflow() {
+        let layout = Layout::column().child(Constraint::Length(1), "top").child(
+            Constraint::Flex(1),
+            Layout::row().child(Constraint::Percentage(50), "L").child(Constraint::Flex(1), "R"),
+        );
+
+        let wide = layout.render(20, 3);
+        assert_eq!(wide.lines().nth(1), Some("L         R         "));
+
+        let narrow = layout.render(6, 3);
+        assert_eq!(narrow.lines().nth(1), Some("L  R  "));
+    }
+
+    #[test]
+    fn test_render_hyperlinked_child() {
+        let link = "\x1b]8;;http://x.org/zzz\x07ab\x1b]8;;\x07";
+        let screen = Layout::row()
+            .child(Constraint::Length(4), link)
+            .child(Constraint::Length(4), "CDEF")
+            .render(8, 1);
+
+        assert_eq!(crate::style::strip_ansi(&screen), "ab  CDEF");
+        assert!(screen.contains("http://x.org/zzz"));
+        // The link is closed before the neighbour starts.
+        let close = screen.rfind("\x1b]8;;\x07").unwrap();
+        assert!(close < screen.find("CDEF").unwrap());
+    }
+}
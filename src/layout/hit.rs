@@ -0,0 +1,115 @@
+//! Mouse hit-testing.
+
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use super::Rect;
+
+/// Packed value meaning "never drawn".
+const UNSET: u64 = u64::MAX;
+
+/// The screen area a component was last drawn into.
+///
+/// Components record their area from [`Model::view_in`](crate::Model::view_in),
+/// which only borrows them immutably, so the rectangle is kept in an atomic.
+/// Mouse events carry absolute terminal coordinates; [`HitArea::hit`] turns
+/// them into coordinates relative to the component.
+///
+/// A component that has never been given an area can't be hit, so mouse
+/// events pass through it until it is drawn with `view_in` or given an area.
+///
+/// # Example
+///
+/// ```rust
+/// use teapot::layout::{HitArea, Rect};
+///
+/// let area = HitArea::new();
+/// assert_eq!(area.hit(3, 1), None);
+///
+/// area.set(Rect::new(10, 5, 20, 4));
+/// assert_eq!(area.hit(12, 6), Some((2, 1)));
+/// assert_eq!(area.hit(3, 1), None);
+/// ```
+pub struct HitArea {
+    packed: AtomicU64,
+}
+
+impl HitArea {
+    /// Create an area that hasn't been drawn yet.
+    pub fn new() -> Self {
+        Self { packed: AtomicU64::new(UNSET) }
+    }
+
+    /// Record where the component was drawn.
+    pub fn set(&self, area: Rect) {
+        let packed = (area.x as u64) << 48
+            | (area.y as u64) << 32
+            | (area.width as u64) << 16
+            | area.height as u64;
+        self.packed.store(packed, Ordering::Relaxed);
+    }
+
+    /// Forget the recorded area.
+    pub fn clear(&self) {
+        self.packed.store(UNSET, Ordering::Relaxed);
+    }
+
+    /// The recorded area, if any.
+    pub fn get(&self) -> Option<Rect> {
+        let packed = self.packed.load(Ordering::Relaxed);
+        (packed != UNSET).then(|| {
+            Rect::new(
+                (packed >> 48) as u16,
+                (packed >> 32) as u16,
+                (packed >> 16) as u16,
+                packed as u16,
+            )
+        })
+    }
+
+    /// Convert a terminal cell to (column, row) relative to the area, or
+    /// `None` if the cell lies outside it or no area was recorded.
+    pub fn hit(&self, column: u16, row: u16) -> Option<(u16, u16)> {
+        let area = self.get()?;
+        area.contains(column, row).then(|| (column - area.x, row - area.y))
+    }
+}
+
+impl Default for HitArea {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Clone for HitArea {
+    fn clone(&self) -> Self {
+        Self { packed: AtomicU64::new(self.packed.load(Ordering::Relaxed)) }
+    }
+}
+
+impl std::fmt::Debug for HitArea {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("HitArea").field(&self.get()).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hit_area() {
+        let area = HitArea::default();
+        assert_eq!(area.get(), None);
+        assert_eq!(area.hit(0, 0), None);
+
+        area.set(Rect::new(2, 3, 4, 2));
+        assert_eq!(area.get(), Some(Rect::new(2, 3, 4, 2)));
+        assert_eq!(area.hit(2, 3), Some((0, 0)));
+        assert_eq!(area.hit(5, 4), Some((3, 1)));
+        assert_eq!(area.hit(6, 4), None);
+        assert_eq!(area.clone().get(), area.get());
+
+        area.clear();
+        assert_eq!(area.get(), None);
+    }
+}
@@ -0,0 +1,38 @@
+//! Flexbox-style layout.
+//!
+//! A [`Layout`] arranges children in a row or column. Each child has a
+//! [`Constraint`] (fixed, percentage, min, max or flex), and the layout adds
+//! gaps and padding around them. Layouts resolve to [`Rect`]s for a given
+//! area and render their children into those areas, so a view built from a
+//! layout reflows whenever the terminal is resized.
+//!
+//! A [`Compositor`] stacks [`Layer`]s (modals, popovers, toasts) over a base
+//! view at absolute positions and in z-order.
+//!
+//! A [`HitArea`] remembers where a component was drawn so mouse events can
+//! be mapped back to its rows and columns.
+//!
+//! # Example
+//!
+//! ```rust
+//! use teapot::layout::{Constraint, Layout};
+//!
+//! let (width, height) = (60, 10);
+//! let view = Layout::row()
+//!     .gap(1)
+//!     .child(Constraint::Max(20), "Navigation")
+//!     .child(Constraint::Flex(1), "Content")
+//!     .render(width, height);
+//! ```
+
+mod compositor;
+mod constraint;
+mod flex;
+mod hit;
+mod rect;
+
+pub use compositor::{Compositor, Layer};
+pub use constraint::{Constraint, solve};
+pub use flex::{Direction, Layout, Render, clip, fit};
+pub use hit::HitArea;
+pub use rect::Rect;
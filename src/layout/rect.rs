@@ -0,0 +1,94 @@
+//! Screen rectangles.
+
+use crate::style::Spacing;
+
+/// A rectangular area of the terminal, in cells.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Rect {
+    /// Left column.
+    pub x: u16,
+    /// Top row.
+    pub y: u16,
+    /// Width in columns.
+    pub width: u16,
+    /// Height in rows.
+    pub height: u16,
+}
+
+impl Rect {
+    /// Create a rectangle.
+    pub fn new(x: u16, y: u16, width: u16, height: u16) -> Self {
+        Self { x, y, width, height }
+    }
+
+    /// Create a rectangle at the origin, e.g. for the whole terminal.
+    pub fn from_size(width: u16, height: u16) -> Self {
+        Self::new(0, 0, width, height)
+    }
+
+    /// Number of cells covered.
+    pub fn area(&self) -> u32 {
+        self.width as u32 * self.height as u32
+    }
+
+    /// Whether the rectangle covers no cells.
+    pub fn is_empty(&self) -> bool {
+        self.width == 0 || self.height == 0
+    }
+
+    /// Column just past the right edge.
+    pub fn right(&self) -> u16 {
+        self.x.saturating_add(self.width)
+    }
+
+    /// Row just past the bottom edge.
+    pub fn bottom(&self) -> u16 {
+        self.y.saturating_add(self.height)
+    }
+
+    /// Whether the cell at (`x`, `y`) lies inside.
+    pub fn contains(&self, x: u16, y: u16) -> bool {
+        x >= self.x && x < self.right() && y >= self.y && y < self.bottom()
+    }
+
+    /// Shrink by the given spacing on each side.
+    pub fn inner(&self, spacing: Spacing) -> Rect {
+        let clamp = |v: usize| v.min(u16::MAX as usize) as u16;
+        let horizontal = clamp(spacing.left + spacing.right).min(self.width);
+        let vertical = clamp(spacing.top + spacing.bottom).min(self.height);
+        Rect {
+            x: self.x.saturating_add(clamp(spacing.left).min(self.width)),
+            y: self.y.saturating_add(clamp(spacing.top).min(self.height)),
+            width: self.width - horizontal,
+            height: self.height - vertical,
+        }
+    }
+
+    /// The overlapping area of two rectangles.
+    pub fn intersection(&self, other: &Rect) -> Rect {
+        let x = self.x.max(other.x);
+        let y = self.y.max(other.y);
+        let right = self.right().min(other.right());
+        let bottom = self.bottom().min(other.bottom());
+        Rect::new(x, y, right.saturating_sub(x), bottom.saturating_sub(y))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rect_geometry() {
+        let rect = Rect::new(2, 1, 10, 4);
+        assert_eq!(rect.area(), 40);
+        assert_eq!((rect.right(), rect.bottom()), (12, 5));
+        assert!(rect.contains(2, 1));
+        assert!(!rect.contains(12, 1));
+        assert_eq!(rect.inner(Spacing::from_values(&[1, 2])), Rect::new(4, 2, 6, 2));
+        assert!(rect.inner(Spacing::all(10)).is_empty());
+        assert_eq!(rect.intersection(&Rect::new(8, 0, 10, 2)), Rect::new(8, 1, 4, 1));
+        assert!(rect.intersection(&Rect::new(20, 20, 1, 1)).is_empty());
+    }
+}
@@ -81,6 +81,12 @@
 //! - [`components::Table`] - Scrollable data table
 //! - [`components::MultiProgress`] - Multiple parallel progress bars
 //!
+//! ## Layout
+//!
+//! The [`layout`] module arranges views in rows and columns with fixed,
+//! percentage, min/max and flex sizes, resolving to [`layout::Rect`]s for the
+//! current terminal size.
+//!
 //! ## Forms
 //!
 //! The `forms` module provides declarative form building:
@@ -140,6 +146,7 @@
 pub mod components;
 pub mod error;
 pub mod forms;
+pub mod layout;
 pub mod output;
 pub mod runtime;
 pub mod style;
@@ -31,7 +31,7 @@ const RESET: &str = "\x1b[0m";
 /// ```
 pub fn success(message: &str) {
     if is_tty() {
-        eprintln!("{}✓{} {}", Color::Green.to_ansi_fg(), RESET, message);
+        eprintln!("{}✓{} {}", Color::SUCCESS.to_ansi_fg(), RESET, message);
     } else {
         eprintln!("+ {}", message);
     }
@@ -48,7 +48,7 @@ pub fn success(message: &str) {
 /// ```
 pub fn warning(message: &str) {
     if is_tty() {
-        eprintln!("{}⚠{} {}", Color::Yellow.to_ansi_fg(), RESET, message);
+        eprintln!("{}⚠{} {}", Color::WARNING.to_ansi_fg(), RESET, message);
     } else {
         eprintln!("! {}", message);
     }
@@ -65,7 +65,7 @@ pub fn warning(message: &str) {
 /// ```
 pub fn error(message: &str) {
     if is_tty() {
-        eprintln!("{}✗{} {}", Color::Red.to_ansi_fg(), RESET, message);
+        eprintln!("{}✗{} {}", Color::ERROR.to_ansi_fg(), RESET, message);
     } else {
         eprintln!("x {}", message);
     }
@@ -82,7 +82,7 @@ pub fn error(message: &str) {
 /// ```
 pub fn info(message: &str) {
     if is_tty() {
-        eprintln!("{}○{} {}", Color::BrightBlack.to_ansi_fg(), RESET, message);
+        eprintln!("{}○{} {}", Color::MUTED.to_ansi_fg(), RESET, message);
     } else {
         eprintln!("- {}", message);
     }
@@ -101,7 +101,7 @@ pub fn kv(key: &str, value: &str) {
     if is_tty() {
         eprintln!(
             "{}{}: {}{}{}",
-            Color::BrightBlack.to_ansi_fg(),
+            Color::MUTED.to_ansi_fg(),
             key,
             Color::Default.to_ansi_fg(),
             value,
@@ -112,6 +112,34 @@ pub fn kv(key: &str, value: &str) {
     }
 }
 
+/// Print a key-value pair whose value links to a URL.
+///
+/// The value is an OSC 8 hyperlink when the terminal supports it; otherwise
+/// the URL is printed after it in parentheses.
+///
+/// # Example
+///
+/// ```no_run
+/// use teapot::output;
+/// output::kv_link("Docs", "teapot", "https://docs.rs/teapot");
+/// // Output: Docs: teapot (https://docs.rs/teapot)
+/// ```
+pub fn kv_link(key: &str, value: &str, url: &str) {
+    let linked = is_tty() && crate::style::hyperlinks_enabled();
+    kv(key, &link_value(value, url, linked));
+}
+
+/// Format a linked value, falling back to showing the URL.
+fn link_value(value: &str, url: &str, linked: bool) -> String {
+    if linked {
+        crate::style::hyperlink(value, url)
+    } else if value == url {
+        value.to_string()
+    } else {
+        format!("{} ({})", value, url)
+    }
+}
+
 /// Print a header/section title.
 ///
 /// # Example
@@ -126,10 +154,10 @@ pub fn header(title: &str) {
     if is_tty() {
         eprintln!(
             "{}\x1b[1m{}{}\n{}{}{}",
-            Color::Cyan.to_ansi_fg(),
+            Color::PRIMARY.to_ansi_fg(),
             title,
             RESET,
-            Color::BrightBlack.to_ansi_fg(),
+            Color::MUTED.to_ansi_fg(),
             "─".repeat(title.chars().count()),
             RESET
         );
@@ -150,7 +178,7 @@ pub fn header(title: &str) {
 /// ```
 pub fn phase(name: &str) {
     if is_tty() {
-        eprintln!("{}━━━ {} ━━━{}", Color::BrightBlack.to_ansi_fg(), name, RESET);
+        eprintln!("{}━━━ {} ━━━{}", Color::MUTED.to_ansi_fg(), name, RESET);
     } else {
         eprintln!("--- {} ---", name);
     }
@@ -261,4 +289,13 @@ mod tests {
         let width = terminal_width();
         assert!(width > 0);
     }
+
+    #[test]
+    fn test_link_value_fallback() {
+        assert_eq!(link_value("docs", "https://example.com", false), "docs (https://example.com)");
+        assert_eq!(
+            link_value("https://example.com", "https://example.com", false),
+            "https://example.com"
+        );
+    }
 }
@@ -38,7 +38,7 @@ pub mod cmd {
     pub use super::command::{batch, none, quit, run_process, sequence, tick};
 }
 
-use crate::terminal::Event;
+use crate::{layout::Rect, terminal::Event};
 
 /// The core Model trait - equivalent to `tea.Model` in Bubble Tea.
 ///
@@ -48,7 +48,7 @@ use crate::terminal::Event;
 /// 1. `init()` - Called once at startup
 /// 2. `handle_event()` - Convert terminal events to messages
 /// 3. `update()` - Handle messages and update state
-/// 4. `view()` - Render the current state
+/// 4. `view_in()` - Render the current state into the terminal area (defaults to `view()`)
 /// 5. `subscriptions()` - Declare recurring event sources
 ///
 /// # Example
@@ -150,6 +150,32 @@ pub trait Model: Sized {
     /// The framework handles diffing and efficient updates.
     fn view(&self) -> String;
 
+    /// Render the model into a region of the screen.
+    ///
+    /// The runtime calls this with the full terminal area on every frame, so
+    /// models that size themselves from `area` reflow on resize. Parents pass
+    /// each child the [`Rect`] it was given, e.g. from
+    /// [`Layout::split`](crate::layout::Layout::split), and the child clips,
+    /// wraps or scrolls its content to fit.
+    ///
+    /// The default implementation ignores the area and returns [`view`](Model::view).
+    fn view_in(&self, area: Rect) -> String {
+        let _ = area;
+        self.view()
+    }
+
+    /// Render the model once for non-interactive output.
+    ///
+    /// When stdin or stdout isn't a terminal (pipes, CI, scripts),
+    /// [`Program`] prints this a single time instead of
+    /// running the event loop, so it should show the content itself rather
+    /// than cursors, scroll positions or key hints.
+    ///
+    /// The default implementation returns [`view`](Model::view).
+    fn view_static(&self) -> String {
+        self.view()
+    }
+
     /// Convert terminal events to messages.
     ///
     /// Override this to handle keyboard, mouse, and resize events.
@@ -20,7 +20,13 @@ use crossterm::{
 };
 
 use super::{Model, command::CmdResult, subscription::SubEntry};
-use crate::{Cmd, error::Error, terminal::Event};
+use crate::{
+    Cmd,
+    error::Error,
+    layout::Rect,
+    style::{ColorProfile, Theme},
+    terminal::Event,
+};
 
 /// Type alias for pending tick entries: (scheduled_time, interval, message_generator)
 type PendingTick<M> = (Instant, Duration, Box<dyn Fn(Instant) -> M + Send>);
@@ -29,7 +35,7 @@ type PendingTick<M> = (Instant, Duration, Box<dyn Fn(Instant) -> M + Send>);
 struct ActiveSub<M> {
     next_fire: Instant,
     interval: Duration,
-    msg_fn: Box<dyn Fn() -> M + Send>,
+    msg_fn: Box<dyn Fn() -> Option<M> + Send>,
 }
 
 /// Options for configuring the program runtime.
@@ -53,7 +59,8 @@ pub struct ProgramOptions {
     /// Enable accessible mode (text-based prompts instead of TUI).
     pub accessible: bool,
 
-    /// Respect NO_COLOR environment variable.
+    /// Respect NO_COLOR environment variable. When false, the color profile
+    /// is detected from the terminal as if it were unset.
     pub respect_no_color: bool,
 
     /// Disable animations and spinners.
@@ -61,6 +68,17 @@ pub struct ProgramOptions {
 
     /// Tick duration for models that want periodic updates.
     pub tick_rate: Duration,
+
+    /// Theme to install when the program starts.
+    pub theme: Option<Theme>,
+
+    /// Color profile to render with; detected from the terminal when `None`.
+    pub color_profile: Option<ColorProfile>,
+
+    /// How long to wait for the terminal to report its colors at start-up.
+    ///
+    /// `None` skips the query.
+    pub color_query_timeout: Option<Duration>,
 }
 
 impl Default for ProgramOptions {
@@ -75,6 +93,9 @@ impl Default for ProgramOptions {
             respect_no_color: true,
             reduce_motion: std::env::var("REDUCE_MOTION").is_ok(),
             tick_rate: Duration::from_millis(100),
+            theme: None,
+            color_profile: None,
+            color_query_timeout: Some(Duration::from_millis(100)),
         }
     }
 }
@@ -143,13 +164,21 @@ pub struct Program<M: Model> {
     model: M,
     options: ProgramOptions,
     last_view: String,
+    /// Screen row the inline frame starts on, to translate mouse rows.
+    inline_top: u16,
     filter: Option<MessageFilter<M, M::Message>>,
 }
 
 impl<M: Model> Program<M> {
     /// Create a new program with the given model.
     pub fn new(model: M) -> Self {
-        Self { model, options: ProgramOptions::default(), last_view: String::new(), filter: None }
+        Self {
+            model,
+            options: ProgramOptions::default(),
+            last_view: String::new(),
+            inline_top: 0,
+            filter: None,
+        }
     }
 
     /// Configure the program with custom options.
@@ -270,6 +299,40 @@ impl<M: Model> Program<M> {
         self
     }
 
+    /// Set the application-wide theme.
+    ///
+    /// The theme is installed with [`set_theme`](crate::style::set_theme)
+    /// when the program starts and the previous one is restored when it
+    /// exits.
+    pub fn with_theme(mut self, theme: Theme) -> Self {
+        self.options.theme = Some(theme);
+        self
+    }
+
+    /// Set the color profile instead of detecting it.
+    ///
+    /// All colors are downsampled to this profile when rendered. Like a
+    /// detected profile, it is only installed while the program runs.
+    pub fn with_color_profile(mut self, profile: ColorProfile) -> Self {
+        self.options.color_profile = Some(profile);
+        self
+    }
+
+    /// Set how long to wait for the terminal to report its background color.
+    ///
+    /// The reply drives [`has_dark_background`](crate::style::has_dark_background)
+    /// and adaptive colors. The query runs once per process.
+    pub fn with_color_query_timeout(mut self, timeout: Duration) -> Self {
+        self.options.color_query_timeout = Some(timeout);
+        self
+    }
+
+    /// Skip querying the terminal's colors at start-up.
+    pub fn without_color_query(mut self) -> Self {
+        self.options.color_query_timeout = None;
+        self
+    }
+
     /// Check if running in an interactive terminal.
     pub fn is_interactive() -> bool {
         use std::io::IsTerminal;
@@ -297,6 +360,27 @@ impl<M: Model> Program<M> {
     ///
     /// Returns an error if terminal I/O fails or the program cannot start.
     pub fn run(mut self) -> Result<M, Error> {
+        let previous_theme =
+            self.options.theme.take().map(|theme| crate::style::replace_theme(Some(theme)));
+        let profile = match self.options.color_profile {
+            Some(profile) => profile,
+            None if self.options.respect_no_color => ColorProfile::detect(),
+            None => ColorProfile::detect_terminal(),
+        };
+        let previous_profile = crate::style::replace_color_profile(Some(profile));
+
+        let result = self.run_with_globals();
+
+        // Leave output printed after the program as it was before.
+        if let Some(theme) = previous_theme {
+            crate::style::replace_theme(theme);
+        }
+        crate::style::replace_color_profile(previous_profile);
+        result
+    }
+
+    /// Run once the theme and color profile are installed.
+    fn run_with_globals(mut self) -> Result<M, Error> {
         if !Self::is_interactive() || self.options.accessible {
             return self.run_non_interactive().map_err(Error::from);
         }
@@ -348,7 +432,7 @@ impl<M: Model> Program<M> {
             // Check for subscription fires
             for sub in active_subs.values_mut() {
                 if now >= sub.next_fire {
-                    messages.push((sub.msg_fn)());
+                    messages.extend((sub.msg_fn)());
                     sub.next_fire = now + sub.interval;
                 }
             }
@@ -397,7 +481,9 @@ impl<M: Model> Program<M> {
                 let event = Event::from(crossterm_event.clone());
 
                 // Convert to message and update (applying filter)
-                if let Some(msg) = self.model.handle_event(event) {
+                if let Some(msg) =
+                    self.frame_event(event).and_then(|event| self.model.handle_event(event))
+                {
                     // Apply the message filter
                     if let Some(msg) = self.apply_filter(msg) {
                         if let Some(cmd) = self.model.update(msg)
@@ -534,7 +620,7 @@ impl<M: Model> Program<M> {
 
         // In non-interactive mode, display a stripped view (no ANSI codes)
         // This ensures clean output for CI/scripts
-        let view = self.model.view();
+        let view = self.model.view_static();
         let clean_view = if self.options.accessible { strip_ansi(&view) } else { view };
         println!("{}", clean_view);
 
@@ -551,6 +637,11 @@ impl<M: Model> Program<M> {
     fn setup_terminal(&self) -> io::Result<()> {
         terminal::enable_raw_mode()?;
 
+        // Query before anything reads input so the reply isn't taken as keys.
+        if let Some(timeout) = self.options.color_query_timeout {
+            crate::terminal::detect_colors(timeout);
+        }
+
         let mut stdout = io::stdout();
 
         if self.options.alt_screen {
@@ -604,9 +695,24 @@ impl<M: Model> Program<M> {
         Ok(())
     }
 
+    /// Make mouse rows relative to the inline frame, dropping clicks above
+    /// it. The view's areas start at row 0 wherever the frame is drawn.
+    fn frame_event(&self, event: Event) -> Option<Event> {
+        match event {
+            Event::Mouse(mut mouse) if !self.options.alt_screen => {
+                mouse.row = mouse.row.checked_sub(self.inline_top)?;
+                Some(Event::Mouse(mouse))
+            },
+            event => Some(event),
+        }
+    }
+
     /// Render the current view.
     fn render(&mut self, stdout: &mut impl Write) -> io::Result<()> {
-        let view = self.model.view();
+        let view = match crate::terminal::size() {
+            Ok((width, height)) => self.model.view_in(Rect::from_size(width, height)),
+            Err(_) => self.model.view(),
+        };
 
         // Only redraw if the view changed
         if view != self.last_view {
@@ -622,6 +728,13 @@ impl<M: Model> Program<M> {
                         terminal::Clear(ClearType::FromCursorDown)
                     )?;
                 }
+                if self.options.mouse {
+                    // The frame scrolls up if it doesn't fit below the cursor.
+                    let (_, row) = cursor::position().unwrap_or_default();
+                    let (_, height) = crate::terminal::size().unwrap_or((0, u16::MAX));
+                    let lines = u16::try_from(view.lines().count()).unwrap_or(u16::MAX);
+                    self.inline_top = row.min(height.saturating_sub(lines));
+                }
             }
 
             write!(stdout, "{}", view)?;
@@ -675,6 +788,35 @@ mod tests {
         assert_eq!(program.model.count, 0);
     }
 
+    #[test]
+    fn test_inline_mouse_rows() {
+        use crate::terminal::{MouseEvent, MouseEventKind};
+
+        let click = |row| Event::Mouse(MouseEvent::new(MouseEventKind::Moved, 2, row));
+        let mut program =
+            Program::new(TestModel { count: 0 }).with_options(ProgramOptions::inline());
+        program.inline_top = 10;
+        assert!(matches!(program.frame_event(click(12)), Some(Event::Mouse(m)) if m.row == 2));
+        assert!(program.frame_event(click(9)).is_none());
+
+        program.options.alt_screen = true;
+        assert!(matches!(program.frame_event(click(12)), Some(Event::Mouse(m)) if m.row == 12));
+    }
+
+    #[test]
+    fn test_run_restores_globals() {
+        // Accessible mode runs without touching the terminal.
+        let options = ProgramOptions { accessible: true, ..ProgramOptions::default() };
+        Program::new(TestModel { count: 0 })
+            .with_options(options)
+            .with_theme(Theme::default())
+            .with_color_profile(ColorProfile::TrueColor)
+            .run()
+            .unwrap();
+        assert!(crate::style::replace_theme(None).is_none());
+        assert!(crate::style::replace_color_profile(None).is_none());
+    }
+
     #[test]
     fn test_program_options() {
         let options = ProgramOptions::fullscreen();
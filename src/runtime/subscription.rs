@@ -32,7 +32,10 @@
 //! ]);
 //! ```
 
-use std::time::Duration;
+use std::{
+    sync::{Arc, Mutex, mpsc::Receiver},
+    time::Duration,
+};
 
 /// A subscription representing an ongoing source of messages.
 ///
@@ -56,6 +59,15 @@ enum SubInner<M> {
         /// Function to generate messages
         msg_fn: Box<dyn Fn() -> M + Send>,
     },
+    /// Polled source that only sometimes has a message
+    Poll {
+        /// Unique identifier for this subscription
+        id: String,
+        /// Time between polls
+        interval: Duration,
+        /// Function to poll for a message
+        poll_fn: Box<dyn Fn() -> Option<M> + Send>,
+    },
     /// Batch of subscriptions
     Batch(Vec<Sub<M>>),
 }
@@ -147,6 +159,50 @@ impl<M> Sub<M> {
         Self::interval(id, Duration::from_secs(secs), msg_fn)
     }
 
+    /// Create a subscription that delivers values sent on a channel.
+    ///
+    /// The channel is checked every `interval`; everything waiting is
+    /// delivered together in one message, and nothing is sent while it is
+    /// empty. The receiver is shared so `subscriptions()` can hand out the
+    /// same one on every call.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::{
+    ///     sync::{Arc, Mutex, mpsc},
+    ///     time::Duration,
+    /// };
+    ///
+    /// use teapot::Sub;
+    ///
+    /// enum Msg { Lines(Vec<String>) }
+    ///
+    /// let (tx, rx) = mpsc::channel::<String>();
+    /// let rx = Arc::new(Mutex::new(rx));
+    /// let sub: Sub<Msg> = Sub::channel("logs", rx, Duration::from_millis(50), Msg::Lines);
+    /// tx.send("started".to_string()).unwrap();
+    /// ```
+    pub fn channel<T, F>(
+        id: impl Into<String>,
+        receiver: Arc<Mutex<Receiver<T>>>,
+        interval: Duration,
+        msg_fn: F,
+    ) -> Self
+    where
+        T: Send + 'static,
+        F: Fn(Vec<T>) -> M + Send + 'static,
+    {
+        let poll_fn = move || {
+            let values: Vec<T> = match receiver.lock() {
+                Ok(receiver) => receiver.try_iter().collect(),
+                Err(_) => Vec::new(),
+            };
+            (!values.is_empty()).then(|| msg_fn(values))
+        };
+        Self { inner: SubInner::Poll { id: id.into(), interval, poll_fn: Box::new(poll_fn) } }
+    }
+
     /// Combine multiple subscriptions into one.
     ///
     /// All subscriptions in the batch will be active simultaneously.
@@ -211,6 +267,11 @@ impl<M> Sub<M> {
                 let f = f.clone();
                 Sub::interval(id, interval, move || f(msg_fn()))
             },
+            SubInner::Poll { id, interval, poll_fn } => {
+                let f = f.clone();
+                let poll_fn = Box::new(move || poll_fn().map(&f));
+                Sub { inner: SubInner::Poll { id, interval, poll_fn } }
+            },
             SubInner::Batch(subs) => {
                 Sub::batch(subs.into_iter().map(|s| s.map(f.clone())).collect())
             },
@@ -218,11 +279,17 @@ impl<M> Sub<M> {
     }
 
     /// Extract subscription entries for the runtime.
-    pub(crate) fn into_entries(self) -> Vec<SubEntry<M>> {
+    pub(crate) fn into_entries(self) -> Vec<SubEntry<M>>
+    where
+        M: 'static,
+    {
         match self.inner {
             SubInner::None => vec![],
             SubInner::Interval { id, interval, msg_fn } => {
-                vec![SubEntry { id, interval, msg_fn }]
+                vec![SubEntry { id, interval, msg_fn: Box::new(move || Some(msg_fn())) }]
+            },
+            SubInner::Poll { id, interval, poll_fn } => {
+                vec![SubEntry { id, interval, msg_fn: poll_fn }]
             },
             SubInner::Batch(subs) => subs.into_iter().flat_map(|s| s.into_entries()).collect(),
         }
@@ -242,6 +309,9 @@ impl<M> std::fmt::Debug for Sub<M> {
             SubInner::Interval { id, interval, .. } => {
                 write!(f, "Sub::Interval({:?}, {:?})", id, interval)
             },
+            SubInner::Poll { id, interval, .. } => {
+                write!(f, "Sub::Poll({:?}, {:?})", id, interval)
+            },
             SubInner::Batch(subs) => write!(f, "Sub::Batch({} subs)", subs.len()),
         }
     }
@@ -253,8 +323,8 @@ pub(crate) struct SubEntry<M> {
     pub id: String,
     /// Interval between firings
     pub interval: Duration,
-    /// Message generator function
-    pub msg_fn: Box<dyn Fn() -> M + Send>,
+    /// Message generator function; `None` when there is nothing to deliver
+    pub msg_fn: Box<dyn Fn() -> Option<M> + Send>,
 }
 
 #[cfg(test)]
@@ -283,7 +353,7 @@ mod tests {
         assert_eq!(entries.len(), 1);
         assert_eq!(entries[0].id, "test");
         assert_eq!(entries[0].interval, Duration::from_millis(100));
-        assert_eq!((entries[0].msg_fn)(), TestMsg::Tick);
+        assert_eq!((entries[0].msg_fn)(), Some(TestMsg::Tick));
     }
 
     #[test]
@@ -327,7 +397,8 @@ mod tests {
         let entries = parent_sub.into_entries();
         assert_eq!(entries.len(), 1);
         match (entries[0].msg_fn)() {
-            Parent::Child(Child::Tick) => {},
+            Some(Parent::Child(Child::Tick)) => {},
+            None => panic!("interval subscriptions always deliver"),
         }
     }
 
@@ -342,4 +413,23 @@ mod tests {
         assert_eq!(entries1[0].interval, Duration::from_millis(100));
         assert_eq!(entries2[0].interval, Duration::from_secs(1));
     }
+
+    #[test]
+    fn test_sub_channel() {
+        let (tx, rx) = std::sync::mpsc::channel();
+        let rx = Arc::new(Mutex::new(rx));
+        let sub: Sub<Vec<u32>> = Sub::channel("ch", rx, Duration::from_millis(10), |v| v);
+        let sub = sub.map(|mut v: Vec<u32>| {
+            v.reverse();
+            v
+        });
+
+        let entries = sub.into_entries();
+        assert_eq!(entries[0].id, "ch");
+        assert_eq!((entries[0].msg_fn)(), None);
+        tx.send(1).unwrap();
+        tx.send(2).unwrap();
+        assert_eq!((entries[0].msg_fn)(), Some(vec![2, 1]));
+        assert_eq!((entries[0].msg_fn)(), None);
+    }
 }
@@ -0,0 +1,493 @@
+//! ANSI-aware text operations.
+//!
+//! Wrapping, truncating and slicing styled text by display column. Escape
+//! sequences never count towards the width, and SGR styles and OSC 8
+//! hyperlinks active at a cut are closed before it and reopened after it, so
+//! every resulting line renders correctly on its own.
+
+use unicode_width::UnicodeWidthChar;
+
+/// Tab stop width used by [`wrap`].
+const TAB_WIDTH: usize = 4;
+
+/// A piece of a line: an escape sequence or a visible character.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Segment<'a> {
+    Escape(&'a str),
+    Char(char),
+}
+
+/// Split a string into escape sequences and characters.
+pub(crate) fn segments(s: &str) -> impl Iterator<Item = Segment<'_>> {
+    let mut rest = s;
+    std::iter::from_fn(move || {
+        let c = rest.chars().next()?;
+        if c != '\x1b' {
+            rest = &rest[c.len_utf8()..];
+            return Some(Segment::Char(c));
+        }
+
+        let bytes = rest.as_bytes();
+        let end = match bytes.get(1) {
+            // CSI: parameters up to a final byte in 0x40..=0x7e.
+            Some(b'[') => bytes[2..]
+                .iter()
+                .position(|b| (0x40..=0x7e).contains(b))
+                .map_or(rest.len(), |i| i + 3),
+            // OSC: terminated by BEL or ST (ESC \).
+            Some(b']') => {
+                let mut end = rest.len();
+                let mut i = 2;
+                while i < bytes.len() {
+                    if bytes[i] == 0x07 {
+                        end = i + 1;
+                        break;
+                    }
+                    if bytes[i] == 0x1b && bytes.get(i + 1) == Some(&b'\\') {
+                        end = i + 2;
+                        break;
+                    }
+                    i += 1;
+                }
+                end
+            },
+            Some(_) => 1 + rest[1..].chars().next().map_or(0, char::len_utf8),
+            None => 1,
+        };
+
+        let (escape, tail) = rest.split_at(end);
+        rest = tail;
+        Some(Segment::Escape(escape))
+    })
+}
+
+/// SGR styles and hyperlink active at a point in a string.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub(crate) struct SgrState {
+    /// Active SGR sequences, in order.
+    pub(crate) style: String,
+    /// Active OSC 8 hyperlink opener.
+    pub(crate) link: String,
+}
+
+impl SgrState {
+    /// Track an escape sequence. Returns whether it was an SGR or OSC 8 sequence.
+    pub(crate) fn apply(&mut self, escape: &str) -> bool {
+        if let Some(params) = escape.strip_prefix("\x1b[").and_then(|s| s.strip_suffix('m')) {
+            if params.is_empty() || params == "0" {
+                self.style.clear();
+            } else if params.starts_with("0;") {
+                self.style = escape.to_string();
+            } else {
+                self.style.push_str(escape);
+            }
+            return true;
+        }
+
+        if let Some(rest) = escape.strip_prefix("\x1b]8;") {
+            let url = rest.split_once(';').map(|(_, url)| url).unwrap_or_default();
+            let url = url.trim_end_matches(['\x07', '\\']).trim_end_matches('\x1b');
+            self.link = if url.is_empty() { String::new() } else { escape.to_string() };
+            return true;
+        }
+
+        false
+    }
+
+    /// Sequences that restore this state.
+    pub(crate) fn open(&self) -> String {
+        format!("{}{}", self.style, self.link)
+    }
+
+    /// Sequences that end this state.
+    pub(crate) fn close(&self) -> String {
+        let mut close = String::new();
+        if !self.link.is_empty() {
+            close.push_str("\x1b]8;;\x07");
+        }
+        if !self.style.is_empty() {
+            close.push_str("\x1b[0m");
+        }
+        close
+    }
+}
+
+/// A visible character with the escape sequences in front of it.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct Glyph<'a> {
+    pub(crate) escapes: Vec<&'a str>,
+    /// The character plus any combining marks.
+    pub(crate) text: String,
+    pub(crate) width: usize,
+}
+
+impl Glyph<'_> {
+    fn is_space(&self) -> bool {
+        self.text == " "
+    }
+}
+
+/// Split a line into glyphs, returning escape sequences after the last one
+/// separately. Stray tabs count as a single space.
+pub(crate) fn glyphs(line: &str) -> (Vec<Glyph<'_>>, Vec<&str>) {
+    let mut glyphs: Vec<Glyph<'_>> = Vec::new();
+    let mut escapes = Vec::new();
+
+    for segment in segments(line) {
+        match segment {
+            Segment::Escape(escape) => escapes.push(escape),
+            Segment::Char('\t') => glyphs.push(Glyph {
+                escapes: std::mem::take(&mut escapes),
+                text: " ".to_string(),
+                width: 1,
+            }),
+            Segment::Char(c) => {
+                let width = c.width().unwrap_or(0);
+                // Combining marks join the previous glyph.
+                if width == 0
+                    && escapes.is_empty()
+                    && let Some(previous) = glyphs.last_mut()
+                {
+                    previous.text.push(c);
+                    continue;
+                }
+                glyphs.push(Glyph {
+                    escapes: std::mem::take(&mut escapes),
+                    text: c.to_string(),
+                    width,
+                });
+            },
+        }
+    }
+
+    (glyphs, escapes)
+}
+
+/// Builds one output line, opening the carried state lazily.
+struct LineWriter {
+    state: SgrState,
+    text: String,
+    width: usize,
+    opened: bool,
+}
+
+impl LineWriter {
+    fn new(state: SgrState) -> Self {
+        Self { state, text: String::new(), width: 0, opened: false }
+    }
+
+    fn escape(&mut self, escape: &str) {
+        let tracked = self.state.apply(escape);
+        if self.opened || !tracked {
+            self.text.push_str(escape);
+        }
+    }
+
+    fn open(&mut self) {
+        if !self.opened {
+            self.text.push_str(&self.state.open());
+            self.opened = true;
+        }
+    }
+
+    fn glyph(&mut self, glyph: &Glyph<'_>) {
+        for escape in &glyph.escapes {
+            self.escape(escape);
+        }
+        self.open();
+        self.text.push_str(&glyph.text);
+        self.width += glyph.width;
+    }
+
+    /// Blank columns in the current style.
+    fn blank(&mut self, width: usize) {
+        self.open();
+        self.text.push_str(&" ".repeat(width));
+        self.width += width;
+    }
+
+    fn push_str(&mut self, s: &str) {
+        for segment in segments(s) {
+            match segment {
+                Segment::Escape(escape) => self.escape(escape),
+                Segment::Char(c) => {
+                    self.open();
+                    self.text.push(c);
+                    self.width += c.width().unwrap_or(0);
+                },
+            }
+        }
+    }
+
+    /// Finish the line into `lines` and start the next one.
+    fn break_line(self, lines: &mut Vec<String>) -> Self {
+        let (text, state) = self.finish();
+        lines.push(text);
+        Self::new(state)
+    }
+
+    /// Finish the line, returning it and the state to carry over.
+    fn finish(mut self) -> (String, SgrState) {
+        if self.opened {
+            self.text.push_str(&self.state.close());
+        }
+        (self.text, self.state)
+    }
+}
+
+/// Word-wrap text to `width` columns.
+///
+/// Lines break at spaces; words longer than `width` are broken wherever they
+/// reach the edge. Existing line breaks and leading indentation are kept,
+/// tabs are expanded to 4-column stops, and styles carry across the breaks.
+///
+/// # Example
+///
+/// ```rust
+/// use teapot::style::wrap;
+///
+/// let lines = wrap("\x1b[1mhello world\x1b[0m", 5);
+/// assert_eq!(lines, vec!["\x1b[1mhello\x1b[0m", "\x1b[1mworld\x1b[0m"]);
+/// ```
+pub fn wrap(s: &str, width: usize) -> Vec<String> {
+    let width = width.max(1);
+    let mut lines = Vec::new();
+    let mut state = SgrState::default();
+
+    for line in expand_tabs(s, TAB_WIDTH).lines() {
+        state = wrap_line(line, width, state, &mut lines);
+    }
+
+    lines
+}
+
+fn wrap_line(line: &str, width: usize, state: SgrState, lines: &mut Vec<String>) -> SgrState {
+    let (glyphs, trailing) = glyphs(line);
+    let mut writer = LineWriter::new(state);
+    let mut wrapped = false;
+    let mut index = 0;
+
+    while index < glyphs.len() {
+        let is_space = glyphs[index].is_space();
+        let end = glyphs[index..]
+            .iter()
+            .position(|g| g.is_space() != is_space)
+            .map_or(glyphs.len(), |offset| index + offset);
+        let run = &glyphs[index..end];
+        let run_width: usize = run.iter().map(|g| g.width).sum();
+        index = end;
+
+        if is_space {
+            let at_break = wrapped && writer.width == 0;
+            let next_fits = glyphs.get(end).is_none_or(|_| {
+                let word_width: usize =
+                    glyphs[end..].iter().take_while(|g| !g.is_space()).map(|g| g.width).sum();
+                writer.width + run_width + word_width <= width
+            });
+            let breaks = !next_fits && writer.width > 0;
+            if at_break || breaks {
+                // Spaces at a break are dropped, keeping their escapes.
+                for glyph in run {
+                    for escape in &glyph.escapes {
+                        writer.escape(escape);
+                    }
+                }
+                if breaks {
+                    writer = writer.break_line(lines);
+                    wrapped = true;
+                }
+                continue;
+            }
+            for glyph in run {
+                if writer.width + glyph.width > width {
+                    break;
+                }
+                writer.glyph(glyph);
+            }
+            continue;
+        }
+
+        if writer.width > 0 && writer.width + run_width > width {
+            writer = writer.break_line(lines);
+            wrapped = true;
+        }
+        for glyph in run {
+            if writer.width > 0 && writer.width + glyph.width > width {
+                writer = writer.break_line(lines);
+                wrapped = true;
+            }
+            writer.glyph(glyph);
+        }
+    }
+
+    for escape in trailing {
+        writer.escape(escape);
+    }
+    let (text, state) = writer.finish();
+    lines.push(text);
+    state
+}
+
+/// Take display columns `start..end` of each line.
+///
+/// Wide characters cut by either edge are replaced with spaces.
+///
+/// # Example
+///
+/// ```rust
+/// use teapot::style::slice;
+///
+/// assert_eq!(slice("\x1b[31mhello\x1b[0m", 1, 4), "\x1b[31mell\x1b[0m");
+/// assert_eq!(slice("日本語", 1, 5), " 本 ");
+/// ```
+pub fn slice(s: &str, start: usize, end: usize) -> String {
+    s.lines().map(|line| slice_line(line, start, end, "")).collect::<Vec<_>>().join("\n")
+}
+
+/// Keep at most `width` display columns of each line.
+pub fn crop(s: &str, width: usize) -> String {
+    slice(s, 0, width)
+}
+
+/// Truncate each line to `width` columns, ending cut lines with `tail`.
+///
+/// The tail is drawn in the style active where the line is cut.
+///
+/// # Example
+///
+/// ```rust
+/// use teapot::style::truncate_with;
+///
+/// assert_eq!(truncate_with("hello world", 8, "…"), "hello w…");
+/// assert_eq!(truncate_with("\x1b[1mhello\x1b[0m", 4, "…"), "\x1b[1mhel…\x1b[0m");
+/// ```
+pub fn truncate_with(s: &str, width: usize, tail: &str) -> String {
+    let tail_width = super::width(tail);
+    s.lines()
+        .map(|line| {
+            if super::width(line) <= width {
+                line.to_string()
+            } else if tail_width >= width {
+                slice_line(tail, 0, width, "")
+            } else {
+                slice_line(line, 0, width - tail_width, tail)
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn slice_line(line: &str, start: usize, end: usize, tail: &str) -> String {
+    let (glyphs, _) = glyphs(line);
+    let mut writer = LineWriter::new(SgrState::default());
+    let mut column = 0;
+
+    for glyph in &glyphs {
+        if column >= end {
+            break;
+        }
+        let right = column + glyph.width;
+        if column >= start && right <= end {
+            writer.glyph(glyph);
+        } else {
+            for escape in &glyph.escapes {
+                writer.escape(escape);
+            }
+            let visible = right.min(end).saturating_sub(column.max(start));
+            if visible > 0 {
+                writer.blank(visible);
+            }
+        }
+        column = right;
+    }
+
+    writer.push_str(tail);
+    writer.finish().0
+}
+
+/// Expand tabs to spaces at `tab_width`-column tab stops.
+///
+/// A `tab_width` of 0 removes tabs.
+pub fn expand_tabs(s: &str, tab_width: usize) -> String {
+    if !s.contains('\t') {
+        return s.to_string();
+    }
+
+    let mut result = String::with_capacity(s.len());
+    let mut column = 0;
+    for segment in segments(s) {
+        match segment {
+            Segment::Escape(escape) => result.push_str(escape),
+            Segment::Char('\t') => {
+                if tab_width > 0 {
+                    let spaces = tab_width - column % tab_width;
+                    result.push_str(&" ".repeat(spaces));
+                    column += spaces;
+                }
+            },
+            Segment::Char('\n') => {
+                result.push('\n');
+                column = 0;
+            },
+            Segment::Char(c) => {
+                result.push(c);
+                column += c.width().unwrap_or(0);
+            },
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::style::{strip_ansi, width};
+
+    #[test]
+    fn test_wrap_words() {
+        assert_eq!(wrap("hello world foo bar", 10), vec!["hello", "world foo", "bar"]);
+        assert_eq!(wrap("  indented text\n\nnext", 20), vec!["  indented text", "", "next"]);
+        assert_eq!(wrap("", 10), Vec::<String>::new());
+    }
+
+    #[test]
+    fn test_wrap_hard_breaks_long_words() {
+        assert_eq!(wrap("abcdefghij xy", 4), vec!["abcd", "efgh", "ij", "xy"]);
+        assert_eq!(wrap("日本語テキスト", 5), vec!["日本", "語テ", "キス", "ト"]);
+    }
+
+    #[test]
+    fn test_wrap_carries_styles() {
+        let lines = wrap("plain \x1b[31mred text here\x1b[0m done", 9);
+        assert_eq!(lines, vec!["plain \x1b[31mred\x1b[0m", "\x1b[31mtext here\x1b[0m", "done"]);
+
+        let link = "\x1b]8;;https://example.com\x07a link\x1b]8;;\x07";
+        let lines = wrap(link, 2);
+        assert_eq!(lines[1], "\x1b]8;;https://example.com\x07li\x1b]8;;\x07");
+        assert!(lines.iter().all(|line| width(line) <= 2));
+    }
+
+    #[test]
+    fn test_slice_and_crop() {
+        assert_eq!(slice("abcdef", 2, 4), "cd");
+        assert_eq!(slice("\x1b[1mab\x1b[0mcd", 1, 3), "\x1b[1mb\x1b[0mc");
+        assert_eq!(crop("日本語", 3), "日 ");
+        assert_eq!(crop("short", 10), "short");
+    }
+
+    #[test]
+    fn test_truncate_with() {
+        assert_eq!(truncate_with("hello", 5, "…"), "hello");
+        assert_eq!(truncate_with("hello", 1, "…"), "…");
+        assert_eq!(truncate_with("\x1b[32mgreen\x1b[0m text", 6, "..."), "\x1b[32mgre...\x1b[0m");
+        assert_eq!(strip_ansi(&truncate_with("a\nlong line", 4, ".")), "a\nlon.");
+    }
+
+    #[test]
+    fn test_expand_tabs() {
+        assert_eq!(expand_tabs("a\tb", 4), "a   b");
+        assert_eq!(expand_tabs("abcd\te\n\tf", 4), "abcd    e\n    f");
+        assert_eq!(expand_tabs("\x1b[1ma\x1b[0m\tb", 2), "\x1b[1ma\x1b[0m b");
+        assert_eq!(expand_tabs("a\tb", 0), "ab");
+    }
+}
@@ -2,7 +2,9 @@
 //!
 //! Supports ANSI 16, 256, true color, and adaptive colors for light/dark terminals.
 
-use std::env;
+use std::{env, sync::RwLock};
+
+use super::theme::{Theme, ThemeColor, with_current_theme};
 
 /// A terminal color.
 #[derive(Debug, Clone, PartialEq, Eq, Default)]
@@ -63,6 +65,8 @@ pub enum Color {
         /// ANSI 16 (4-bit) value.
         ansi: u8,
     },
+    /// A semantic slot looked up in the current [`Theme`] when rendered.
+    Theme(ThemeColor),
 }
 
 impl Color {
@@ -110,6 +114,10 @@ impl Color {
     }
 
     /// Resolve adaptive colors to their concrete value based on terminal background.
+    ///
+    /// This borrows from `self`, so it can't look up the current theme: a
+    /// [`Color::Theme`] slot is returned unchanged. Use [`Color::resolve_owned`]
+    /// when the result must be a concrete color.
     pub fn resolve(&self) -> &Color {
         match self {
             Color::Adaptive { light, dark } => {
@@ -123,9 +131,88 @@ impl Color {
         }
     }
 
+    /// Resolve adaptive and theme colors to their concrete value.
+    ///
+    /// Adaptive colors pick a side based on the terminal background, and theme
+    /// slots are looked up in the current theme (see [`set_theme`](super::set_theme)).
+    pub fn resolve_owned(&self) -> Color {
+        match self {
+            Color::Adaptive { .. } | Color::Theme(_) => {
+                with_current_theme(|theme| self.resolve_in(theme))
+            },
+            _ => self.clone(),
+        }
+    }
+
+    /// Resolve against an explicit theme instead of the current one.
+    pub fn resolve_in(&self, theme: &Theme) -> Color {
+        self.resolve_with(theme, false)
+    }
+
+    fn resolve_with(&self, theme: &Theme, in_theme: bool) -> Color {
+        match self {
+            Color::Adaptive { light, dark } => {
+                if has_dark_background() {
+                    dark.resolve_with(theme, in_theme)
+                } else {
+                    light.resolve_with(theme, in_theme)
+                }
+            },
+            // A theme slot that refers back to the theme has no concrete value.
+            Color::Theme(_) if in_theme => Color::Default,
+            Color::Theme(slot) => theme.get(*slot).resolve_with(theme, true),
+            _ => self.clone(),
+        }
+    }
+
+    /// Downsample to the closest color the given profile can display.
+    ///
+    /// True colors are matched to the ANSI 256 or ANSI 16 palette by
+    /// perceptual (CIELAB) distance. [`ColorProfile::Ascii`] yields
+    /// [`Color::Default`].
+    ///
+    /// # Example
+    /// ```
+    /// use teapot::style::{Color, ColorProfile};
+    ///
+    /// assert_eq!(Color::Rgb(255, 0, 0).convert(ColorProfile::Ansi256), Color::Ansi256(196));
+    /// assert_eq!(Color::Rgb(255, 0, 0).convert(ColorProfile::Ansi), Color::BrightRed);
+    /// ```
+    pub fn convert(&self, profile: ColorProfile) -> Color {
+        let color = self.resolve_owned();
+        match profile {
+            ColorProfile::TrueColor => color,
+            ColorProfile::Ascii => Color::Default,
+            ColorProfile::Ansi256 => match color {
+                Color::Rgb(r, g, b) => Color::Ansi256(nearest_ansi256((r, g, b))),
+                Color::Complete { ansi256, .. } => Color::Ansi256(ansi256),
+                other => other,
+            },
+            ColorProfile::Ansi => match color {
+                Color::Rgb(r, g, b) => ansi16(nearest_ansi16((r, g, b))),
+                Color::Ansi256(n) if n < 16 => ansi16(n),
+                Color::Ansi256(n) => ansi16(nearest_ansi16(ansi256_to_rgb(n))),
+                Color::Complete { ansi, .. } => ansi16(ansi),
+                other => other,
+            },
+        }
+    }
+
     /// Get the ANSI escape sequence for this color as foreground.
+    ///
+    /// The color is downsampled to the current [`color_profile`].
     pub fn to_ansi_fg(&self) -> String {
-        self.resolve().to_ansi_fg_inner()
+        self.to_ansi_fg_in(color_profile())
+    }
+
+    /// Get the foreground escape sequence for an explicit color profile.
+    ///
+    /// Returns an empty string for [`ColorProfile::Ascii`].
+    pub fn to_ansi_fg_in(&self, profile: ColorProfile) -> String {
+        if profile == ColorProfile::Ascii {
+            return String::new();
+        }
+        self.convert(profile).to_ansi_fg_inner()
     }
 
     fn to_ansi_fg_inner(&self) -> String {
@@ -149,7 +236,7 @@ impl Color {
             Color::BrightWhite => "\x1b[97m".to_string(),
             Color::Ansi256(n) => format!("\x1b[38;5;{}m", n),
             Color::Rgb(r, g, b) => format!("\x1b[38;2;{};{};{}m", r, g, b),
-            Color::Adaptive { dark, .. } => dark.to_ansi_fg_inner(),
+            Color::Adaptive { .. } | Color::Theme(_) => self.resolve_owned().to_ansi_fg_inner(),
             Color::Complete { true_color, .. } => {
                 format!("\x1b[38;2;{};{};{}m", true_color.0, true_color.1, true_color.2)
             },
@@ -157,8 +244,20 @@ impl Color {
     }
 
     /// Get the ANSI escape sequence for this color as background.
+    ///
+    /// The color is downsampled to the current [`color_profile`].
     pub fn to_ansi_bg(&self) -> String {
-        self.resolve().to_ansi_bg_inner()
+        self.to_ansi_bg_in(color_profile())
+    }
+
+    /// Get the background escape sequence for an explicit color profile.
+    ///
+    /// Returns an empty string for [`ColorProfile::Ascii`].
+    pub fn to_ansi_bg_in(&self, profile: ColorProfile) -> String {
+        if profile == ColorProfile::Ascii {
+            return String::new();
+        }
+        self.convert(profile).to_ansi_bg_inner()
     }
 
     fn to_ansi_bg_inner(&self) -> String {
@@ -182,16 +281,16 @@ impl Color {
             Color::BrightWhite => "\x1b[107m".to_string(),
             Color::Ansi256(n) => format!("\x1b[48;5;{}m", n),
             Color::Rgb(r, g, b) => format!("\x1b[48;2;{};{};{}m", r, g, b),
-            Color::Adaptive { dark, .. } => dark.to_ansi_bg_inner(),
+            Color::Adaptive { .. } | Color::Theme(_) => self.resolve_owned().to_ansi_bg_inner(),
             Color::Complete { true_color, .. } => {
                 format!("\x1b[48;2;{};{};{}m", true_color.0, true_color.1, true_color.2)
             },
         }
     }
 
-    /// Convert to crossterm color.
+    /// Convert to crossterm color, downsampled to the current [`color_profile`].
     pub fn to_crossterm(&self) -> crossterm::style::Color {
-        match self.resolve() {
+        match self.convert(color_profile()) {
             Color::Default => crossterm::style::Color::Reset,
             Color::Black => crossterm::style::Color::Black,
             Color::Red => crossterm::style::Color::DarkRed,
@@ -209,14 +308,145 @@ impl Color {
             Color::BrightMagenta => crossterm::style::Color::Magenta,
             Color::BrightCyan => crossterm::style::Color::Cyan,
             Color::BrightWhite => crossterm::style::Color::White,
-            Color::Ansi256(n) => crossterm::style::Color::AnsiValue(*n),
-            Color::Rgb(r, g, b) => crossterm::style::Color::Rgb { r: *r, g: *g, b: *b },
+            Color::Ansi256(n) => crossterm::style::Color::AnsiValue(n),
+            Color::Rgb(r, g, b) => crossterm::style::Color::Rgb { r, g, b },
             Color::Complete { true_color, .. } => {
                 crossterm::style::Color::Rgb { r: true_color.0, g: true_color.1, b: true_color.2 }
             },
-            Color::Adaptive { .. } => crossterm::style::Color::Reset,
+            Color::Adaptive { .. } | Color::Theme(_) => crossterm::style::Color::Reset,
+        }
+    }
+}
+
+// Color manipulation
+impl Color {
+    /// The RGB value of this color, if it has one.
+    ///
+    /// ANSI colors map to xterm's default palette; [`Color::Default`] has no
+    /// value.
+    pub fn to_rgb(&self) -> Option<(u8, u8, u8)> {
+        match self.resolve_owned() {
+            Color::Default | Color::Adaptive { .. } | Color::Theme(_) => None,
+            Color::Rgb(r, g, b) => Some((r, g, b)),
+            Color::Complete { true_color, .. } => Some(true_color),
+            Color::Ansi256(n) => Some(ansi256_to_rgb(n)),
+            named => Some(ANSI16_RGB[named.ansi16_index()? as usize]),
+        }
+    }
+
+    fn ansi16_index(&self) -> Option<u8> {
+        (0..16).find(|&i| ansi16(i) == *self)
+    }
+
+    /// Mix two colors in the OKLab color space.
+    ///
+    /// `t` runs from 0.0 (all `a`) to 1.0 (all `b`). If either color has no
+    /// RGB value, the nearer one is returned unchanged.
+    ///
+    /// # Example
+    /// ```
+    /// use teapot::style::Color;
+    ///
+    /// let mid = Color::blend(&Color::Rgb(0, 0, 0), &Color::Rgb(255, 255, 255), 0.5);
+    /// assert!(matches!(mid, Color::Rgb(r, g, b) if r == g && g == b));
+    /// ```
+    pub fn blend(a: &Color, b: &Color, t: f64) -> Color {
+        let t = t.clamp(0.0, 1.0);
+        match (a.to_rgb(), b.to_rgb()) {
+            (Some(from), Some(to)) => {
+                let (l1, a1, b1) = rgb_to_oklab(from);
+                let (l2, a2, b2) = rgb_to_oklab(to);
+                let mix = |x: f64, y: f64| x + (y - x) * t;
+                let (r, g, b) = oklab_to_rgb((mix(l1, l2), mix(a1, a2), mix(b1, b2)));
+                Color::Rgb(r, g, b)
+            },
+            _ if t < 0.5 => a.clone(),
+            _ => b.clone(),
         }
     }
+
+    /// Raise the OKLCH lightness by `amount` (0.0 to 1.0).
+    pub fn lighten(&self, amount: f64) -> Color {
+        self.map_oklch(|l, c, h| ((l + amount).clamp(0.0, 1.0), c, h))
+    }
+
+    /// Lower the OKLCH lightness by `amount` (0.0 to 1.0).
+    pub fn darken(&self, amount: f64) -> Color {
+        self.lighten(-amount)
+    }
+
+    /// Scale the OKLCH chroma up by `amount` (0.5 is 50% more saturated).
+    pub fn saturate(&self, amount: f64) -> Color {
+        self.map_oklch(|l, c, h| (l, (c * (1.0 + amount)).max(0.0), h))
+    }
+
+    /// Scale the OKLCH chroma down by `amount` (1.0 is fully gray).
+    pub fn desaturate(&self, amount: f64) -> Color {
+        self.saturate(-amount.clamp(0.0, 1.0))
+    }
+
+    fn map_oklch(&self, f: impl FnOnce(f64, f64, f64) -> (f64, f64, f64)) -> Color {
+        let Some(rgb) = self.to_rgb() else {
+            return self.clone();
+        };
+        let (l, a, b) = rgb_to_oklab(rgb);
+        let (l, c, h) = f(l, a.hypot(b), b.atan2(a));
+        let (r, g, b) = oklab_to_rgb((l, c * h.cos(), c * h.sin()));
+        Color::Rgb(r, g, b)
+    }
+
+    /// WCAG relative luminance, from 0.0 (black) to 1.0 (white).
+    pub fn luminance(&self) -> Option<f64> {
+        let (r, g, b) = self.to_rgb()?;
+        Some(0.2126 * srgb_to_linear(r) + 0.7152 * srgb_to_linear(g) + 0.0722 * srgb_to_linear(b))
+    }
+
+    /// WCAG contrast ratio against another color, from 1.0 to 21.0.
+    ///
+    /// # Example
+    /// ```
+    /// use teapot::style::Color;
+    ///
+    /// let ratio = Color::Rgb(0, 0, 0).contrast_ratio(&Color::Rgb(255, 255, 255));
+    /// assert_eq!(ratio.map(f64::round), Some(21.0));
+    /// ```
+    pub fn contrast_ratio(&self, other: &Color) -> Option<f64> {
+        let (a, b) = (self.luminance()?, other.luminance()?);
+        let (light, dark) = if a > b { (a, b) } else { (b, a) };
+        Some((light + 0.05) / (dark + 0.05))
+    }
+
+    /// Whether text in this color meets WCAG AA (4.5:1) on `background`.
+    pub fn is_readable_on(&self, background: &Color) -> bool {
+        self.contrast_ratio(background).is_some_and(|ratio| ratio >= 4.5)
+    }
+}
+
+// Semantic theme slots
+impl Color {
+    /// The current theme's primary color.
+    pub const PRIMARY: Color = Color::Theme(ThemeColor::Primary);
+
+    /// The current theme's accent color.
+    pub const ACCENT: Color = Color::Theme(ThemeColor::Accent);
+
+    /// The current theme's muted color.
+    pub const MUTED: Color = Color::Theme(ThemeColor::Muted);
+
+    /// The current theme's success color.
+    pub const SUCCESS: Color = Color::Theme(ThemeColor::Success);
+
+    /// The current theme's warning color.
+    pub const WARNING: Color = Color::Theme(ThemeColor::Warning);
+
+    /// The current theme's error color.
+    pub const ERROR: Color = Color::Theme(ThemeColor::Error);
+
+    /// The current theme's border color.
+    pub const BORDER: Color = Color::Theme(ThemeColor::Border);
+
+    /// The current theme's selection color.
+    pub const SELECTION: Color = Color::Theme(ThemeColor::Selection);
 }
 
 // TRON-inspired color palette for InferaDB
@@ -245,8 +475,15 @@ impl Color {
 
 /// Check if the terminal has a dark background.
 ///
-/// This checks the `COLORFGBG` environment variable and defaults to true (dark).
+/// Uses the background reported by the terminal when it has been queried (see
+/// [`detect_colors`](crate::terminal::detect_colors), which
+/// [`Program`](crate::Program) runs at start-up), then falls back to the
+/// `COLORFGBG` environment variable, and finally defaults to true (dark).
 pub fn has_dark_background() -> bool {
+    if let Some(dark) = crate::terminal::terminal_colors().and_then(|colors| colors.is_dark()) {
+        return dark;
+    }
+
     // Check COLORFGBG environment variable (format: "fg;bg")
     if let Ok(val) = env::var("COLORFGBG")
         && let Some(bg) = val.split(';').nth(1)
@@ -281,7 +518,11 @@ impl ColorProfile {
         if env::var("NO_COLOR").is_ok() {
             return ColorProfile::Ascii;
         }
+        Self::detect_terminal()
+    }
 
+    /// Detect the profile from `COLORTERM` and `TERM`, ignoring `NO_COLOR`.
+    pub(crate) fn detect_terminal() -> Self {
         // Check for COLORTERM
         if let Ok(val) = env::var("COLORTERM")
             && (val == "truecolor" || val == "24bit")
@@ -304,6 +545,187 @@ impl ColorProfile {
     }
 }
 
+static COLOR_PROFILE: RwLock<Option<ColorProfile>> = RwLock::new(None);
+
+/// Set the color profile all colors are downsampled to when rendered.
+///
+/// [`Program`](crate::Program) sets this from [`ColorProfile::detect`] while it
+/// runs; call it directly when printing styled output without a program.
+pub fn set_color_profile(profile: ColorProfile) {
+    replace_color_profile(Some(profile));
+}
+
+/// Install a color profile, or the default with `None`, returning the old one.
+pub(crate) fn replace_color_profile(profile: Option<ColorProfile>) -> Option<ColorProfile> {
+    std::mem::replace(&mut *COLOR_PROFILE.write().unwrap_or_else(|e| e.into_inner()), profile)
+}
+
+/// Get the color profile colors are rendered with.
+///
+/// Defaults to [`ColorProfile::TrueColor`], i.e. no downsampling, until
+/// [`set_color_profile`] is called.
+pub fn color_profile() -> ColorProfile {
+    COLOR_PROFILE.read().unwrap_or_else(|e| e.into_inner()).unwrap_or(ColorProfile::TrueColor)
+}
+
+// ========== Palette Matching ==========
+
+/// xterm's default values for the 16 ANSI colors.
+const ANSI16_RGB: [(u8, u8, u8); 16] = [
+    (0, 0, 0),
+    (205, 0, 0),
+    (0, 205, 0),
+    (205, 205, 0),
+    (0, 0, 238),
+    (205, 0, 205),
+    (0, 205, 205),
+    (229, 229, 229),
+    (127, 127, 127),
+    (255, 0, 0),
+    (0, 255, 0),
+    (255, 255, 0),
+    (92, 92, 255),
+    (255, 0, 255),
+    (0, 255, 255),
+    (255, 255, 255),
+];
+
+/// Channel levels of the 6x6x6 color cube (indices 16-231).
+const CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+/// Map an ANSI 16 index to its named color.
+fn ansi16(index: u8) -> Color {
+    match index {
+        0 => Color::Black,
+        1 => Color::Red,
+        2 => Color::Green,
+        3 => Color::Yellow,
+        4 => Color::Blue,
+        5 => Color::Magenta,
+        6 => Color::Cyan,
+        7 => Color::White,
+        8 => Color::BrightBlack,
+        9 => Color::BrightRed,
+        10 => Color::BrightGreen,
+        11 => Color::BrightYellow,
+        12 => Color::BrightBlue,
+        13 => Color::BrightMagenta,
+        14 => Color::BrightCyan,
+        _ => Color::BrightWhite,
+    }
+}
+
+/// RGB value of an ANSI 256 palette entry.
+fn ansi256_to_rgb(n: u8) -> (u8, u8, u8) {
+    match n {
+        0..=15 => ANSI16_RGB[n as usize],
+        16..=231 => {
+            let i = n - 16;
+            (
+                CUBE_LEVELS[(i / 36) as usize],
+                CUBE_LEVELS[(i / 6 % 6) as usize],
+                CUBE_LEVELS[(i % 6) as usize],
+            )
+        },
+        _ => {
+            let v = 8 + (n - 232) * 10;
+            (v, v, v)
+        },
+    }
+}
+
+/// Closest ANSI 16 index.
+fn nearest_ansi16(rgb: (u8, u8, u8)) -> u8 {
+    let lab = rgb_to_lab(rgb);
+    (0..16u8)
+        .min_by(|&a, &b| {
+            lab_distance(lab, rgb_to_lab(ANSI16_RGB[a as usize]))
+                .total_cmp(&lab_distance(lab, rgb_to_lab(ANSI16_RGB[b as usize])))
+        })
+        .unwrap_or(0)
+}
+
+/// Closest ANSI 256 index, choosing between the color cube and the gray ramp.
+///
+/// The first 16 entries are skipped because terminals commonly remap them.
+fn nearest_ansi256(rgb: (u8, u8, u8)) -> u8 {
+    let level = |v: u8| -> u8 {
+        match v {
+            0..48 => 0,
+            48..115 => 1,
+            _ => (v - 35) / 40,
+        }
+    };
+    let cube = 16 + 36 * level(rgb.0) + 6 * level(rgb.1) + level(rgb.2);
+
+    let average = (rgb.0 as u16 + rgb.1 as u16 + rgb.2 as u16) / 3;
+    let gray = if average > 238 { 255 } else { 232 + average.saturating_sub(3) as u8 / 10 };
+
+    let lab = rgb_to_lab(rgb);
+    let cube_distance = lab_distance(lab, rgb_to_lab(ansi256_to_rgb(cube)));
+    let gray_distance = lab_distance(lab, rgb_to_lab(ansi256_to_rgb(gray)));
+    if gray_distance < cube_distance { gray } else { cube }
+}
+
+/// Convert an sRGB channel to linear light.
+fn srgb_to_linear(c: u8) -> f64 {
+    let c = c as f64 / 255.0;
+    if c <= 0.04045 { c / 12.92 } else { ((c + 0.055) / 1.055).powf(2.4) }
+}
+
+/// Convert linear light to an sRGB channel, clamping out-of-gamut values.
+fn linear_to_srgb(c: f64) -> u8 {
+    let c = if c <= 0.0031308 { c * 12.92 } else { 1.055 * c.powf(1.0 / 2.4) - 0.055 };
+    (c.clamp(0.0, 1.0) * 255.0).round() as u8
+}
+
+/// Convert sRGB to CIELAB (D65 white point).
+fn rgb_to_lab((r, g, b): (u8, u8, u8)) -> (f64, f64, f64) {
+    let (r, g, b) = (srgb_to_linear(r), srgb_to_linear(g), srgb_to_linear(b));
+
+    let x = (0.4124 * r + 0.3576 * g + 0.1805 * b) / 0.95047;
+    let y = 0.2126 * r + 0.7152 * g + 0.0722 * b;
+    let z = (0.0193 * r + 0.1192 * g + 0.9505 * b) / 1.08883;
+
+    let f = |t: f64| if t > 0.008856 { t.cbrt() } else { 7.787 * t + 16.0 / 116.0 };
+    let (fx, fy, fz) = (f(x), f(y), f(z));
+
+    (116.0 * fy - 16.0, 500.0 * (fx - fy), 200.0 * (fy - fz))
+}
+
+/// Convert sRGB to OKLab.
+fn rgb_to_oklab((r, g, b): (u8, u8, u8)) -> (f64, f64, f64) {
+    let (r, g, b) = (srgb_to_linear(r), srgb_to_linear(g), srgb_to_linear(b));
+
+    let l = (0.4122214708 * r + 0.5363325363 * g + 0.0514459929 * b).cbrt();
+    let m = (0.2119034982 * r + 0.6806995451 * g + 0.1073969566 * b).cbrt();
+    let s = (0.0883024619 * r + 0.2817188376 * g + 0.6299787005 * b).cbrt();
+
+    (
+        0.2104542553 * l + 0.7936177850 * m - 0.0040720468 * s,
+        1.9779984951 * l - 2.4285922050 * m + 0.4505937099 * s,
+        0.0259040371 * l + 0.7827717662 * m - 0.8086757660 * s,
+    )
+}
+
+/// Convert OKLab to sRGB.
+fn oklab_to_rgb((l, a, b): (f64, f64, f64)) -> (u8, u8, u8) {
+    let l_ = (l + 0.3963377774 * a + 0.2158037573 * b).powi(3);
+    let m_ = (l - 0.1055613458 * a - 0.0638541728 * b).powi(3);
+    let s_ = (l - 0.0894841775 * a - 1.2914855480 * b).powi(3);
+
+    (
+        linear_to_srgb(4.0767416621 * l_ - 3.3077115913 * m_ + 0.2309699292 * s_),
+        linear_to_srgb(-1.2684380046 * l_ + 2.6097574011 * m_ - 0.3413193965 * s_),
+        linear_to_srgb(-0.0041960863 * l_ - 0.7034186147 * m_ + 1.7076147010 * s_),
+    )
+}
+
+/// Squared CIE76 distance between two Lab colors.
+fn lab_distance(a: (f64, f64, f64), b: (f64, f64, f64)) -> f64 {
+    (a.0 - b.0).powi(2) + (a.1 - b.1).powi(2) + (a.2 - b.2).powi(2)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -338,6 +760,66 @@ mod tests {
         assert_eq!(color.to_ansi_fg(), "\x1b[38;2;255;0;0m");
     }
 
+    #[test]
+    fn test_convert() {
+        assert_eq!(Color::Rgb(255, 0, 0).convert(ColorProfile::TrueColor), Color::Rgb(255, 0, 0));
+        assert_eq!(Color::Rgb(255, 0, 0).convert(ColorProfile::Ansi256), Color::Ansi256(196));
+        assert_eq!(Color::Rgb(128, 128, 128).convert(ColorProfile::Ansi256), Color::Ansi256(244));
+        assert_eq!(Color::Rgb(255, 0, 0).convert(ColorProfile::Ansi), Color::BrightRed);
+        assert_eq!(Color::Rgb(128, 128, 128).convert(ColorProfile::Ansi), Color::BrightBlack);
+        assert_eq!(Color::Ansi256(21).convert(ColorProfile::Ansi), Color::Blue);
+        assert_eq!(Color::Ansi256(9).convert(ColorProfile::Ansi), Color::BrightRed);
+        assert_eq!(Color::Red.convert(ColorProfile::Ansi), Color::Red);
+        assert_eq!(Color::Cyan.convert(ColorProfile::Ascii), Color::Default);
+
+        let complete = Color::complete((255, 0, 0), 196, 1);
+        assert_eq!(complete.convert(ColorProfile::Ansi256), Color::Ansi256(196));
+        assert_eq!(complete.convert(ColorProfile::Ansi), Color::Red);
+    }
+
+    #[test]
+    fn test_ansi_codes_in_profile() {
+        let orange = Color::Rgb(255, 128, 0);
+        assert_eq!(orange.to_ansi_fg_in(ColorProfile::TrueColor), "\x1b[38;2;255;128;0m");
+        assert_eq!(orange.to_ansi_fg_in(ColorProfile::Ansi256), "\x1b[38;5;208m");
+        assert_eq!(orange.to_ansi_bg_in(ColorProfile::Ansi256), "\x1b[48;5;208m");
+        assert_eq!(orange.to_ansi_fg_in(ColorProfile::Ascii), "");
+        assert_eq!(Color::Red.to_ansi_bg_in(ColorProfile::Ascii), "");
+    }
+
+    #[test]
+    fn test_manipulation() {
+        let black = Color::Rgb(0, 0, 0);
+        let white = Color::Rgb(255, 255, 255);
+        assert_eq!(Color::blend(&black, &white, 0.0), black);
+        assert_eq!(Color::blend(&black, &white, 1.0), white);
+        assert_eq!(Color::Red.to_rgb(), Some((205, 0, 0)));
+        assert_eq!(Color::Default.to_rgb(), None);
+        assert_eq!(Color::blend(&Color::Default, &white, 0.2), Color::Default);
+
+        let gray = Color::Rgb(128, 128, 128);
+        assert!(gray.lighten(0.2).luminance() > gray.luminance());
+        assert!(gray.darken(0.2).luminance() < gray.luminance());
+        assert_eq!(white.lighten(0.5), white);
+
+        let muted = Color::Rgb(160, 90, 90);
+        let (r, g, _) = muted.saturate(0.5).to_rgb().unwrap();
+        assert!(r as i32 - g as i32 > 70);
+        let (r, g, b) = muted.desaturate(1.0).to_rgb().unwrap();
+        assert!(r.abs_diff(g) <= 1 && g.abs_diff(b) <= 1);
+    }
+
+    #[test]
+    fn test_contrast_ratio() {
+        let black = Color::Rgb(0, 0, 0);
+        let white = Color::Rgb(255, 255, 255);
+        assert!((black.contrast_ratio(&white).unwrap() - 21.0).abs() < 0.01);
+        assert_eq!(white.contrast_ratio(&white), Some(1.0));
+        assert!(black.is_readable_on(&white));
+        assert!(!Color::Rgb(119, 119, 119).is_readable_on(&Color::Rgb(136, 136, 136)));
+        assert!(!Color::Default.is_readable_on(&white));
+    }
+
     #[test]
     fn test_default() {
         assert_eq!(Color::default(), Color::Default);
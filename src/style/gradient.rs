@@ -0,0 +1,82 @@
+//! Color gradients.
+//!
+//! A [`Gradient`] blends evenly spaced color stops in OKLab space.
+//!
+//! # Example
+//!
+//! ```rust
+//! use teapot::style::{Color, Gradient};
+//!
+//! let gradient = Gradient::new([Color::Rgb(90, 86, 224), Color::Rgb(238, 111, 248)]);
+//! let colors = gradient.colors(5);
+//! assert_eq!(colors.len(), 5);
+//! assert_eq!(colors[0], Color::Rgb(90, 86, 224));
+//! ```
+
+use super::color::Color;
+
+/// A multi-stop color gradient.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Gradient {
+    stops: Vec<Color>,
+}
+
+impl Gradient {
+    /// Create a gradient through the given colors, spaced evenly.
+    pub fn new(stops: impl IntoIterator<Item = Color>) -> Self {
+        Self { stops: stops.into_iter().collect() }
+    }
+
+    /// Get the color stops.
+    pub fn stops(&self) -> &[Color] {
+        &self.stops
+    }
+
+    /// Sample the gradient at `t`, from 0.0 (first stop) to 1.0 (last stop).
+    pub fn at(&self, t: f64) -> Color {
+        match self.stops.as_slice() {
+            [] => Color::Default,
+            [only] => only.clone(),
+            stops => {
+                let segments = stops.len() - 1;
+                let position = t.clamp(0.0, 1.0) * segments as f64;
+                let index = (position.floor() as usize).min(segments - 1);
+                Color::blend(&stops[index], &stops[index + 1], position - index as f64)
+            },
+        }
+    }
+
+    /// Sample `n` evenly spaced colors, including both ends.
+    pub fn colors(&self, n: usize) -> Vec<Color> {
+        match n {
+            0 => Vec::new(),
+            1 => vec![self.at(0.0)],
+            n => (0..n).map(|i| self.at(i as f64 / (n - 1) as f64)).collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sampling() {
+        let red = Color::Rgb(255, 0, 0);
+        let blue = Color::Rgb(0, 0, 255);
+        let gradient = Gradient::new([red.clone(), Color::Rgb(0, 255, 0), blue.clone()]);
+
+        assert_eq!(gradient.at(0.0), red);
+        assert_eq!(gradient.at(0.5), Color::Rgb(0, 255, 0));
+        assert_eq!(gradient.at(1.0), blue);
+        assert_eq!(gradient.at(2.0), blue);
+
+        let colors = gradient.colors(5);
+        assert_eq!(colors.len(), 5);
+        assert_eq!(colors[4], blue);
+        assert_eq!(gradient.colors(1), vec![red]);
+        assert!(gradient.colors(0).is_empty());
+        assert_eq!(Gradient::default().at(0.3), Color::Default);
+    }
+}
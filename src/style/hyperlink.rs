@@ -0,0 +1,113 @@
+//! OSC 8 hyperlinks.
+
+use std::{
+    path::Path,
+    sync::{OnceLock, RwLock},
+};
+
+static HYPERLINKS: RwLock<Option<bool>> = RwLock::new(None);
+
+/// Turn hyperlinks on or off, overriding detection.
+pub fn set_hyperlinks(enabled: bool) {
+    *HYPERLINKS.write().unwrap_or_else(|e| e.into_inner()) = Some(enabled);
+}
+
+/// Whether hyperlinks are emitted.
+///
+/// Defaults to [`supports_hyperlinks`](crate::terminal::supports_hyperlinks),
+/// checked once, until [`set_hyperlinks`] is called.
+pub fn hyperlinks_enabled() -> bool {
+    static DETECTED: OnceLock<bool> = OnceLock::new();
+
+    HYPERLINKS
+        .read()
+        .unwrap_or_else(|e| e.into_inner())
+        .unwrap_or_else(|| *DETECTED.get_or_init(crate::terminal::supports_hyperlinks))
+}
+
+/// Link `text` to `url`.
+///
+/// Falls back to the plain text when hyperlinks aren't enabled. Link escape
+/// sequences take no width, so [`width`](super::width) and the layout
+/// functions measure only the text.
+///
+/// # Example
+///
+/// ```rust
+/// use teapot::style::{hyperlink, set_hyperlinks, width};
+///
+/// set_hyperlinks(true);
+/// let link = hyperlink("docs", "https://example.com");
+/// assert_eq!(link, "\x1b]8;;https://example.com\x07docs\x1b]8;;\x07");
+/// assert_eq!(width(&link), 4);
+/// ```
+pub fn hyperlink(text: &str, url: &str) -> String {
+    if hyperlinks_enabled() { osc8(text, url) } else { text.to_string() }
+}
+
+/// Wrap each line of `text` in an OSC 8 link to `url`.
+pub(crate) fn osc8(text: &str, url: &str) -> String {
+    // Control characters would end the sequence early.
+    let url: String = url.chars().filter(|c| !c.is_control()).collect();
+    if url.is_empty() {
+        return text.to_string();
+    }
+
+    text.split('\n')
+        .map(|line| {
+            if line.is_empty() {
+                String::new()
+            } else {
+                format!("\x1b]8;;{url}\x07{line}\x1b]8;;\x07")
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Build a `file://` URL for a path, made absolute and percent-encoded.
+pub fn file_url(path: &Path) -> String {
+    let path = std::path::absolute(path).unwrap_or_else(|_| path.to_path_buf());
+    let path = path.to_string_lossy().replace('\\', "/");
+
+    let mut url = String::from("file://");
+    if !path.starts_with('/') {
+        url.push('/');
+    }
+    for byte in path.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' | b'/' | b':' => {
+                url.push(byte as char)
+            },
+            _ => url.push_str(&format!("%{byte:02X}")),
+        }
+    }
+    url
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::style::{crop, width};
+
+    #[test]
+    fn test_osc8() {
+        let link = osc8("a link", "https://example.com");
+        assert_eq!(link, "\x1b]8;;https://example.com\x07a link\x1b]8;;\x07");
+        assert_eq!(width(&link), 6);
+        assert_eq!(crop(&link, 1), "\x1b]8;;https://example.com\x07a\x1b]8;;\x07");
+
+        assert_eq!(
+            osc8("two\nlines", "u"),
+            "\x1b]8;;u\x07two\x1b]8;;\x07\n\x1b]8;;u\x07lines\x1b]8;;\x07"
+        );
+        assert_eq!(osc8("text", "\x1b\x07"), "text");
+    }
+
+    #[test]
+    fn test_file_url() {
+        #[cfg(unix)]
+        assert_eq!(file_url(Path::new("/tmp/my file#1.txt")), "file:///tmp/my%20file%231.txt");
+        assert!(file_url(Path::new("relative")).starts_with("file:///"));
+    }
+}
@@ -2,9 +2,12 @@
 //!
 //! This module provides a Lip Gloss-inspired styling system with:
 //! - Colors (ANSI 16, 256, true color, and adaptive colors)
+//! - Color blending, lightening, contrast checks and gradients
 //! - Text attributes (bold, italic, underline, etc.)
+//! - OSC 8 hyperlinks
 //! - Borders and padding/margins with CSS-style shorthand
 //! - Width, height, and alignment
+//! - ANSI-aware wrapping, truncation and tab expansion
 //! - Layout utilities (join, place)
 //! - Style inheritance and composition
 //!
@@ -23,13 +26,24 @@
 //! eprint!("\r{}Processing...", CLEAR_LINE);
 //! ```
 
+mod ansi;
 mod border;
 mod color;
+mod gradient;
+mod hyperlink;
 mod text;
+mod theme;
 
+pub(crate) use ansi::{SgrState, glyphs};
+pub use ansi::{crop, expand_tabs, slice, truncate_with, wrap};
 pub use border::{Border, BorderStyle};
-pub use color::{Color, ColorProfile, has_dark_background};
+pub(crate) use color::replace_color_profile;
+pub use color::{Color, ColorProfile, color_profile, has_dark_background, set_color_profile};
+pub use gradient::Gradient;
+pub use hyperlink::{file_url, hyperlink, hyperlinks_enabled, set_hyperlinks};
 pub use text::{Position, Spacing, Style, bold, colored, dim, underline};
+pub(crate) use theme::replace_theme;
+pub use theme::{Theme, ThemeColor, current_theme, reset_theme, set_theme};
 
 // ============================================================================
 // ANSI Escape Sequence Constants
@@ -152,28 +166,15 @@ pub fn pad_center(s: &str, target_width: usize) -> String {
 }
 
 /// Truncate a string to fit within a width, adding ellipsis if needed.
+///
+/// Escape sequences are kept; see [`truncate_with`] for a custom tail.
 pub fn truncate(s: &str, max_width: usize) -> String {
-    let current = width(s);
-    if current <= max_width {
+    if width(s) <= max_width {
         s.to_string()
     } else if max_width <= 3 {
         ".".repeat(max_width)
     } else {
-        let mut result = String::new();
-        let mut current_width = 0;
-        let target = max_width - 3; // Leave room for "..."
-
-        for c in s.chars() {
-            let char_width = unicode_width::UnicodeWidthChar::width(c).unwrap_or(0);
-            if current_width + char_width > target {
-                break;
-            }
-            result.push(c);
-            current_width += char_width;
-        }
-
-        result.push_str("...");
-        result
+        truncate_with(s, max_width, "...")
     }
 }
 
@@ -491,6 +492,7 @@ mod tests {
         assert_eq!(truncate("hello world", 8), "hello...");
         assert_eq!(truncate("hi", 10), "hi");
         assert_eq!(truncate("test", 3), "...");
+        assert_eq!(truncate("\x1b[31mhello world\x1b[0m", 8), "\x1b[31mhello...\x1b[0m");
     }
 
     #[test]
@@ -4,10 +4,15 @@
 
 use super::{
     border::{Border, BorderStyle},
-    color::Color,
-    strip_ansi, width as str_width,
+    color::{Color, ColorProfile},
+    crop, expand_tabs,
+    hyperlink::osc8,
+    width as str_width, wrap,
 };
 
+/// Tab stop width used when none is set.
+const DEFAULT_TAB_WIDTH: usize = 4;
+
 /// Position for alignment.
 #[derive(Debug, Clone, Copy, PartialEq, Default)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -120,8 +125,14 @@ pub struct Style {
     border_foreground: Option<Color>,
     border_background: Option<Color>,
 
+    // Hyperlink
+    hyperlink: Option<String>,
+
     // Rendering options
     inline: bool,
+    word_wrap: bool,
+    tab_width: Option<usize>,
+    color_profile: Option<ColorProfile>,
 }
 
 impl Style {
@@ -496,6 +507,28 @@ impl Style {
         self.border.as_ref()
     }
 
+    // ========== Hyperlink ==========
+
+    /// Link the rendered text to a URL with OSC 8.
+    ///
+    /// Rendered as plain text when hyperlinks aren't enabled; see
+    /// [`hyperlinks_enabled`](super::hyperlinks_enabled).
+    pub fn hyperlink(mut self, url: impl Into<String>) -> Self {
+        self.hyperlink = Some(url.into());
+        self
+    }
+
+    /// Remove the hyperlink.
+    pub fn unset_hyperlink(mut self) -> Self {
+        self.hyperlink = None;
+        self
+    }
+
+    /// Get the hyperlink URL.
+    pub fn get_hyperlink(&self) -> Option<&str> {
+        self.hyperlink.as_deref()
+    }
+
     // ========== Rendering Options ==========
 
     /// Set inline mode.
@@ -509,6 +542,49 @@ impl Style {
         self.inline
     }
 
+    /// Word-wrap text to the width instead of cutting it off.
+    ///
+    /// Text wraps to [`width`](Self::width) minus padding, or to
+    /// [`max_width`](Self::max_width) minus the frame. Styles carry across
+    /// the wrapped lines.
+    pub fn word_wrap(mut self, value: bool) -> Self {
+        self.word_wrap = value;
+        self
+    }
+
+    /// Get word wrap setting.
+    pub fn get_word_wrap(&self) -> bool {
+        self.word_wrap
+    }
+
+    /// Set the tab stop width tabs are expanded to (default: 4, 0 removes tabs).
+    pub fn tab_width(mut self, value: usize) -> Self {
+        self.tab_width = Some(value);
+        self
+    }
+
+    /// Get the tab stop width.
+    pub fn get_tab_width(&self) -> usize {
+        self.tab_width.unwrap_or(DEFAULT_TAB_WIDTH)
+    }
+
+    /// Render colors for a specific color profile instead of the global one.
+    ///
+    /// See [`set_color_profile`](super::set_color_profile).
+    pub fn color_profile(mut self, profile: ColorProfile) -> Self {
+        self.color_profile = Some(profile);
+        self
+    }
+
+    /// Get the color profile override.
+    pub fn get_color_profile(&self) -> Option<ColorProfile> {
+        self.color_profile
+    }
+
+    fn profile(&self) -> ColorProfile {
+        self.color_profile.unwrap_or_else(super::color_profile)
+    }
+
     // ========== Frame Size ==========
 
     /// Get horizontal frame size (padding + border + margin).
@@ -573,11 +649,14 @@ impl Style {
             return self.render_inline(text);
         }
 
-        let mut result = text.to_string();
+        let mut result = expand_tabs(text, self.get_tab_width());
 
         // Apply text styling
         result = self.apply_text_style(&result);
 
+        // Wrap lines
+        result = self.apply_wrap(&result);
+
         // Apply padding
         result = self.apply_padding(&result);
 
@@ -597,10 +676,19 @@ impl Style {
     }
 
     fn render_inline(&self, text: &str) -> String {
-        self.apply_text_style(text)
+        self.apply_text_style(&expand_tabs(text, self.get_tab_width()))
     }
 
     fn apply_text_style(&self, text: &str) -> String {
+        let linked;
+        let text = match &self.hyperlink {
+            Some(url) if super::hyperlinks_enabled() => {
+                linked = osc8(text, url);
+                linked.as_str()
+            },
+            _ => text,
+        };
+
         let mut codes = Vec::new();
 
         if self.bold == Some(true) {
@@ -625,7 +713,11 @@ impl Style {
             codes.push("9");
         }
 
-        if codes.is_empty() && self.foreground.is_none() && self.background.is_none() {
+        let profile = self.profile();
+        let fg = self.foreground.as_ref().map(|c| c.to_ansi_fg_in(profile)).unwrap_or_default();
+        let bg = self.background.as_ref().map(|c| c.to_ansi_bg_in(profile)).unwrap_or_default();
+
+        if codes.is_empty() && fg.is_empty() && bg.is_empty() {
             return text.to_string();
         }
 
@@ -635,12 +727,8 @@ impl Style {
             result.push_str(&format!("\x1b[{}m", codes.join(";")));
         }
 
-        if let Some(ref fg) = self.foreground {
-            result.push_str(&fg.to_ansi_fg());
-        }
-        if let Some(ref bg) = self.background {
-            result.push_str(&bg.to_ansi_bg());
-        }
+        result.push_str(&fg);
+        result.push_str(&bg);
 
         result.push_str(text);
         result.push_str("\x1b[0m");
@@ -648,6 +736,26 @@ impl Style {
         result
     }
 
+    fn apply_wrap(&self, text: &str) -> String {
+        let limit = if !self.word_wrap {
+            None
+        } else if let Some(width) = self.width {
+            Some(width.saturating_sub(self.padding.horizontal()))
+        } else {
+            self.max_width.map(|max| max.saturating_sub(self.get_horizontal_frame_size()))
+        };
+
+        match limit {
+            Some(limit) => wrap(text, limit).join("\n"),
+            // Styled multi-line text still needs its styles closed and
+            // reopened on every line before padding and borders are added.
+            None if text.contains('\n') && text.contains('\x1b') => {
+                wrap(text, usize::MAX).join("\n")
+            },
+            None => text.to_string(),
+        }
+    }
+
     fn apply_padding(&self, text: &str) -> String {
         if self.padding == Spacing::default() {
             return text.to_string();
@@ -711,7 +819,7 @@ impl Style {
                         },
                     }
                 } else if line_width > target_width {
-                    *line = truncate_to_width(line, target_width);
+                    *line = crop(line, target_width);
                 }
             }
         }
@@ -825,12 +933,13 @@ impl Style {
     }
 
     fn get_border_style_start(&self) -> String {
+        let profile = self.profile();
         let mut codes = String::new();
         if let Some(ref fg) = self.border_foreground {
-            codes.push_str(&fg.to_ansi_fg());
+            codes.push_str(&fg.to_ansi_fg_in(profile));
         }
         if let Some(ref bg) = self.border_background {
-            codes.push_str(&bg.to_ansi_bg());
+            codes.push_str(&bg.to_ansi_bg_in(profile));
         }
         codes
     }
@@ -871,7 +980,7 @@ impl Style {
         if let Some(max_w) = self.max_width {
             for line in &mut lines {
                 if str_width(line) > max_w {
-                    *line = truncate_to_width(line, max_w);
+                    *line = crop(line, max_w);
                 }
             }
         }
@@ -887,32 +996,6 @@ impl Style {
     }
 }
 
-/// Truncate a string to a specific display width.
-fn truncate_to_width(s: &str, max_width: usize) -> String {
-    let stripped = strip_ansi(s);
-    if str_width(&stripped) <= max_width {
-        return s.to_string();
-    }
-
-    if max_width == 0 {
-        return String::new();
-    }
-
-    let mut result = String::new();
-    let mut current_width = 0;
-
-    for c in stripped.chars() {
-        let char_width = unicode_width::UnicodeWidthChar::width(c).unwrap_or(0);
-        if current_width + char_width > max_width {
-            break;
-        }
-        result.push(c);
-        current_width += char_width;
-    }
-
-    result
-}
-
 // ========== Convenience Color Constructors ==========
 
 impl Style {
@@ -1076,4 +1159,46 @@ mod tests {
         assert_eq!(style.get_horizontal_frame_size(), 4 + 8 + 2);
         assert_eq!(style.get_vertical_frame_size(), 2 + 6 + 2);
     }
+
+    #[test]
+    fn test_color_profile() {
+        let style = Style::new().foreground(Color::Rgb(255, 0, 0));
+        assert_eq!(
+            style.clone().color_profile(ColorProfile::Ansi256).render("x"),
+            "\x1b[38;5;196mx\x1b[0m"
+        );
+        assert_eq!(style.clone().color_profile(ColorProfile::Ascii).render("x"), "x");
+
+        let bold = style.bold(true).color_profile(ColorProfile::Ascii);
+        assert_eq!(bold.render("x"), "\x1b[1mx\x1b[0m");
+    }
+
+    #[test]
+    fn test_word_wrap() {
+        let style = Style::new().width(8).padding(&[0, 1]).word_wrap(true);
+        assert_eq!(style.render("the quick brown fox"), " the    \n quick  \n brown  \n fox    ");
+
+        let styled = Style::new().bold(true).max_width(5).word_wrap(true).render("ab cd ef");
+        assert_eq!(styled, "\x1b[1mab cd\x1b[0m\n\x1b[1mef\x1b[0m");
+    }
+
+    #[test]
+    fn test_tabs_and_styled_truncation() {
+        assert_eq!(Style::new().render("a\tb"), "a   b");
+        assert_eq!(Style::new().tab_width(2).render("a\tb"), "a b");
+
+        let cut = Style::new().fg(Color::Red).width(3).render("hello");
+        assert_eq!(cut, "\x1b[31mhel\x1b[0m");
+    }
+
+    #[test]
+    fn test_hyperlink() {
+        let style = Style::new().hyperlink("https://example.com").bold(true);
+        assert_eq!(style.get_hyperlink(), Some("https://example.com"));
+
+        // Linked or not, the link takes no width.
+        let rendered = style.width(6).render("docs");
+        assert_eq!(str_width(&rendered), 6);
+        assert_eq!(crate::style::strip_ansi(&rendered), "docs  ");
+    }
 }
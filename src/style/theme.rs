@@ -0,0 +1,243 @@
+//! Application-wide color themes.
+//!
+//! A [`Theme`] maps semantic slots (primary, accent, muted, ...) to concrete
+//! colors. Components default to [`Color::Theme`] references such as
+//! [`Color::PRIMARY`], which are looked up in the current theme when rendered,
+//! so installing a theme with [`set_theme`] recolors every component that
+//! hasn't been given an explicit color.
+//!
+//! # Example
+//!
+//! ```rust
+//! use teapot::style::{Color, Theme, set_theme};
+//!
+//! set_theme(Theme::dark().primary(Color::Rgb(0, 204, 255)));
+//! ```
+
+use std::sync::{LazyLock, RwLock};
+
+use super::color::{Color, has_dark_background};
+
+/// A semantic color slot in a [`Theme`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum ThemeColor {
+    /// Titles, headers, cursors and active elements.
+    Primary,
+    /// Secondary highlights.
+    Accent,
+    /// Hints, placeholders and inactive text.
+    Muted,
+    /// Success states.
+    Success,
+    /// Warning states.
+    Warning,
+    /// Error states.
+    Error,
+    /// Borders and separators.
+    Border,
+    /// Selected items.
+    Selection,
+}
+
+/// A set of semantic colors shared by all components.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Theme {
+    /// Titles, headers, cursors and active elements.
+    pub primary: Color,
+    /// Secondary highlights.
+    pub accent: Color,
+    /// Hints, placeholders and inactive text.
+    pub muted: Color,
+    /// Success states.
+    pub success: Color,
+    /// Warning states.
+    pub warning: Color,
+    /// Error states.
+    pub error: Color,
+    /// Borders and separators.
+    pub border: Color,
+    /// Selected items.
+    pub selection: Color,
+}
+
+impl Default for Theme {
+    /// The built-in palettes, picking light or dark per terminal background.
+    fn default() -> Self {
+        Self::adaptive(Self::light(), Self::dark())
+    }
+}
+
+impl Theme {
+    /// Palette for dark terminal backgrounds.
+    pub fn dark() -> Self {
+        Self {
+            primary: Color::Cyan,
+            accent: Color::Magenta,
+            muted: Color::BrightBlack,
+            success: Color::Green,
+            warning: Color::Yellow,
+            error: Color::Red,
+            border: Color::BrightBlack,
+            selection: Color::Cyan,
+        }
+    }
+
+    /// Palette for light terminal backgrounds.
+    pub fn light() -> Self {
+        Self {
+            primary: Color::Blue,
+            accent: Color::Magenta,
+            muted: Color::Ansi256(243),
+            success: Color::Green,
+            warning: Color::Ansi256(130),
+            error: Color::Red,
+            border: Color::Ansi256(250),
+            selection: Color::Blue,
+        }
+    }
+
+    /// Combine two palettes, choosing per slot via [`has_dark_background`].
+    pub fn adaptive(light: Theme, dark: Theme) -> Self {
+        Self {
+            primary: Color::adaptive(light.primary, dark.primary),
+            accent: Color::adaptive(light.accent, dark.accent),
+            muted: Color::adaptive(light.muted, dark.muted),
+            success: Color::adaptive(light.success, dark.success),
+            warning: Color::adaptive(light.warning, dark.warning),
+            error: Color::adaptive(light.error, dark.error),
+            border: Color::adaptive(light.border, dark.border),
+            selection: Color::adaptive(light.selection, dark.selection),
+        }
+    }
+
+    /// Pick the light or dark palette for the current terminal.
+    pub fn detect() -> Self {
+        if has_dark_background() { Self::dark() } else { Self::light() }
+    }
+
+    /// Set the primary color.
+    pub fn primary(mut self, color: Color) -> Self {
+        self.primary = color;
+        self
+    }
+
+    /// Set the accent color.
+    pub fn accent(mut self, color: Color) -> Self {
+        self.accent = color;
+        self
+    }
+
+    /// Set the muted color.
+    pub fn muted(mut self, color: Color) -> Self {
+        self.muted = color;
+        self
+    }
+
+    /// Set the success color.
+    pub fn success(mut self, color: Color) -> Self {
+        self.success = color;
+        self
+    }
+
+    /// Set the warning color.
+    pub fn warning(mut self, color: Color) -> Self {
+        self.warning = color;
+        self
+    }
+
+    /// Set the error color.
+    pub fn error(mut self, color: Color) -> Self {
+        self.error = color;
+        self
+    }
+
+    /// Set the border color.
+    pub fn border(mut self, color: Color) -> Self {
+        self.border = color;
+        self
+    }
+
+    /// Set the selection color.
+    pub fn selection(mut self, color: Color) -> Self {
+        self.selection = color;
+        self
+    }
+
+    /// Get the color for a slot.
+    pub fn get(&self, slot: ThemeColor) -> &Color {
+        match slot {
+            ThemeColor::Primary => &self.primary,
+            ThemeColor::Accent => &self.accent,
+            ThemeColor::Muted => &self.muted,
+            ThemeColor::Success => &self.success,
+            ThemeColor::Warning => &self.warning,
+            ThemeColor::Error => &self.error,
+            ThemeColor::Border => &self.border,
+            ThemeColor::Selection => &self.selection,
+        }
+    }
+}
+
+static DEFAULT_THEME: LazyLock<Theme> = LazyLock::new(Theme::default);
+static CURRENT_THEME: RwLock<Option<Theme>> = RwLock::new(None);
+
+/// Install the theme used by every component without an explicit color.
+pub fn set_theme(theme: Theme) {
+    replace_theme(Some(theme));
+}
+
+/// Restore the built-in adaptive theme.
+pub fn reset_theme() {
+    replace_theme(None);
+}
+
+/// Install a theme, or the built-in one with `None`, returning the old one.
+pub(crate) fn replace_theme(theme: Option<Theme>) -> Option<Theme> {
+    std::mem::replace(&mut *CURRENT_THEME.write().unwrap_or_else(|e| e.into_inner()), theme)
+}
+
+/// Get a copy of the current theme.
+pub fn current_theme() -> Theme {
+    with_current_theme(Theme::clone)
+}
+
+/// Run `f` with the current theme.
+pub(crate) fn with_current_theme<R>(f: impl FnOnce(&Theme) -> R) -> R {
+    let guard = CURRENT_THEME.read().unwrap_or_else(|e| e.into_inner());
+    f(guard.as_ref().unwrap_or(&DEFAULT_THEME))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_palettes() {
+        let dark = Theme::dark();
+        assert_eq!(dark.get(ThemeColor::Primary), &Color::Cyan);
+        assert_eq!(dark.get(ThemeColor::Border), &Color::BrightBlack);
+        assert_ne!(Theme::light(), dark);
+        assert!(matches!(Theme::default().primary, Color::Adaptive { .. }));
+    }
+
+    #[test]
+    fn test_resolve_in_theme() {
+        let theme = Theme::dark().accent(Color::Rgb(1, 2, 3));
+        assert_eq!(Color::ACCENT.resolve_in(&theme), Color::Rgb(1, 2, 3));
+        assert_eq!(Color::ERROR.resolve_in(&theme), Color::Red);
+
+        // A slot that points back at the theme can't loop forever.
+        let theme = Theme::dark().muted(Color::MUTED);
+        assert_eq!(Color::MUTED.resolve_in(&theme), Color::Default);
+    }
+
+    #[test]
+    fn test_resolve_owned() {
+        // Both built-in palettes use green for success.
+        assert_eq!(Color::SUCCESS.resolve_owned(), Color::Green);
+        assert_eq!(Color::SUCCESS.resolve(), &Color::SUCCESS);
+    }
+}
@@ -0,0 +1,215 @@
+//! Terminal color queries.
+//!
+//! Asks the terminal for its background (OSC 11), foreground (OSC 10) and
+//! ANSI palette (OSC 4) colors. The queries are followed by a Primary Device
+//! Attributes request, which every terminal answers, so terminals that ignore
+//! the color queries don't stall start-up until the timeout.
+
+use std::{sync::OnceLock, time::Duration};
+
+use crate::style::Color;
+
+/// Colors reported by the terminal.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TerminalColors {
+    /// Background color (OSC 11).
+    pub background: Option<Color>,
+    /// Default foreground color (OSC 10).
+    pub foreground: Option<Color>,
+    /// The 16 ANSI palette colors (OSC 4).
+    pub palette: [Option<Color>; 16],
+}
+
+impl TerminalColors {
+    /// Whether the reported background is dark, if one was reported.
+    pub fn is_dark(&self) -> Option<bool> {
+        match self.background {
+            Some(Color::Rgb(r, g, b)) => {
+                let luminance = 0.2126 * r as f64 + 0.7152 * g as f64 + 0.0722 * b as f64;
+                Some(luminance < 128.0)
+            },
+            _ => None,
+        }
+    }
+}
+
+static TERMINAL_COLORS: OnceLock<TerminalColors> = OnceLock::new();
+
+/// Get the colors detected by [`detect_colors`], if it has run.
+pub fn terminal_colors() -> Option<&'static TerminalColors> {
+    TERMINAL_COLORS.get()
+}
+
+/// Query the terminal's colors once and cache the result.
+///
+/// Later calls return the cached colors without querying again. Returns
+/// `None` when stdin or stdout is not a terminal or the terminal didn't answer
+/// within `timeout`; the failure is cached too.
+///
+/// [`Program`](crate::Program) calls this at start-up, after which
+/// [`has_dark_background`](crate::style::has_dark_background) and adaptive
+/// colors follow the real background.
+pub fn detect_colors(timeout: Duration) -> Option<&'static TerminalColors> {
+    let colors = TERMINAL_COLORS.get_or_init(|| query_colors(timeout).unwrap_or_default());
+    (colors != &TerminalColors::default()).then_some(colors)
+}
+
+/// Query the terminal's colors without caching.
+///
+/// Puts the terminal in raw mode for the duration of the query if it isn't
+/// already.
+pub fn query_colors(timeout: Duration) -> Option<TerminalColors> {
+    use std::io::IsTerminal;
+
+    if !std::io::stdin().is_terminal() || !std::io::stdout().is_terminal() {
+        return None;
+    }
+
+    let was_raw = crossterm::terminal::is_raw_mode_enabled().unwrap_or(false);
+    if !was_raw {
+        crossterm::terminal::enable_raw_mode().ok()?;
+    }
+
+    let response = read_response(timeout);
+
+    if !was_raw {
+        let _ = crossterm::terminal::disable_raw_mode();
+    }
+
+    response.map(|bytes| parse_response(&String::from_utf8_lossy(&bytes)))
+}
+
+#[cfg(unix)]
+fn read_response(timeout: Duration) -> Option<Vec<u8>> {
+    use std::{
+        fs::OpenOptions,
+        io::{Read, Write},
+        time::Instant,
+    };
+
+    use rustix::event::{PollFd, PollFlags, Timespec, poll};
+
+    let mut tty = OpenOptions::new().read(true).write(true).open("/dev/tty").ok()?;
+
+    let mut query = String::from("\x1b]11;?\x07\x1b]10;?\x07");
+    for index in 0..16 {
+        query.push_str(&format!("\x1b]4;{};?\x07", index));
+    }
+    query.push_str("\x1b[c");
+    tty.write_all(query.as_bytes()).ok()?;
+    tty.flush().ok()?;
+
+    // Only read once the tty has input, so nothing is left blocked reading
+    // (and swallowing keystrokes) after the deadline.
+    let deadline = Instant::now() + timeout;
+    let mut response = Vec::new();
+    let mut buf = [0u8; 256];
+    while !ends_with_device_attributes(&response) {
+        let remaining =
+            Timespec::try_from(deadline.checked_duration_since(Instant::now())?).ok()?;
+        match poll(&mut [PollFd::new(&tty, PollFlags::IN)], Some(&remaining)) {
+            Ok(0) => return None,
+            Ok(_) => {},
+            Err(rustix::io::Errno::INTR) => continue,
+            Err(_) => return None,
+        }
+        let n = tty.read(&mut buf).ok()?;
+        if n == 0 {
+            break;
+        }
+        response.extend_from_slice(&buf[..n]);
+    }
+    Some(response)
+}
+
+#[cfg(not(unix))]
+fn read_response(_timeout: Duration) -> Option<Vec<u8>> {
+    None
+}
+
+/// Check for a complete `ESC [ ? ... c` reply at the end of the buffer.
+fn ends_with_device_attributes(bytes: &[u8]) -> bool {
+    if bytes.last() != Some(&b'c') {
+        return false;
+    }
+    let Some(start) = bytes.windows(3).rposition(|w| w == b"\x1b[?") else {
+        return false;
+    };
+    bytes[start + 3..bytes.len() - 1].iter().all(|b| b.is_ascii_digit() || *b == b';')
+}
+
+/// Parse OSC 10, 11 and 4 replies.
+fn parse_response(response: &str) -> TerminalColors {
+    let mut colors = TerminalColors::default();
+
+    for reply in response.split("\x1b]").skip(1) {
+        let reply = reply.split(['\x07', '\x1b']).next().unwrap_or_default();
+        let mut parts = reply.split(';');
+        match (parts.next(), parts.next(), parts.next()) {
+            (Some("11"), Some(spec), None) => colors.background = parse_rgb(spec),
+            (Some("10"), Some(spec), None) => colors.foreground = parse_rgb(spec),
+            (Some("4"), Some(index), Some(spec)) => {
+                if let Ok(index) = index.parse::<usize>()
+                    && index < 16
+                {
+                    colors.palette[index] = parse_rgb(spec);
+                }
+            },
+            _ => {},
+        }
+    }
+
+    colors
+}
+
+/// Parse an X11 color spec like `rgb:ffff/8080/0000`.
+fn parse_rgb(spec: &str) -> Option<Color> {
+    let channels = spec.strip_prefix("rgb:").or_else(|| spec.strip_prefix("rgba:"))?;
+    let mut channels = channels.split('/').map(|hex| {
+        if hex.is_empty() || hex.len() > 4 {
+            return None;
+        }
+        let value = u32::from_str_radix(hex, 16).ok()?;
+        let max = (1u32 << (4 * hex.len())) - 1;
+        Some((value * 255 / max) as u8)
+    });
+
+    Some(Color::Rgb(channels.next()??, channels.next()??, channels.next()??))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_rgb() {
+        assert_eq!(parse_rgb("rgb:ffff/8080/0000"), Some(Color::Rgb(255, 128, 0)));
+        assert_eq!(parse_rgb("rgb:ff/80/00"), Some(Color::Rgb(255, 128, 0)));
+        assert_eq!(parse_rgb("rgb:f/8/0"), Some(Color::Rgb(255, 136, 0)));
+        assert_eq!(parse_rgb("rgba:0000/0000/0000/ffff"), Some(Color::Rgb(0, 0, 0)));
+        assert_eq!(parse_rgb("#ffffff"), None);
+        assert_eq!(parse_rgb("rgb:ffff/ffff"), None);
+    }
+
+    #[test]
+    fn test_parse_response() {
+        let response = "\x1b]11;rgb:ffff/ffff/ffff\x1b\\\x1b]10;rgb:0000/0000/0000\x07\
+                        \x1b]4;1;rgb:cdcd/0000/0000\x07\x1b[?62;22c";
+        let colors = parse_response(response);
+
+        assert_eq!(colors.background, Some(Color::Rgb(255, 255, 255)));
+        assert_eq!(colors.foreground, Some(Color::Rgb(0, 0, 0)));
+        assert_eq!(colors.palette[1], Some(Color::Rgb(205, 0, 0)));
+        assert_eq!(colors.palette[0], None);
+        assert_eq!(colors.is_dark(), Some(false));
+        assert!(ends_with_device_attributes(response.as_bytes()));
+    }
+
+    #[test]
+    fn test_no_reply() {
+        let colors = parse_response("\x1b[?1;2c");
+        assert_eq!(colors, TerminalColors::default());
+        assert_eq!(colors.is_dark(), None);
+        assert!(!ends_with_device_attributes(b"\x1b]11;rgb:0/0/0\x07"));
+    }
+}
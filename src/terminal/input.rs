@@ -235,6 +235,13 @@ pub struct MouseEvent {
     pub modifiers: KeyModifiers,
 }
 
+impl MouseEvent {
+    /// Create a mouse event at (`column`, `row`) with no modifiers held.
+    pub fn new(kind: MouseEventKind, column: u16, row: u16) -> Self {
+        Self { kind, column, row, modifiers: KeyModifiers::NONE }
+    }
+}
+
 impl From<CrosstermMouseEvent> for MouseEvent {
     fn from(event: CrosstermMouseEvent) -> Self {
         Self {
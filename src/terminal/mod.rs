@@ -3,13 +3,16 @@
 //! This module provides a unified interface over crossterm for:
 //! - Event handling (keyboard, mouse, resize)
 //! - Terminal capabilities detection
+//! - Color queries (background, foreground, palette)
 //! - Raw mode management
 
 mod backend;
+mod colors;
 mod input;
 mod output;
 
 pub use backend::Backend;
+pub use colors::{TerminalColors, detect_colors, query_colors, terminal_colors};
 pub use input::{Event, KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
 pub use output::TerminalOutput;
 
@@ -58,3 +61,41 @@ pub fn supports_color() -> bool {
 
     is_tty()
 }
+
+/// Check if the terminal supports OSC 8 hyperlinks.
+///
+/// `FORCE_HYPERLINK=1` or `FORCE_HYPERLINK=0` overrides detection. Otherwise
+/// this looks for terminals known to support them, and is `false` when
+/// stdout isn't a terminal or in CI.
+pub fn supports_hyperlinks() -> bool {
+    if let Ok(force) = std::env::var("FORCE_HYPERLINK") {
+        return !force.is_empty() && force != "0";
+    }
+
+    if !is_tty() || is_ci() {
+        return false;
+    }
+
+    let var = |name: &str| std::env::var(name).unwrap_or_default();
+
+    if ["WT_SESSION", "KITTY_WINDOW_ID", "KONSOLE_VERSION", "DOMTERM"]
+        .iter()
+        .any(|name| std::env::var_os(name).is_some())
+    {
+        return true;
+    }
+
+    if var("VTE_VERSION").parse::<u32>().is_ok_and(|version| version >= 5000) {
+        return true;
+    }
+
+    let program = var("TERM_PROGRAM");
+    if ["iTerm.app", "WezTerm", "vscode", "ghostty", "Hyper", "Tabby", "rio"]
+        .contains(&program.as_str())
+    {
+        return true;
+    }
+
+    let term = var("TERM");
+    ["kitty", "alacritty", "foot", "wezterm", "ghostty"].iter().any(|name| term.contains(name))
+}
@@ -0,0 +1,282 @@
+//! Fuzzy matching and ranking.
+//!
+//! Scores how well a query matches a string the way fzf does: every query
+//! character must appear in order, and matches score higher when they are
+//! consecutive or start a word, and lower the more characters they skip.
+
+/// Score for each matched character.
+const SCORE_MATCH: i32 = 16;
+/// Penalty for the first character skipped between two matches.
+const SCORE_GAP_START: i32 = -3;
+/// Penalty for each further character skipped.
+const SCORE_GAP_EXTENSION: i32 = -1;
+/// Bonus for matching at the start of a word.
+const BONUS_BOUNDARY: i32 = SCORE_MATCH / 2;
+/// Bonus for matching a camelCase hump or the first digit of a number.
+const BONUS_CAMEL: i32 = BONUS_BOUNDARY + SCORE_GAP_EXTENSION;
+/// Bonus for matching right after the previous match.
+const BONUS_CONSECUTIVE: i32 = -(SCORE_GAP_START + SCORE_GAP_EXTENSION);
+/// The bonus of the first query character counts this many times.
+const BONUS_FIRST_CHAR_MULTIPLIER: i32 = 2;
+
+/// Score of an impossible match.
+const NONE: i32 = i32::MIN / 2;
+
+/// How letter case is compared.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CaseMatching {
+    /// Ignore case unless the term contains an uppercase letter.
+    #[default]
+    Smart,
+    /// Always compare case.
+    Sensitive,
+    /// Never compare case.
+    Insensitive,
+}
+
+/// A successful match.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FuzzyMatch {
+    /// How good the match is; higher is better.
+    pub score: i32,
+    /// Indices of the matched characters (not bytes), in ascending order.
+    pub positions: Vec<usize>,
+}
+
+/// Matches queries of space-separated terms against strings.
+///
+/// Every term must match. In fuzzy mode a term's characters may be spread
+/// out; in exact mode the term must appear as a substring.
+///
+/// # Example
+///
+/// ```rust
+/// use teapot::util::FuzzyMatcher;
+///
+/// let matcher = FuzzyMatcher::new();
+/// let hit = matcher.matches("cfg", "Cargo.config.toml").unwrap();
+/// assert_eq!(hit.positions, vec![6, 9, 11]);
+///
+/// // Word starts beat scattered letters.
+/// let boundary = matcher.matches("ct", "cargo_test").unwrap();
+/// let scattered = matcher.matches("ct", "cartoon").unwrap();
+/// assert!(boundary.score > scattered.score);
+///
+/// assert!(matcher.matches("xyz", "cargo").is_none());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct FuzzyMatcher {
+    case: CaseMatching,
+    exact: bool,
+}
+
+impl FuzzyMatcher {
+    /// Create a fuzzy matcher with smart case.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set how letter case is compared.
+    pub fn case(mut self, case: CaseMatching) -> Self {
+        self.case = case;
+        self
+    }
+
+    /// Require each term to appear as a substring.
+    pub fn exact(mut self, exact: bool) -> Self {
+        self.exact = exact;
+        self
+    }
+
+    /// Match `query` against `text`.
+    ///
+    /// Returns `None` if any term doesn't match. An empty query matches
+    /// everything with a score of 0.
+    pub fn matches(&self, query: &str, text: &str) -> Option<FuzzyMatch> {
+        let text: Vec<char> = text.chars().collect();
+        let mut total = FuzzyMatch { score: 0, positions: Vec::new() };
+
+        for term in query.split_whitespace() {
+            let fold = match self.case {
+                CaseMatching::Smart => !term.chars().any(char::is_uppercase),
+                CaseMatching::Sensitive => false,
+                CaseMatching::Insensitive => true,
+            };
+            let term: Vec<char> = term.chars().collect();
+            let found = if self.exact {
+                exact_match(&term, &text, fold)?
+            } else {
+                fuzzy_match(&term, &text, fold)?
+            };
+            total.score += found.score;
+            total.positions.extend(found.positions);
+        }
+
+        total.positions.sort_unstable();
+        total.positions.dedup();
+        Some(total)
+    }
+}
+
+fn fold_char(c: char) -> char {
+    c.to_lowercase().next().unwrap_or(c)
+}
+
+fn chars_eq(a: char, b: char, fold: bool) -> bool {
+    a == b || (fold && fold_char(a) == fold_char(b))
+}
+
+/// Bonus for matching `c` given the character before it.
+fn bonus(prev: Option<char>, c: char) -> i32 {
+    match prev {
+        _ if !c.is_alphanumeric() => 0,
+        None => BONUS_BOUNDARY,
+        Some(p) if !p.is_alphanumeric() => BONUS_BOUNDARY,
+        Some(p) if p.is_lowercase() && c.is_uppercase() => BONUS_CAMEL,
+        Some(p) if !p.is_numeric() && c.is_numeric() => BONUS_CAMEL,
+        Some(_) => 0,
+    }
+}
+
+/// Find the best-scoring placement of `pattern` as a subsequence of `text`.
+fn fuzzy_match(pattern: &[char], text: &[char], fold: bool) -> Option<FuzzyMatch> {
+    let (m, n) = (pattern.len(), text.len());
+
+    // Cheap subsequence check before the full search.
+    let mut rest = text.iter();
+    if !pattern.iter().all(|&p| rest.any(|&t| chars_eq(p, t, fold))) {
+        return None;
+    }
+
+    let bonuses: Vec<i32> =
+        (0..n).map(|j| bonus(j.checked_sub(1).map(|k| text[k]), text[j])).collect();
+
+    // score[i * n + j]: best score with pattern[..=i] matched and pattern[i]
+    // on text[j]; from[i * n + j]: where pattern[i - 1] went; chunk[i * n + j]:
+    // the bonus a run of consecutive matches ending there carries forward.
+    let mut score = vec![NONE; m * n];
+    let mut from = vec![0; m * n];
+    let mut chunk = vec![0; m * n];
+    for i in 0..m {
+        // Best way to reach the current column after skipping at least one
+        // character since the previous match.
+        let (mut gap, mut gap_from) = (NONE, 0);
+        for j in i..n {
+            if i > 0 && j >= 2 {
+                let open = score[(i - 1) * n + j - 2] + SCORE_GAP_START;
+                gap += SCORE_GAP_EXTENSION;
+                if open >= gap {
+                    (gap, gap_from) = (open, j - 2);
+                }
+            }
+            if !chars_eq(pattern[i], text[j], fold) {
+                continue;
+            }
+
+            let (best, prev, run) = if i == 0 {
+                (bonuses[j] * BONUS_FIRST_CHAR_MULTIPLIER, 0, bonuses[j])
+            } else {
+                // A consecutive match earns at least the bonus of the
+                // character that started the run.
+                let run = bonuses[j].max(chunk[(i - 1) * n + j - 1]).max(BONUS_CONSECUTIVE);
+                let consecutive = score[(i - 1) * n + j - 1] + run;
+                if consecutive >= gap + bonuses[j] {
+                    (consecutive, j - 1, run)
+                } else {
+                    (gap + bonuses[j], gap_from, bonuses[j])
+                }
+            };
+            if best > NONE / 2 {
+                score[i * n + j] = best + SCORE_MATCH;
+                from[i * n + j] = prev;
+                chunk[i * n + j] = run;
+            }
+        }
+    }
+
+    // Ties go to the earliest end.
+    let last = (m - 1) * n;
+    let end = (m - 1..n)
+        .fold(m - 1, |best, j| if score[last + j] > score[last + best] { j } else { best });
+    let best = score[last + end];
+    if best <= NONE / 2 {
+        return None;
+    }
+
+    let mut positions = vec![0; m];
+    let mut j = end;
+    for i in (0..m).rev() {
+        positions[i] = j;
+        j = from[i * n + j];
+    }
+    Some(FuzzyMatch { score: best, positions })
+}
+
+/// Find the best-scoring occurrence of `pattern` as a substring of `text`.
+fn exact_match(pattern: &[char], text: &[char], fold: bool) -> Option<FuzzyMatch> {
+    let m = pattern.len();
+    let score_at = |start: usize| {
+        let bonus_at = |j: usize| bonus(j.checked_sub(1).map(|k| text[k]), text[j]);
+        let mut run = bonus_at(start);
+        let mut score = run * BONUS_FIRST_CHAR_MULTIPLIER + SCORE_MATCH;
+        for j in start + 1..start + m {
+            run = run.max(bonus_at(j)).max(BONUS_CONSECUTIVE);
+            score += run + SCORE_MATCH;
+        }
+        score
+    };
+
+    (0..=text.len().checked_sub(m)?)
+        .filter(|&start| pattern.iter().zip(&text[start..]).all(|(&p, &t)| chars_eq(p, t, fold)))
+        .map(|start| (score_at(start), start))
+        // Ties go to the earliest occurrence.
+        .fold(None, |best: Option<(i32, usize)>, hit| match best {
+            Some(best) if best.0 >= hit.0 => Some(best),
+            _ => Some(hit),
+        })
+        .map(|(score, start)| FuzzyMatch { score, positions: (start..start + m).collect() })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fuzzy_ranking() {
+        let matcher = FuzzyMatcher::new();
+        let score = |query, text| matcher.matches(query, text).map(|m| m.score);
+
+        assert!(score("fb", "foo_bar") > score("fb", "fooxbar"));
+        assert!(score("abc", "abc") > score("abc", "a_b_c"));
+        assert!(score("gc", "GitCommit") > score("gc", "magic"));
+        assert!(score("lib", "src/lib.rs") > score("lib", "collibrate"));
+
+        // The best placement wins, not the first one.
+        let hit = matcher.matches("ab", "xaxxx_ab").unwrap();
+        assert_eq!(hit.positions, vec![6, 7]);
+    }
+
+    #[test]
+    fn test_case_and_unicode() {
+        let matcher = FuzzyMatcher::new();
+        let hit = matcher.matches("cc", "Café crème").unwrap();
+        assert_eq!(hit.positions, vec![0, 5]);
+        assert!(matcher.matches("ÉC", "école").is_none());
+        assert!(matcher.case(CaseMatching::Insensitive).matches("ÉC", "école").is_some());
+        assert!(matcher.case(CaseMatching::Sensitive).matches("c", "Café").is_none());
+    }
+
+    #[test]
+    fn test_exact_and_terms() {
+        let exact = FuzzyMatcher::new().exact(true);
+        assert!(exact.matches("crg", "cargo").is_none());
+        assert_eq!(exact.matches("ar", "bar_art").unwrap().positions, vec![4, 5]);
+
+        let matcher = FuzzyMatcher::new();
+        let hit = matcher.matches("main rs", "src/main.rs").unwrap();
+        assert_eq!(hit.positions, vec![4, 5, 6, 7, 9, 10]);
+        assert!(matcher.matches("main py", "src/main.rs").is_none());
+        assert_eq!(matcher.matches("  ", "anything").unwrap().score, 0);
+    }
+}
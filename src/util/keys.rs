@@ -84,7 +84,7 @@ impl KeyBindings {
                 let padding = " ".repeat(max_key_width - key.len());
                 format!(
                     "  {}{}{}{} {}",
-                    crate::style::Color::Cyan.to_ansi_fg(),
+                    crate::style::Color::PRIMARY.to_ansi_fg(),
                     key,
                     "\x1b[0m",
                     padding,
@@ -1,10 +1,12 @@
 //! Utility functions.
 
+mod fuzzy;
 mod keys;
 mod scroll;
 mod size;
 mod worker;
 
+pub use fuzzy::{CaseMatching, FuzzyMatch, FuzzyMatcher};
 pub use keys::{KeyBinding, KeyBindings};
 pub use scroll::ScrollState;
 pub use size::{measure_text, wrap_text};
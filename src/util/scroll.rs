@@ -77,6 +77,66 @@ impl ScrollState {
         self.h_offset = 0;
     }
 
+    /// Select a row, clamped to the content, and scroll it into view.
+    ///
+    /// # Arguments
+    ///
+    /// * `row` - Row to select
+    /// * `row_count` - Total number of rows in the content
+    /// * `visible` - Number of rows visible in the viewport
+    pub fn select(&mut self, row: usize, row_count: usize, visible: usize) {
+        self.selected = row.min(row_count.saturating_sub(1));
+        self.scroll_to_selected(visible);
+    }
+
+    /// Select the first row.
+    pub fn select_first(&mut self) {
+        self.selected = 0;
+        self.offset = 0;
+    }
+
+    /// Select the last row.
+    ///
+    /// # Arguments
+    ///
+    /// * `row_count` - Total number of rows in the content
+    /// * `visible` - Number of rows visible in the viewport
+    pub fn select_last(&mut self, row_count: usize, visible: usize) {
+        self.select(row_count.saturating_sub(1), row_count, visible);
+    }
+
+    /// Adjust the vertical offset so the selection is visible.
+    ///
+    /// # Arguments
+    ///
+    /// * `visible` - Number of rows visible in the viewport
+    pub fn scroll_to_selected(&mut self, visible: usize) {
+        let visible = visible.max(1);
+        if self.selected < self.offset {
+            self.offset = self.selected;
+        } else if self.selected >= self.offset + visible {
+            self.offset = self.selected + 1 - visible;
+        }
+    }
+
+    /// Rows to draw in a viewport of `visible` rows, as `(start, end)`.
+    ///
+    /// The viewport may be smaller than the one the offset was computed
+    /// for, so the range is shifted to keep the selection in view.
+    ///
+    /// # Arguments
+    ///
+    /// * `row_count` - Total number of rows in the content
+    /// * `visible` - Number of rows visible in the viewport
+    pub fn visible_range(&self, row_count: usize, visible: usize) -> (usize, usize) {
+        let visible = visible.max(1);
+        // The content may have shrunk since the offset was set.
+        let selected = self.selected.min(row_count.saturating_sub(1));
+        let offset = self.offset.min(row_count.saturating_sub(visible));
+        let start = if selected >= offset + visible { selected + 1 - visible } else { offset };
+        (start, (start + visible).min(row_count))
+    }
+
     /// Move selection up by one row.
     ///
     /// Automatically adjusts vertical scroll offset to keep selection visible.
@@ -235,6 +295,24 @@ mod tests {
         assert_eq!(state.selected(), 0);
     }
 
+    #[test]
+    fn test_select_and_visible_range() {
+        let mut state = ScrollState::new();
+        state.select(12, 10, 4);
+        assert_eq!((state.selected(), state.offset()), (9, 6));
+        assert_eq!(state.visible_range(10, 4), (6, 10));
+        // A shorter viewport still shows the selection.
+        assert_eq!(state.visible_range(10, 2), (8, 10));
+        // Content that shrank below the offset is clamped.
+        assert_eq!(state.visible_range(5, 4), (1, 5));
+        assert_eq!(state.visible_range(0, 4), (0, 0));
+
+        state.select_first();
+        assert_eq!((state.selected(), state.offset()), (0, 0));
+        state.select_last(3, 5);
+        assert_eq!((state.selected(), state.offset()), (2, 0));
+    }
+
     #[test]
     fn test_page_down() {
         let mut state = ScrollState::new();
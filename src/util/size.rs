@@ -10,36 +10,11 @@ pub fn measure_text(text: &str) -> usize {
 }
 
 /// Wrap text to a given width.
+///
+/// See [`style::wrap`](crate::style::wrap); escape sequences are kept and
+/// styles carry across the wrapped lines.
 pub fn wrap_text(text: &str, width: usize) -> Vec<String> {
-    let mut lines = Vec::new();
-    let mut current_line = String::new();
-    let mut current_width = 0;
-
-    for word in text.split_whitespace() {
-        let word_width = UnicodeWidthStr::width(word);
-
-        if current_width == 0 {
-            // First word on line
-            current_line = word.to_string();
-            current_width = word_width;
-        } else if current_width + 1 + word_width <= width {
-            // Word fits on current line
-            current_line.push(' ');
-            current_line.push_str(word);
-            current_width += 1 + word_width;
-        } else {
-            // Start new line
-            lines.push(current_line);
-            current_line = word.to_string();
-            current_width = word_width;
-        }
-    }
-
-    if !current_line.is_empty() {
-        lines.push(current_line);
-    }
-
-    lines
+    crate::style::wrap(text, width)
 }
 
 #[cfg(test)]